@@ -0,0 +1,103 @@
+// Copyright (C) 2021 Clover Network
+// This file is part of Clover.
+
+//! Parlia headers of a test chain with chain id 56 and epoch 4, sealed by the keys
+//! `keccak256("validator-a")` to `keccak256("validator-d")`.
+//!
+//! Header 8 carries the validators a, b and c, header 12 adds d which seals from header 14, the
+//! following epoch headers carry the same four validators.
+//! Block 9 holds two transactions, the second one emits a `Deposit(address,uint256)` of 1000
+//! from 0x1111..11 to `243E34C336F3D2c08BBC79b99E6BCA1fA7c58595`.
+
+use hex_literal::hex;
+use sp_core::{H160, H256};
+
+pub const HEADERS: [&[u8]; 17] = [
+  &hex!("f90293a0b830ed3535cff77327c9b436b1ec2b87b7d73d19b8bc3250d4e3b701aee29e69a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d4934794d29f34a2e1bef6840cb8aa1c19973db40c7b5408a011bf6095a5cef723ac198f5b637140a7e56e53bac71ee96b1cf4fb5342ee0acea056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b901000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002088401c9c38080845f5e1018b89d00000000000000000000000000000000000000000000000000000000000000007fa00bcce4371d49a785ee6a9b47143995257d80ccf323fd8faf02be3b83a5bdf9ecc41b78c870edd29f34a2e1bef6840cb8aa1c19973db40c7b5408f46b534bd75006e937fcdde365de74a14409d0e27b16cb32f518e648f40676965017165762cbe011ea49a40a23aa434846b8b39cd5818c3ea79b3ef45d01f54601a00000000000000000000000000000000000000000000000000000000000000000880000000000000000"),
+  &hex!("f90257a002eab79d4936009c7d72d682ef0305f9ea5aea6ddf8eec31be3160d5ecbddeeaa01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347947fa00bcce4371d49a785ee6a9b47143995257d80a01dbb00877ca4572c6227c3ed277789af00e20d04faaff58d87d41d83727bdbeba015e8507eb806d29c3cfb14639251f2058a9d911cf2248fbd4867444e67e49f2da01355d00e74f131281d280aa19e10782ec7974913d34205adeb6fb7045995ad0bb901000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002098401c9c38080845f5e101bb8610000000000000000000000000000000000000000000000000000000000000000df54426f37638dfe4944427f1ec70db4d1921e618dd97e33ab4cad5d23363483688c209f136e76e210cabc2f4c11363fa5ecc62597ccbd63c1dad53009963dc000a00000000000000000000000000000000000000000000000000000000000000000880000000000000000"),
+  &hex!("f90257a066bd88830230354bd60e24ee4dca812823fa9517fe8b180b97d2823b64368bd2a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d4934794ccf323fd8faf02be3b83a5bdf9ecc41b78c870eda02964192b0c066e42f996b4e855df2b2a69a54db5a4bbc543a352f257b189378ca056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020a8401c9c38080845f5e101eb86100000000000000000000000000000000000000000000000000000000000000003b55314be76eb90e5386505f988e06681e76a5d370d42d49b078f94f60d3eb7630d2cf13150b8ca52be0ac90b0ee20d845dd27c16faad7240d6436a894e1a2b700a00000000000000000000000000000000000000000000000000000000000000000880000000000000000"),
+  &hex!("f90257a0149512ca66013bd9cd7c6cffbbc6d2f82c731eb010a3f0e3d15c3c02efaf9807a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d4934794d29f34a2e1bef6840cb8aa1c19973db40c7b5408a0bf635589e8ea2a89ec6a41aa58d20c201373816ac011d27241589fbc85b4acb3a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020b8401c9c38080845f5e1021b8610000000000000000000000000000000000000000000000000000000000000000ac477f1517b8aabb069f8939c76f93790bcecf33cb5a9e4ec7ed9217ce1532de33e617b90cf40fa986348ceed4668489e577e0cdd64d9f9f8c8cf1efa56ab2e000a00000000000000000000000000000000000000000000000000000000000000000880000000000000000"),
+  &hex!("f902a7a051902dc1d5b25d9ff27d287cbee969997441b2ed43f120e13bffce8e946cb7f1a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347947fa00bcce4371d49a785ee6a9b47143995257d80a0ef4d3b474591e04a0667e1cb33d39db50c3e965e7d6692d151a43e6a3e3aaadfa056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020c8401c9c38080845f5e1024b8b100000000000000000000000000000000000000000000000000000000000000007fa00bcce4371d49a785ee6a9b47143995257d80c09fe6ff1404f67d341598fb290acf2897e6008fccf323fd8faf02be3b83a5bdf9ecc41b78c870edd29f34a2e1bef6840cb8aa1c19973db40c7b540860658d6df30132aba12c50a769f09a7e39f921855af54bcbaca3460ff351a1ce3c67a7a06b37235ea4c013fa028719ee07449a47e0365806559470bd544a29e200a00000000000000000000000000000000000000000000000000000000000000000880000000000000000"),
+  &hex!("f90257a039af08af2db1769bce6605882985fec3072234e3de159f46c1b95508bbf901d9a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d4934794ccf323fd8faf02be3b83a5bdf9ecc41b78c870eda024fb1f5b4f14c7403aad888ab122ccf3c9a86c0ef3b4ebef92b18ced81c48251a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020d8401c9c38080845f5e1027b861000000000000000000000000000000000000000000000000000000000000000096a192212118f672e8b327a92c0839b67dda4fe0b53db26e8be157ff3f9d3ded5f19f361dfed95f973b11fce4ee8406c945674713003202455c5edad048bf5a301a00000000000000000000000000000000000000000000000000000000000000000880000000000000000"),
+  &hex!("f90257a03abad3137254524af5a0c67cdee2b8ab0ff5f8d8ef00e5c67b8c5a2bb6169d02a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d4934794c09fe6ff1404f67d341598fb290acf2897e6008fa012e879022845ec07115e4e86ed5ab7d32268c6aafa94f17e603ddffc903678f2a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010e8401c9c38080845f5e102ab86100000000000000000000000000000000000000000000000000000000000000000702bab6c9f618c069af06f178dd6351fc2dd296a726685e651900ff5b8e81053ef11438df2657d35ec17175dc56e4d9295e5787492a5e35b7362bd3ec0c9c4201a00000000000000000000000000000000000000000000000000000000000000000880000000000000000"),
+  &hex!("f90257a030b4bf731ac2feb602ba932884e9f984141b691b132015b2b997f4c9a5b11ebea01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d4934794d29f34a2e1bef6840cb8aa1c19973db40c7b5408a0f6757aded1fa03bd0d56d6463948213085a355b83f46e8798f5143c525794644a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020f8401c9c38080845f5e102db86100000000000000000000000000000000000000000000000000000000000000004ba680c59a3c556107075457670df41c3517037098e079b457b6cd750aa9ed91460ced43da06c017e4815c540d994893645fb9bd0c841f77ef4e1aa2770023ca01a00000000000000000000000000000000000000000000000000000000000000000880000000000000000"),
+  &hex!("f902a7a061e78fe842504bacffcaf61935d12a47e971a91f3781b3f4e146f5e3e09ab665a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347947fa00bcce4371d49a785ee6a9b47143995257d80a0109cfad692b1a185e4c2fdb9c317135222f532a8d19aa3d84771e7984e1b5008a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b901000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002108401c9c38080845f5e1030b8b100000000000000000000000000000000000000000000000000000000000000007fa00bcce4371d49a785ee6a9b47143995257d80c09fe6ff1404f67d341598fb290acf2897e6008fccf323fd8faf02be3b83a5bdf9ecc41b78c870edd29f34a2e1bef6840cb8aa1c19973db40c7b5408583eb55df86732b5c8f5abaab0d92098aa9f0da68599740bb5ac65937924589f6155a43b12fa7e417f12dc49f4d13c116662b48bde02d57d6e142b26505933f701a00000000000000000000000000000000000000000000000000000000000000000880000000000000000"),
+  &hex!("f90257a05b2d7ef02dfa4bef3fda2d9fa4419073f29bbc5bc8cee599b998ae5d56dca613a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d4934794c09fe6ff1404f67d341598fb290acf2897e6008fa0ccefbefc6b050307c11e0d09f391a15ca9cfc0b904f867acbd7093bd701daf74a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b901000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002118401c9c38080845f5e1033b86100000000000000000000000000000000000000000000000000000000000000000a60802c2111956a48e781fb027c85348a6d58aa4505cd469ca86d82afb0c9562a4b600ac7ea87bd48b2b95130a406273945f04511f9529e1449c1a5c657c50b01a00000000000000000000000000000000000000000000000000000000000000000880000000000000000"),
+  &hex!("f90257a032b22684bbca4cdb9d7a9d68ebf6e09fc83391fdc6f10d387c8ce6f44cd84576a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d4934794ccf323fd8faf02be3b83a5bdf9ecc41b78c870eda0dbad848c890ab0c17f28349f1ae275ac3c540860893043e804dc6fd6eeade6d7a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b901000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002128401c9c38080845f5e1036b861000000000000000000000000000000000000000000000000000000000000000029e9493f86d506770342078d5a57ee4ecf7d1f0ef5f3eba2a7328f8b69277c6322bf419bd070f26f1d4a040738adb59f42b1e5581689f640d27bd236db139ddd00a00000000000000000000000000000000000000000000000000000000000000000880000000000000000"),
+  &hex!("f90257a07da91f280c7b5e5bb788e2fdc3779ce6b6b5f532c0afddb9cec2b32fed3d8bcca01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d4934794d29f34a2e1bef6840cb8aa1c19973db40c7b5408a08ed5dafd76f65fdcc62fd083e63f1cea72d27130108a706a1f51c4366526117aa056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b901000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002138401c9c38080845f5e1039b86100000000000000000000000000000000000000000000000000000000000000006e209bc575314d767d4136051fb38daad1116f4aefde7e12b6dede46668b7e7626a07140c2ff6667032bedbe6e0812d2b4f957d4c6932cec697f76d301257eb801a00000000000000000000000000000000000000000000000000000000000000000880000000000000000"),
+  &hex!("f902a7a098c45d79b561ad895d1e4769b5de38f3f3fc07b8706e67f9814b8fda1b8c1f38a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347947fa00bcce4371d49a785ee6a9b47143995257d80a0744e061ee414ae9d48e79fe29149d36573717d2b7816594cc3ca4bca9559f4caa056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b901000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002148401c9c38080845f5e103cb8b100000000000000000000000000000000000000000000000000000000000000007fa00bcce4371d49a785ee6a9b47143995257d80c09fe6ff1404f67d341598fb290acf2897e6008fccf323fd8faf02be3b83a5bdf9ecc41b78c870edd29f34a2e1bef6840cb8aa1c19973db40c7b54089bc5c8199863eca7f777a33a490eab1fad4443b5f21b0c109f6ba5a8c2033b7f188b2602412748ee2d33ac49e45cafd437df64b8e5830d9ecd77264a8ca1c7fa00a00000000000000000000000000000000000000000000000000000000000000000880000000000000000"),
+  &hex!("f90257a07ea6df8c24b9c3fd4312bc05361055237a0f67711737ff0c0b7747cd01f35eeba01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d4934794c09fe6ff1404f67d341598fb290acf2897e6008fa09ec299fde9e7ff60a69e30723ed1b5ccea911de5dfe0404757f4fc04d096ebe6a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b901000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002158401c9c38080845f5e103fb86100000000000000000000000000000000000000000000000000000000000000003bfdfc33c4dbf258e0207ced52158b76dfda89699bdc7b49fbefed828f51eb2b689cb0f022d28672225a4100ee78288e550dfb8de8616f1958ff80704d14622500a00000000000000000000000000000000000000000000000000000000000000000880000000000000000"),
+  &hex!("f90257a059e64a5dd4650406deb3dc9f97167627f90bcfcb84534db3c611bf990f180949a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d4934794ccf323fd8faf02be3b83a5bdf9ecc41b78c870eda0ecf51757c5b3df3df076d9888a920b54e78fa378bf09c6195b3d130e93875273a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b901000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002168401c9c38080845f5e1042b8610000000000000000000000000000000000000000000000000000000000000000367eaabfd206cf1e3b36dd35c83a58c68baf1a48e8800acfcafe6e1aba31fb9838dd26b6b3be7600f9006e7ee48d83d2fa8eb8f5eb7ea5ddc799e8c5f9c6d76201a00000000000000000000000000000000000000000000000000000000000000000880000000000000000"),
+  &hex!("f90257a086d50b6b787200fa70b47ce43e6cad5761742094b2b66d71a4fc2d3ab731bc98a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d4934794d29f34a2e1bef6840cb8aa1c19973db40c7b5408a044a102fc1fc7c82f0aeefad5b9dce1bfa335802aa6631686806a1b9414aeacbca056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b901000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002178401c9c38080845f5e1045b86100000000000000000000000000000000000000000000000000000000000000002d3bf77552437956a30575cff16af3d6e0fd1b75e785ea670ef443e6b8f19b93537d0e411b9b04878d7739d5c1b3d385aab6e692d01075fda63dc21bc3e5a3dc01a00000000000000000000000000000000000000000000000000000000000000000880000000000000000"),
+  &hex!("f902a7a06ab3bbe5461017efc53874a285aaa7c4783192551bb2bb66b7cf2b5e0b9c4cb7a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347947fa00bcce4371d49a785ee6a9b47143995257d80a0a2a56660c0343891df9382b23486b8d086dc7e9bffdb39c453cbed69bb9545b4a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b901000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002188401c9c38080845f5e1048b8b100000000000000000000000000000000000000000000000000000000000000007fa00bcce4371d49a785ee6a9b47143995257d80c09fe6ff1404f67d341598fb290acf2897e6008fccf323fd8faf02be3b83a5bdf9ecc41b78c870edd29f34a2e1bef6840cb8aa1c19973db40c7b540808c4d2ba9ab9ee89092693d1babad11828fb03735faf4bf1d2be74c0fbfc06ea0a533e3523db7cab8ff3d174814ef96169e900961f2d612c9e80abd00ef1665800a00000000000000000000000000000000000000000000000000000000000000000880000000000000000"),
+];
+
+/// Headers 10 to 12 of a fork off block 9, all sealed out of turn.
+pub const FORKS: [&[u8]; 3] = [
+  &hex!("f90257a066bd88830230354bd60e24ee4dca812823fa9517fe8b180b97d2823b64368bd2a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d4934794d29f34a2e1bef6840cb8aa1c19973db40c7b5408a02964192b0c066e42f996b4e855df2b2a69a54db5a4bbc543a352f257b189378ca056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010a8401c9c38080845f5e101fb861000000000000000000000000000000000000000000000000000000000000000028d4345e3e0f49fe61fa8a22227d0e85983e37560e51143ec480b2a7876c0bb31fcaf72550a9be8f2fadd0595fa663bad01a40d52372116eb9b2695d29534dd701a00000000000000000000000000000000000000000000000000000000000000000880000000000000000"),
+  &hex!("f90257a0229eb0136c3daa20ab10b284381ff1935e394dbf29db8049e1c638c66ae8ba62a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347947fa00bcce4371d49a785ee6a9b47143995257d80a0bf635589e8ea2a89ec6a41aa58d20c201373816ac011d27241589fbc85b4acb3a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010b8401c9c38080845f5e1022b8610000000000000000000000000000000000000000000000000000000000000000efe07cc48e96806d8ed8b68fba002adbac0c583f8ae84397f0ccac88d4d514ac09ef5f334e7d0970666f87f9b18fdeb26b09133386f493596ba9a62929954ed200a00000000000000000000000000000000000000000000000000000000000000000880000000000000000"),
+  &hex!("f902a7a0490820d9667e0c0a0b79ac29915d541f8cdccbdef4fd90b3bc763df118649e8ea01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d4934794ccf323fd8faf02be3b83a5bdf9ecc41b78c870eda0ef4d3b474591e04a0667e1cb33d39db50c3e965e7d6692d151a43e6a3e3aaadfa056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010c8401c9c38080845f5e1025b8b100000000000000000000000000000000000000000000000000000000000000007fa00bcce4371d49a785ee6a9b47143995257d80c09fe6ff1404f67d341598fb290acf2897e6008fccf323fd8faf02be3b83a5bdf9ecc41b78c870edd29f34a2e1bef6840cb8aa1c19973db40c7b54080568550822843aff5044c1ea04e455218e9841e4b49f47569142742d24c3510464e25a96b846cba63a584d7ce6c702c05cce21198d3132f9eba321bee92a58b101a00000000000000000000000000000000000000000000000000000000000000000880000000000000000"),
+];
+
+/// Header 10 sealed in turn with the out of turn difficulty
+pub const BAD_DIFFICULTY: &[u8] = &hex!("f90257a066bd88830230354bd60e24ee4dca812823fa9517fe8b180b97d2823b64368bd2a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d4934794ccf323fd8faf02be3b83a5bdf9ecc41b78c870eda02964192b0c066e42f996b4e855df2b2a69a54db5a4bbc543a352f257b189378ca056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010a8401c9c38080845f5e101eb86100000000000000000000000000000000000000000000000000000000000000002cb75981439e664009d6f177f9fb278abe62fe5fdc676dc23b4be3c81b1db727030b538d5f0ec249f9f5b16b53375276bd13ee71db2588fe0642f05c601cd9a800a00000000000000000000000000000000000000000000000000000000000000000880000000000000000");
+/// Header 10 sealed by the signer of header 9
+pub const RECENTLY_SIGNED: &[u8] = &hex!("f90257a066bd88830230354bd60e24ee4dca812823fa9517fe8b180b97d2823b64368bd2a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347947fa00bcce4371d49a785ee6a9b47143995257d80a02964192b0c066e42f996b4e855df2b2a69a54db5a4bbc543a352f257b189378ca056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010a8401c9c38080845f5e101eb8610000000000000000000000000000000000000000000000000000000000000000fc4d69dd70cc7259e5d211f4f339b7792b31e3c75c5b3855ba1d58ca349cd9e37bfcdc7824010734f47a90c6100ee0be00ea25bb1bb496c53a810c9cccbeddff00a00000000000000000000000000000000000000000000000000000000000000000880000000000000000");
+/// Header 10 sealed by `keccak256("outsider")`
+pub const UNAUTHORIZED: &[u8] = &hex!("f90257a066bd88830230354bd60e24ee4dca812823fa9517fe8b180b97d2823b64368bd2a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347946ab133ce3481a06313b4e0b1bb810bcd670853a4a02964192b0c066e42f996b4e855df2b2a69a54db5a4bbc543a352f257b189378ca056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010a8401c9c38080845f5e101eb861000000000000000000000000000000000000000000000000000000000000000080f1a9534dbc630c030d8797715dbf3079bc92630a7df911f1fe02d8c84f1c6e32a35e65f8660da952a6eaf5f8b0f721821cc853a02355b0a63cdefa00e7027a00a00000000000000000000000000000000000000000000000000000000000000000880000000000000000");
+
+pub const HASH_9: H256 = H256(hex!(
+  "66bd88830230354bd60e24ee4dca812823fa9517fe8b180b97d2823b64368bd2"
+));
+pub const DEPOSIT_TX_HASH: H256 = H256(hex!(
+  "783678893c2f734bfd8d0064ca0325a5942157640c42a1ed928af3dd05ad08c8"
+));
+pub const DEPOSIT_TOPIC: H256 = H256(hex!(
+  "e1fffcc4923d04b559f4d29a8bfc6cda04eb5b0d3c460751c2402c5c5cc9109c"
+));
+pub const DEPOSIT_CONTRACT: H160 = H160(hex!("1111111111111111111111111111111111111111"));
+
+pub fn deposit_tx_proof() -> Vec<Vec<u8>> {
+  vec![
+    hex!("f851a02bfebc5dbffb9de6ab42e5114123d13cbc5b7254a7bab776c81fadd0953779b880808080808080a0b80a437696c757fecf51b49cb205b1f6429b3e5f2a9ea3ddff811769a22d0b6a8080808080808080").to_vec(),
+    hex!("f87b31b878f876028502540be40083015f9094111111111111111111111111111111111111111180906465706f7369742875696e74323536298194a07d836ba56b88abad41af9a2d0e7a278279f5d24a80179a540545eb5cc8621d6ea02217acd7ad0f4746f99625a00c7d8d3c7b2cffdf5118f5fc5faca80382fd852f").to_vec(),
+  ]
+}
+
+pub fn deposit_receipt_proof() -> Vec<Vec<u8>> {
+  vec![
+    hex!("f851a0a46814bb9b6050e49bc5c7fdcacdf94f381d3b5ef01375d0746950da8fb115f480808080808080a0fd636d1bdc606811a92dfe33f402da77e6189705a5f17fe548e6281646deeb428080808080808080").to_vec(),
+    hex!("f901c831b901c402f901c00183013c68b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f8b6f838942222222222222222222222222222222222222222e1a0db4cacecca9916abc8b926fb896c2c0a31875312259928578c5c0e608894b3b980f87a941111111111111111111111111111111111111111f842a0e1fffcc4923d04b559f4d29a8bfc6cda04eb5b0d3c460751c2402c5c5cc9109ca0000000000000000000000000243e34c336f3d2c08bbc79b99e6bca1fa7c58595a000000000000000000000000000000000000000000000000000000000000003e8").to_vec(),
+  ]
+}
+
+pub fn other_tx_proof() -> Vec<Vec<u8>> {
+  vec![
+    hex!("f851a02bfebc5dbffb9de6ab42e5114123d13cbc5b7254a7bab776c81fadd0953779b880808080808080a0b80a437696c757fecf51b49cb205b1f6429b3e5f2a9ea3ddff811769a22d0b6a8080808080808080").to_vec(),
+    hex!("f87a30b877f875018502540be4008252089422222222222222222222222222222222222222228090747830207061796c6f616420646174618193a0882385b7bb5b36a04b53a28a7415b3dfb3f510dc59defbb70c85c8a78c0a2b08a0a48cfc2a9cd94251ec4ec68d847f0d69f8ea7b3d66bb6381af3cfe0960fe4610").to_vec(),
+  ]
+}
+
+pub fn other_receipt_proof() -> Vec<Vec<u8>> {
+  vec![
+    hex!("f851a0a46814bb9b6050e49bc5c7fdcacdf94f381d3b5ef01375d0746950da8fb115f480808080808080a0fd636d1bdc606811a92dfe33f402da77e6189705a5f17fe548e6281646deeb428080808080808080").to_vec(),
+    hex!("f9014a30b90146f9014301825208b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f83af838942222222222222222222222222222222222222222e1a0db4cacecca9916abc8b926fb896c2c0a31875312259928578c5c0e608894b3b980").to_vec(),
+  ]
+}
+
+pub fn validators(set: &str) -> Vec<H160> {
+  match set {
+    "S1" => vec![
+      H160(hex!("7fa00bcce4371d49a785ee6a9b47143995257d80")),
+      H160(hex!("ccf323fd8faf02be3b83a5bdf9ecc41b78c870ed")),
+      H160(hex!("d29f34a2e1bef6840cb8aa1c19973db40c7b5408")),
+    ],
+    "S2" => vec![
+      H160(hex!("7fa00bcce4371d49a785ee6a9b47143995257d80")),
+      H160(hex!("c09fe6ff1404f67d341598fb290acf2897e6008f")),
+      H160(hex!("ccf323fd8faf02be3b83a5bdf9ecc41b78c870ed")),
+      H160(hex!("d29f34a2e1bef6840cb8aa1c19973db40c7b5408")),
+    ],
+    _ => vec![],
+  }
+}
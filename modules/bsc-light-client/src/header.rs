@@ -0,0 +1,158 @@
+// Copyright (C) 2021 Clover Network
+// This file is part of Clover.
+
+//! Parlia headers of the BSC.
+
+use crate::rlp::{self, Item};
+use clover_eth_signing::{recover, EthereumSignature};
+use sp_core::{H160, H256};
+use sp_io::hashing::keccak_256;
+use sp_std::prelude::*;
+
+/// Length of the vanity prefix of the extra data.
+pub const EXTRA_VANITY: usize = 32;
+/// Length of the validator seal suffix of the extra data.
+pub const EXTRA_SEAL: usize = 65;
+
+/// A decoded header.
+#[derive(Clone, PartialEq, Eq, sp_runtime::RuntimeDebug)]
+pub struct Header {
+  pub parent_hash: H256,
+  pub uncle_hash: H256,
+  pub coinbase: H160,
+  pub state_root: H256,
+  pub transactions_root: H256,
+  pub receipts_root: H256,
+  pub logs_bloom: Vec<u8>,
+  pub difficulty: u64,
+  pub number: u64,
+  pub gas_limit: u64,
+  pub gas_used: u64,
+  pub timestamp: u64,
+  pub extra_data: Vec<u8>,
+  pub mix_digest: H256,
+  pub nonce: Vec<u8>,
+}
+
+impl Header {
+  /// Decode the RLP encoded header.
+  pub fn decode(data: &[u8]) -> Option<Self> {
+    let items = Item::decode(data)?.list()?;
+    if items.len() != 15 {
+      return None;
+    }
+    let header = Header {
+      parent_hash: items[0].h256()?,
+      uncle_hash: items[1].h256()?,
+      coinbase: items[2].h160()?,
+      state_root: items[3].h256()?,
+      transactions_root: items[4].h256()?,
+      receipts_root: items[5].h256()?,
+      logs_bloom: items[6].bytes()?.to_vec(),
+      difficulty: items[7].u64()?,
+      number: items[8].u64()?,
+      gas_limit: items[9].u64()?,
+      gas_used: items[10].u64()?,
+      timestamp: items[11].u64()?,
+      extra_data: items[12].bytes()?.to_vec(),
+      mix_digest: items[13].h256()?,
+      nonce: items[14].bytes()?.to_vec(),
+    };
+    if header.extra_data.len() < EXTRA_VANITY + EXTRA_SEAL {
+      return None;
+    }
+    Some(header)
+  }
+
+  fn encode_fields(&self, extra_data: &[u8]) -> Vec<Vec<u8>> {
+    vec![
+      rlp::encode_bytes(self.parent_hash.as_bytes()),
+      rlp::encode_bytes(self.uncle_hash.as_bytes()),
+      rlp::encode_bytes(self.coinbase.as_bytes()),
+      rlp::encode_bytes(self.state_root.as_bytes()),
+      rlp::encode_bytes(self.transactions_root.as_bytes()),
+      rlp::encode_bytes(self.receipts_root.as_bytes()),
+      rlp::encode_bytes(&self.logs_bloom),
+      rlp::encode_u64(self.difficulty),
+      rlp::encode_u64(self.number),
+      rlp::encode_u64(self.gas_limit),
+      rlp::encode_u64(self.gas_used),
+      rlp::encode_u64(self.timestamp),
+      rlp::encode_bytes(extra_data),
+      rlp::encode_bytes(self.mix_digest.as_bytes()),
+      rlp::encode_bytes(&self.nonce),
+    ]
+  }
+
+  /// The block hash, of the canonical encoding of the header.
+  pub fn hash(&self) -> H256 {
+    H256(keccak_256(&rlp::encode_list(
+      &self.encode_fields(&self.extra_data),
+    )))
+  }
+
+  /// The hash signed by the validator, of the chain id and the header without the seal.
+  pub fn seal_hash(&self, chain_id: u64) -> H256 {
+    let unsealed = &self.extra_data[..self.extra_data.len() - EXTRA_SEAL];
+    let mut fields = vec![rlp::encode_u64(chain_id)];
+    fields.extend(self.encode_fields(unsealed));
+    H256(keccak_256(&rlp::encode_list(&fields)))
+  }
+
+  /// The validator which sealed the header.
+  pub fn signer(&self, chain_id: u64) -> Option<H160> {
+    let mut seal = [0u8; EXTRA_SEAL];
+    seal.copy_from_slice(&self.extra_data[self.extra_data.len() - EXTRA_SEAL..]);
+    recover(
+      &EthereumSignature::Standard(seal),
+      &self.seal_hash(chain_id).0,
+      chain_id,
+    )
+    .map(H160)
+  }
+
+  /// The validator set in the extra data, only epoch headers have one.
+  pub fn validators(&self) -> Option<Vec<H160>> {
+    let data = &self.extra_data[EXTRA_VANITY..self.extra_data.len() - EXTRA_SEAL];
+    if data.is_empty() || data.len() % 20 != 0 {
+      return None;
+    }
+    Some(data.chunks(20).map(H160::from_slice).collect())
+  }
+}
+
+/// Decode a receipt, legacy or typed, to its status and logs.
+pub fn decode_receipt(data: &[u8]) -> Option<(bool, Vec<clover_traits::EvmLog>)> {
+  // typed receipts are prefixed by the transaction type
+  let data = match data.first() {
+    Some(t) if *t < 0x7f => &data[1..],
+    _ => data,
+  };
+  let items = Item::decode(data)?.list()?;
+  if items.len() != 4 {
+    return None;
+  }
+  // a state root before byzantium, the status after
+  let status = items[0].bytes()?;
+  let success = status.len() == 32 || status == &[1u8][..];
+  let logs = items[3]
+    .list()?
+    .iter()
+    .map(|log| {
+      let fields = log.list()?;
+      if fields.len() != 3 {
+        return None;
+      }
+      Some(clover_traits::EvmLog {
+        address: fields[0].h160()?,
+        topics: fields[1]
+          .list()?
+          .iter()
+          .map(|topic| topic.h256())
+          .collect::<Option<Vec<_>>>()?,
+        data: fields[2].bytes()?.to_vec(),
+      })
+    })
+    .collect::<Option<Vec<_>>>()?;
+  Some((success, logs))
+}
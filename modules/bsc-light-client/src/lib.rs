@@ -0,0 +1,534 @@
+// Copyright (C) 2021 Clover Network
+// This file is part of Clover.
+
+//! Light client of the Parlia consensus of the BSC.
+//!
+//! Headers are imported on top of a trusted epoch header, every header must be sealed by a
+//! validator of the set of its branch which did not seal any of its last `len / 2` ancestors,
+//! with the difficulty of its turn. Validator sets are read from the epoch headers and take
+//! effect `len / 2` blocks after them. Forks are kept and the branch with the highest total
+//! difficulty is the best chain. A header of the best chain is final once `len / 2 + 1` blocks
+//! are built on top of it, so distinct validators signed its descendants, and no fork below the
+//! last final header is accepted. Headers older than `HeadersToKeep` blocks before the last
+//! final header are pruned a few numbers per block. Receipts of final blocks are verified by
+//! the `VerifyReceipt` implementation of the module.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use clover_traits::{ReceiptProof, VerifiedReceipt, VerifyReceipt};
+use codec::{Decode, Encode};
+use frame_support::{ensure, traits::Get, weights::Weight};
+use sp_core::{H160, H256};
+use sp_io::hashing::keccak_256;
+use sp_runtime::{DispatchError, DispatchResult, RuntimeDebug};
+use sp_std::prelude::*;
+
+pub use header::Header;
+pub use pallet::*;
+
+pub mod header;
+mod rlp;
+mod trie;
+
+#[cfg(test)]
+mod fixtures;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+/// Maximum number of headers imported by one `submit_headers`.
+pub const MAX_HEADERS_PER_CALL: usize = 64;
+
+/// Maximum number of validators of a set, it bounds the ancestors read to check the recent
+/// signers and the canonical hashes rewritten by a reorganization.
+pub const MAX_VALIDATORS: usize = 32;
+
+/// Maximum number of headers kept for one block number.
+pub const MAX_FORKS_PER_NUMBER: usize = 4;
+
+/// Maximum number of block numbers pruned in one block.
+pub const MAX_PRUNED_PER_BLOCK: u64 = 16;
+
+/// The part of an imported header kept by the light client.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct HeaderInfo {
+  pub number: u64,
+  pub parent_hash: H256,
+  /// The validator which sealed the header
+  pub coinbase: H160,
+  pub timestamp: u64,
+  pub transactions_root: H256,
+  pub receipts_root: H256,
+  /// Sum of the difficulties of the branch since the trusted header
+  pub total_difficulty: u64,
+  /// Hash of the epoch header carrying the validator set which sealed the header
+  pub validator_set: H256,
+  /// Validator set announced on the branch and the block after which it applies
+  pub next_validator_set: Option<(u64, H256)>,
+}
+
+impl HeaderInfo {
+  fn new(
+    header: &Header,
+    total_difficulty: u64,
+    validator_set: H256,
+    next_validator_set: Option<(u64, H256)>,
+  ) -> Self {
+    HeaderInfo {
+      number: header.number,
+      parent_hash: header.parent_hash,
+      coinbase: header.coinbase,
+      timestamp: header.timestamp,
+      transactions_root: header.transactions_root,
+      receipts_root: header.receipts_root,
+      total_difficulty,
+      validator_set,
+      next_validator_set,
+    }
+  }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+  use super::*;
+  use frame_support::pallet_prelude::*;
+  use frame_system::pallet_prelude::*;
+
+  #[pallet::config]
+  pub trait Config: frame_system::Config {
+    type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+    /// The chain id of the tracked chain, part of the sealed data
+    #[pallet::constant]
+    type ChainId: Get<u64>;
+    /// Number of blocks of an epoch, headers of the first block of an epoch carry the
+    /// validator set
+    #[pallet::constant]
+    type Epoch: Get<u64>;
+    /// Number of headers kept before the last final header, at least two epochs so the
+    /// validator sets sealing the headers which are not final are kept
+    #[pallet::constant]
+    type HeadersToKeep: Get<u64>;
+  }
+
+  #[pallet::pallet]
+  pub struct Pallet<T>(sp_std::marker::PhantomData<T>);
+
+  #[pallet::hooks]
+  impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+    fn on_initialize(_now: T::BlockNumber) -> Weight {
+      Self::prune(MAX_PRUNED_PER_BLOCK)
+    }
+
+    fn integrity_test() {
+      assert!(T::HeadersToKeep::get() >= 2 * T::Epoch::get());
+    }
+  }
+
+  #[pallet::error]
+  pub enum Error<T> {
+    /// No trusted header was set
+    NotInitialized,
+    /// The header can not be decoded
+    InvalidHeader,
+    /// The trusted header must be an epoch header with a validator set
+    NotEpochHeader,
+    /// Too many headers in one call
+    TooManyHeaders,
+    /// The parent of the header is not imported
+    UnknownParent,
+    /// The timestamp is not after the one of the parent
+    InvalidTimestamp,
+    /// The seal can not be recovered or is not of the coinbase
+    InvalidSeal,
+    /// The signer is not in the validator set
+    UnauthorizedSigner,
+    /// The signer sealed one of the last `len / 2` blocks
+    RecentlySigned,
+    /// The difficulty does not match the turn of the signer
+    WrongDifficulty,
+    /// An epoch header without a validator set
+    MissingValidators,
+    /// A validator set in a header which is not an epoch header
+    UnexpectedValidators,
+    /// Receipts of the chain are not tracked by the light client
+    UnsupportedChain,
+    /// The block is not a canonical block of the light client
+    UnknownHeader,
+    /// The block is not final yet
+    HeaderNotFinalized,
+    /// The trie proof does not match the roots of the header
+    InvalidProof,
+    /// The proven receipt can not be decoded
+    InvalidReceipt,
+    /// The header is already imported
+    AlreadyImported,
+    /// The header is not a descendant of the last final header
+    ConflictsWithFinalized,
+    /// The header is more than `len / 2 + 1` blocks above the head
+    TooFarAhead,
+    /// Too many headers of the same number
+    TooManyForks,
+    /// The validator set is larger than `MAX_VALIDATORS`
+    TooManyValidators,
+  }
+
+  #[pallet::event]
+  #[pallet::generate_deposit(pub(super) fn deposit_event)]
+  pub enum Event<T: Config> {
+    /// Light client reset to a trusted header. \[number, hash\]
+    Initialized(u64, H256),
+    /// Header imported. \[number, hash, signer\]
+    HeaderImported(u64, H256, H160),
+    /// Validator set announced, it applies to the blocks after `number`.
+    /// \[number, validators\]
+    ValidatorSetChanged(u64, Vec<H160>),
+    /// The best chain switched to the branch of the header, `depth` blocks of the previous
+    /// best chain are reverted. \[number, hash, depth\]
+    Reorganized(u64, H256, u64),
+    /// Reset to a trusted header once the stored headers are pruned. \[number, hash\]
+    InitializationScheduled(u64, H256),
+  }
+
+  /// Validator sets by the hash of the epoch header carrying them
+  #[pallet::storage]
+  #[pallet::getter(fn validator_set)]
+  pub(super) type ValidatorSets<T: Config> = StorageMap<_, Identity, H256, Vec<H160>, ValueQuery>;
+
+  /// Imported headers by hash
+  #[pallet::storage]
+  #[pallet::getter(fn headers)]
+  pub(super) type Headers<T: Config> = StorageMap<_, Identity, H256, HeaderInfo, OptionQuery>;
+
+  /// Hashes of the imported headers by number, forks included
+  #[pallet::storage]
+  #[pallet::getter(fn numbered_headers)]
+  pub(super) type NumberedHeaders<T: Config> =
+    StorageMap<_, Twox64Concat, u64, Vec<H256>, ValueQuery>;
+
+  /// Hashes of the headers of the best chain by number
+  #[pallet::storage]
+  #[pallet::getter(fn canonical_hash)]
+  pub(super) type CanonicalHashes<T: Config> = StorageMap<_, Twox64Concat, u64, H256, OptionQuery>;
+
+  /// Number and hash of the head of the best chain, the branch with the highest total
+  /// difficulty
+  #[pallet::storage]
+  #[pallet::getter(fn head)]
+  pub(super) type Head<T: Config> = StorageValue<_, (u64, H256), OptionQuery>;
+
+  /// Number and hash of the last final header of the best chain
+  #[pallet::storage]
+  #[pallet::getter(fn finalized)]
+  pub(super) type Finalized<T: Config> = StorageValue<_, (u64, H256), OptionQuery>;
+
+  /// Lowest and highest numbers of the stored headers
+  #[pallet::storage]
+  #[pallet::getter(fn stored_range)]
+  pub(super) type StoredRange<T: Config> = StorageValue<_, (u64, u64), OptionQuery>;
+
+  /// Trusted header to reset to once the stored headers are pruned
+  #[pallet::storage]
+  #[pallet::getter(fn pending_initialization)]
+  pub(super) type PendingInitialization<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+  #[pallet::call]
+  impl<T: Config> Pallet<T> {
+    /// Reset the light client to the trusted epoch header, whose validator set seals the
+    /// following headers. Stored headers are pruned first, over the next blocks.
+    #[pallet::weight(T::DbWeight::get().reads_writes(1, 8))]
+    #[frame_support::transactional]
+    pub fn initialize(origin: OriginFor<T>, header: Vec<u8>) -> DispatchResultWithPostInfo {
+      ensure_root(origin)?;
+      let decoded = Header::decode(&header).ok_or(Error::<T>::InvalidHeader)?;
+      ensure!(
+        decoded.number % T::Epoch::get() == 0,
+        Error::<T>::NotEpochHeader
+      );
+      let validators = decoded.validators().ok_or(Error::<T>::NotEpochHeader)?;
+      ensure!(
+        validators.len() <= MAX_VALIDATORS,
+        Error::<T>::TooManyValidators
+      );
+
+      if Self::stored_range().is_none() {
+        Self::insert_trusted_header(&decoded, validators);
+      } else {
+        Head::<T>::kill();
+        Finalized::<T>::kill();
+        PendingInitialization::<T>::put(header);
+        Self::deposit_event(Event::InitializationScheduled(
+          decoded.number,
+          decoded.hash(),
+        ));
+      }
+      Ok(().into())
+    }
+
+    /// Import headers in order, each one extends the head or another imported header.
+    #[pallet::weight(
+      (50_000_000 as Weight)
+        .saturating_add(T::DbWeight::get().reads_writes(
+          12 + 2 * MAX_VALIDATORS as Weight,
+          8 + 2 * MAX_VALIDATORS as Weight,
+        ))
+        .saturating_mul(headers.len() as Weight)
+    )]
+    #[frame_support::transactional]
+    pub fn submit_headers(
+      origin: OriginFor<T>,
+      headers: Vec<Vec<u8>>,
+    ) -> DispatchResultWithPostInfo {
+      ensure_signed(origin)?;
+      ensure!(
+        headers.len() <= MAX_HEADERS_PER_CALL,
+        Error::<T>::TooManyHeaders
+      );
+      for header in headers {
+        let header = Header::decode(&header).ok_or(Error::<T>::InvalidHeader)?;
+        Self::import_header(header)?;
+      }
+      Ok(().into())
+    }
+  }
+}
+
+impl<T: Config> Pallet<T> {
+  fn insert_trusted_header(header: &Header, validators: Vec<H160>) {
+    let hash = header.hash();
+    ValidatorSets::<T>::insert(hash, validators);
+    Headers::<T>::insert(hash, HeaderInfo::new(header, header.difficulty, hash, None));
+    NumberedHeaders::<T>::insert(header.number, vec![hash]);
+    CanonicalHashes::<T>::insert(header.number, hash);
+    Head::<T>::put((header.number, hash));
+    Finalized::<T>::put((header.number, hash));
+    StoredRange::<T>::put((header.number, header.number));
+    Self::deposit_event(Event::Initialized(header.number, hash));
+  }
+
+  fn import_header(header: Header) -> DispatchResult {
+    let (head_number, head_hash) = Self::head().ok_or(Error::<T>::NotInitialized)?;
+    let (finalized, _) = Self::finalized().ok_or(Error::<T>::NotInitialized)?;
+    let hash = header.hash();
+    ensure!(
+      !Headers::<T>::contains_key(hash),
+      Error::<T>::AlreadyImported
+    );
+    ensure!(
+      header.number > finalized,
+      Error::<T>::ConflictsWithFinalized
+    );
+    let parent = Self::headers(header.parent_hash)
+      .filter(|parent| parent.number + 1 == header.number)
+      .ok_or(Error::<T>::UnknownParent)?;
+    let mut numbered = Self::numbered_headers(header.number);
+    ensure!(
+      numbered.len() < MAX_FORKS_PER_NUMBER,
+      Error::<T>::TooManyForks
+    );
+    ensure!(
+      header.timestamp > parent.timestamp,
+      Error::<T>::InvalidTimestamp
+    );
+
+    let (validator_set, mut next_validator_set) = match parent.next_validator_set {
+      Some((apply_after, next)) if header.number > apply_after => (next, None),
+      next => (parent.validator_set, next),
+    };
+    let validators = Self::validator_set(validator_set);
+    let recent = (validators.len() / 2) as u64;
+    ensure!(
+      header.number <= head_number + recent + 1,
+      Error::<T>::TooFarAhead
+    );
+
+    let signer = header
+      .signer(T::ChainId::get())
+      .filter(|signer| signer == &header.coinbase)
+      .ok_or(Error::<T>::InvalidSeal)?;
+    ensure!(validators.contains(&signer), Error::<T>::UnauthorizedSigner);
+    let mut ancestor = Some(parent.clone());
+    for _ in 0..recent {
+      match ancestor {
+        Some(info) => {
+          ensure!(info.coinbase != signer, Error::<T>::RecentlySigned);
+          ancestor = Self::headers(info.parent_hash);
+        }
+        None => break,
+      }
+    }
+    let in_turn = validators[(header.number % validators.len() as u64) as usize] == signer;
+    ensure!(
+      header.difficulty == if in_turn { 2 } else { 1 },
+      Error::<T>::WrongDifficulty
+    );
+
+    match header.validators() {
+      Some(next) => {
+        ensure!(
+          header.number % T::Epoch::get() == 0,
+          Error::<T>::UnexpectedValidators
+        );
+        ensure!(next.len() <= MAX_VALIDATORS, Error::<T>::TooManyValidators);
+        let apply_after = header.number + recent;
+        ValidatorSets::<T>::insert(hash, next.clone());
+        next_validator_set = Some((apply_after, hash));
+        Self::deposit_event(Event::ValidatorSetChanged(apply_after, next));
+      }
+      None => ensure!(
+        header.number % T::Epoch::get() != 0,
+        Error::<T>::MissingValidators
+      ),
+    }
+
+    let info = HeaderInfo::new(
+      &header,
+      parent.total_difficulty.saturating_add(header.difficulty),
+      validator_set,
+      next_validator_set,
+    );
+    Headers::<T>::insert(hash, &info);
+    numbered.push(hash);
+    NumberedHeaders::<T>::insert(header.number, numbered);
+    if let Some((lowest, highest)) = Self::stored_range() {
+      if header.number > highest {
+        StoredRange::<T>::put((lowest, header.number));
+      }
+    }
+    Self::deposit_event(Event::HeaderImported(header.number, hash, signer));
+
+    let head = Self::headers(head_hash).ok_or(Error::<T>::NotInitialized)?;
+    if info.total_difficulty > head.total_difficulty {
+      Self::set_head(hash, &info, head_number, finalized)?;
+    }
+    Ok(())
+  }
+
+  /// Make the header the head of the best chain. Canonical hashes are rewritten down to the
+  /// common ancestor with the previous best chain, which must not be below the final header,
+  /// and the header `len / 2 + 1` blocks below the head becomes final.
+  fn set_head(hash: H256, info: &HeaderInfo, head_number: u64, finalized: u64) -> DispatchResult {
+    for number in info.number + 1..=head_number {
+      CanonicalHashes::<T>::remove(number);
+    }
+    let (mut number, mut ancestor, mut parent_hash) = (info.number, hash, info.parent_hash);
+    while Self::canonical_hash(number) != Some(ancestor) {
+      ensure!(number > finalized, Error::<T>::ConflictsWithFinalized);
+      CanonicalHashes::<T>::insert(number, ancestor);
+      let parent = Self::headers(parent_hash).ok_or(Error::<T>::UnknownParent)?;
+      number = parent.number;
+      ancestor = parent_hash;
+      parent_hash = parent.parent_hash;
+    }
+    if number < head_number {
+      Self::deposit_event(Event::Reorganized(info.number, hash, head_number - number));
+    }
+    Head::<T>::put((info.number, hash));
+
+    let confirmations = (Self::validator_set(info.validator_set).len() / 2 + 1) as u64;
+    if let Some(final_number) = info.number.checked_sub(confirmations) {
+      if final_number > finalized {
+        if let Some(final_hash) = Self::canonical_hash(final_number) {
+          Finalized::<T>::put((final_number, final_hash));
+        }
+      }
+    }
+    Ok(())
+  }
+
+  /// Prune up to `max` numbers older than `HeadersToKeep` blocks before the final header, or
+  /// all stored numbers when a reset is pending, which is applied once they are pruned.
+  fn prune(max: u64) -> Weight {
+    let (lowest, highest) = match Self::stored_range() {
+      Some(range) => range,
+      None => return T::DbWeight::get().reads(1),
+    };
+    let pending = PendingInitialization::<T>::exists();
+    let end = if pending {
+      highest.saturating_add(1)
+    } else {
+      Self::finalized().map_or(lowest, |(finalized, _)| {
+        finalized.saturating_sub(T::HeadersToKeep::get())
+      })
+    }
+    .min(lowest.saturating_add(max));
+
+    let (mut reads, mut writes): (Weight, Weight) = (3, 0);
+    for number in lowest..end {
+      let hashes = NumberedHeaders::<T>::take(number);
+      for hash in &hashes {
+        Headers::<T>::remove(hash);
+        ValidatorSets::<T>::remove(hash);
+      }
+      CanonicalHashes::<T>::remove(number);
+      reads += 1;
+      writes += 2 + 2 * hashes.len() as Weight;
+    }
+
+    if end > highest {
+      StoredRange::<T>::kill();
+      writes += 1;
+      if let Some(header) = PendingInitialization::<T>::take().and_then(|h| Header::decode(&h)) {
+        // the validator set is checked by `initialize`
+        let validators = header.validators().unwrap_or_default();
+        Self::insert_trusted_header(&header, validators);
+        writes += 9;
+      }
+    } else if end > lowest {
+      StoredRange::<T>::put((end, highest));
+      writes += 1;
+    }
+    T::DbWeight::get().reads_writes(reads, writes)
+  }
+
+  /// The validator set which sealed the head.
+  pub fn validators() -> Vec<H160> {
+    Self::head()
+      .and_then(|(_, hash)| Self::headers(hash))
+      .map(|head| Self::validator_set(head.validator_set))
+      .unwrap_or_default()
+  }
+
+  /// The validator set announced on the best chain and the block after which it applies.
+  pub fn next_validators() -> Option<(u64, Vec<H160>)> {
+    let head = Self::head().and_then(|(_, hash)| Self::headers(hash))?;
+    head
+      .next_validator_set
+      .map(|(apply_after, set)| (apply_after, Self::validator_set(set)))
+  }
+
+  /// Whether the block of the best chain is final.
+  pub fn is_finalized(number: u64) -> bool {
+    Self::finalized().map_or(false, |(finalized, _)| number <= finalized)
+  }
+}
+
+impl<T: Config> VerifyReceipt for Pallet<T> {
+  fn verify_receipt(chain_id: u64, proof: &ReceiptProof) -> Result<VerifiedReceipt, DispatchError> {
+    ensure!(chain_id == T::ChainId::get(), Error::<T>::UnsupportedChain);
+    let header = Self::headers(proof.block_hash).ok_or(Error::<T>::UnknownHeader)?;
+    ensure!(
+      Self::canonical_hash(header.number) == Some(proof.block_hash),
+      Error::<T>::UnknownHeader
+    );
+    ensure!(
+      Self::is_finalized(header.number),
+      Error::<T>::HeaderNotFinalized
+    );
+
+    let key = rlp::encode_u64(proof.tx_index);
+    let tx = trie::verify_proof(&header.transactions_root, &key, &proof.tx_proof)
+      .ok_or(Error::<T>::InvalidProof)?;
+    let receipt = trie::verify_proof(&header.receipts_root, &key, &proof.receipt_proof)
+      .ok_or(Error::<T>::InvalidProof)?;
+    let (success, logs) = header::decode_receipt(&receipt).ok_or(Error::<T>::InvalidReceipt)?;
+
+    Ok(VerifiedReceipt {
+      tx_hash: H256(keccak_256(&tx)),
+      block_number: header.number,
+      success,
+      logs,
+    })
+  }
+}
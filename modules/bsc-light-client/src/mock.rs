@@ -0,0 +1,71 @@
+// Copyright (C) 2021 Clover Network
+// This file is part of Clover.
+
+use super::*;
+use crate as bsc_light_client;
+
+use frame_support::parameter_types;
+use sp_runtime::{
+  testing::Header,
+  traits::{BlakeTwo256, IdentityLookup},
+};
+
+parameter_types! {
+    pub const BlockHashCount: u32 = 250;
+}
+impl frame_system::Config for Test {
+  type BaseCallFilter = ();
+  type BlockWeights = ();
+  type BlockLength = ();
+  type Origin = Origin;
+  type Call = Call;
+  type Index = u64;
+  type BlockNumber = u64;
+  type Hash = H256;
+  type Hashing = BlakeTwo256;
+  type AccountId = u64;
+  type Lookup = IdentityLookup<u64>;
+  type Header = Header;
+  type Event = ();
+  type BlockHashCount = BlockHashCount;
+  type DbWeight = ();
+  type Version = ();
+  type PalletInfo = PalletInfo;
+  type AccountData = ();
+  type OnNewAccount = ();
+  type OnKilledAccount = ();
+  type SystemWeightInfo = ();
+  type SS58Prefix = ();
+}
+
+parameter_types! {
+    pub const ChainId: u64 = 56;
+    pub const Epoch: u64 = 4;
+    pub const HeadersToKeep: u64 = 8;
+}
+impl Config for Test {
+  type Event = ();
+  type ChainId = ChainId;
+  type Epoch = Epoch;
+  type HeadersToKeep = HeadersToKeep;
+}
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+  pub enum Test where
+    Block = Block,
+    NodeBlock = Block,
+    UncheckedExtrinsic = UncheckedExtrinsic
+  {
+    System: frame_system::{Module, Call, Config, Storage, Event<T>},
+    BscLightClient: bsc_light_client::{Module, Call, Storage, Event<T>},
+  }
+);
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+  frame_system::GenesisConfig::default()
+    .build_storage::<Test>()
+    .unwrap()
+    .into()
+}
@@ -0,0 +1,145 @@
+// Copyright (C) 2021 Clover Network
+// This file is part of Clover.
+
+//! Minimal RLP for headers, receipts and trie nodes.
+
+use sp_core::{H160, H256};
+use sp_std::prelude::*;
+
+/// A decoded RLP item borrowing the encoded data.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Item<'a> {
+  /// The whole encoding of the item
+  pub raw: &'a [u8],
+  /// The payload of the item, the encoded items of a list
+  pub payload: &'a [u8],
+  pub is_list: bool,
+}
+
+impl<'a> Item<'a> {
+  /// Decode the item which takes all of `data`.
+  pub fn decode(data: &'a [u8]) -> Option<Self> {
+    let (item, rest) = Self::decode_first(data)?;
+    if rest.is_empty() {
+      Some(item)
+    } else {
+      None
+    }
+  }
+
+  /// Decode the first item of `data` and return the rest.
+  pub fn decode_first(data: &'a [u8]) -> Option<(Self, &'a [u8])> {
+    let prefix = *data.first()?;
+    let (is_list, offset, len) = match prefix {
+      0x00..=0x7f => (false, 0, 1),
+      0x80..=0xb7 => (false, 1, (prefix - 0x80) as usize),
+      0xb8..=0xbf => {
+        let len_of_len = (prefix - 0xb7) as usize;
+        (
+          false,
+          1 + len_of_len,
+          decode_len(data.get(1..1 + len_of_len)?)?,
+        )
+      }
+      0xc0..=0xf7 => (true, 1, (prefix - 0xc0) as usize),
+      0xf8..=0xff => {
+        let len_of_len = (prefix - 0xf7) as usize;
+        (
+          true,
+          1 + len_of_len,
+          decode_len(data.get(1..1 + len_of_len)?)?,
+        )
+      }
+    };
+    let end = offset.checked_add(len)?;
+    let item = Item {
+      raw: data.get(..end)?,
+      payload: data.get(offset..end)?,
+      is_list,
+    };
+    Some((item, &data[end..]))
+  }
+
+  pub fn bytes(&self) -> Option<&'a [u8]> {
+    if self.is_list {
+      None
+    } else {
+      Some(self.payload)
+    }
+  }
+
+  pub fn list(&self) -> Option<Vec<Item<'a>>> {
+    if !self.is_list {
+      return None;
+    }
+    let mut items = Vec::new();
+    let mut rest = self.payload;
+    while !rest.is_empty() {
+      let (item, next) = Self::decode_first(rest)?;
+      items.push(item);
+      rest = next;
+    }
+    Some(items)
+  }
+
+  pub fn u64(&self) -> Option<u64> {
+    let bytes = self.bytes()?;
+    if bytes.len() > 8 {
+      return None;
+    }
+    Some(bytes.iter().fold(0u64, |n, b| (n << 8) | *b as u64))
+  }
+
+  pub fn h256(&self) -> Option<H256> {
+    self.bytes().filter(|b| b.len() == 32).map(H256::from_slice)
+  }
+
+  pub fn h160(&self) -> Option<H160> {
+    self.bytes().filter(|b| b.len() == 20).map(H160::from_slice)
+  }
+}
+
+fn decode_len(bytes: &[u8]) -> Option<usize> {
+  if bytes.len() > sp_std::mem::size_of::<usize>() {
+    return None;
+  }
+  Some(bytes.iter().fold(0usize, |n, b| (n << 8) | *b as usize))
+}
+
+fn encode_len(len: usize, offset: u8, out: &mut Vec<u8>) {
+  if len <= 55 {
+    out.push(offset + len as u8);
+  } else {
+    let bytes = (len as u64).to_be_bytes();
+    let skip = bytes.iter().take_while(|b| **b == 0).count();
+    out.push(offset + 55 + (8 - skip) as u8);
+    out.extend_from_slice(&bytes[skip..]);
+  }
+}
+
+pub fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+  if bytes.len() == 1 && bytes[0] < 0x80 {
+    return bytes.to_vec();
+  }
+  let mut out = Vec::with_capacity(bytes.len() + 9);
+  encode_len(bytes.len(), 0x80, &mut out);
+  out.extend_from_slice(bytes);
+  out
+}
+
+pub fn encode_u64(n: u64) -> Vec<u8> {
+  let bytes = n.to_be_bytes();
+  let skip = bytes.iter().take_while(|b| **b == 0).count();
+  encode_bytes(&bytes[skip..])
+}
+
+/// Encode a list of encoded items.
+pub fn encode_list(items: &[Vec<u8>]) -> Vec<u8> {
+  let len = items.iter().map(|item| item.len()).sum();
+  let mut out = Vec::with_capacity(len + 9);
+  encode_len(len, 0xc0, &mut out);
+  for item in items {
+    out.extend_from_slice(item);
+  }
+  out
+}
@@ -0,0 +1,423 @@
+// Copyright (C) 2021 Clover Network
+// This file is part of Clover.
+
+use super::*;
+use crate::fixtures::*;
+use crate::mock::*;
+use clover_traits::EvmLog;
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use sp_runtime::traits::BadOrigin;
+
+fn headers(from: u64, to: u64) -> Vec<Vec<u8>> {
+  (from..=to)
+    .map(|n| HEADERS[(n - 8) as usize].to_vec())
+    .collect()
+}
+
+fn import_to(number: u64) {
+  assert_ok!(BscLightClient::initialize(
+    Origin::root(),
+    HEADERS[0].to_vec()
+  ));
+  assert_ok!(BscLightClient::submit_headers(
+    Origin::signed(1),
+    headers(9, number)
+  ));
+}
+
+fn hash_of(header: &[u8]) -> H256 {
+  Header::decode(header).unwrap().hash()
+}
+
+fn deposit_proof() -> ReceiptProof {
+  ReceiptProof {
+    block_hash: HASH_9,
+    tx_index: 1,
+    tx_proof: deposit_tx_proof(),
+    receipt_proof: deposit_receipt_proof(),
+  }
+}
+
+#[test]
+fn initialize_should_work() {
+  new_test_ext().execute_with(|| {
+    assert_noop!(
+      BscLightClient::submit_headers(Origin::signed(1), headers(9, 9)),
+      Error::<Test>::NotInitialized
+    );
+    assert_noop!(
+      BscLightClient::initialize(Origin::signed(1), HEADERS[0].to_vec()),
+      BadOrigin
+    );
+    assert_noop!(
+      BscLightClient::initialize(Origin::root(), HEADERS[1].to_vec()),
+      Error::<Test>::NotEpochHeader
+    );
+    assert_noop!(
+      BscLightClient::initialize(Origin::root(), vec![0xc0]),
+      Error::<Test>::InvalidHeader
+    );
+
+    assert_ok!(BscLightClient::initialize(
+      Origin::root(),
+      HEADERS[0].to_vec()
+    ));
+    let hash = Header::decode(HEADERS[0]).unwrap().hash();
+    assert_eq!(BscLightClient::head(), Some((8, hash)));
+    assert_eq!(BscLightClient::validators(), validators("S1"));
+    assert_eq!(BscLightClient::canonical_hash(8), Some(hash));
+  });
+}
+
+#[test]
+fn submit_headers_should_work() {
+  new_test_ext().execute_with(|| {
+    import_to(13);
+    assert_eq!(BscLightClient::canonical_hash(9), Some(HASH_9));
+    // the set of header 12 applies after `12 + 3 / 2`
+    assert_eq!(BscLightClient::validators(), validators("S1"));
+    assert_eq!(
+      BscLightClient::next_validators(),
+      Some((13, validators("S2")))
+    );
+
+    // header 14 is sealed out of turn by the new validator
+    assert_ok!(BscLightClient::submit_headers(
+      Origin::signed(1),
+      headers(14, 17)
+    ));
+    assert_eq!(BscLightClient::validators(), validators("S2"));
+    assert_eq!(
+      BscLightClient::next_validators(),
+      Some((18, validators("S2")))
+    );
+    let head = Header::decode(HEADERS[9]).unwrap();
+    assert_eq!(BscLightClient::head(), Some((17, head.hash())));
+    assert_eq!(
+      BscLightClient::headers(head.hash()).map(|info| info.coinbase),
+      Some(validators("S2")[1])
+    );
+  });
+}
+
+#[test]
+fn invalid_headers_should_fail() {
+  new_test_ext().execute_with(|| {
+    import_to(9);
+
+    assert_noop!(
+      BscLightClient::submit_headers(Origin::signed(1), headers(11, 11)),
+      Error::<Test>::UnknownParent
+    );
+    assert_noop!(
+      BscLightClient::submit_headers(Origin::signed(1), vec![BAD_DIFFICULTY.to_vec()]),
+      Error::<Test>::WrongDifficulty
+    );
+    assert_noop!(
+      BscLightClient::submit_headers(Origin::signed(1), vec![RECENTLY_SIGNED.to_vec()]),
+      Error::<Test>::RecentlySigned
+    );
+    assert_noop!(
+      BscLightClient::submit_headers(Origin::signed(1), vec![UNAUTHORIZED.to_vec()]),
+      Error::<Test>::UnauthorizedSigner
+    );
+    // a header whose coinbase is not the sealer
+    let mut tampered = HEADERS[2].to_vec();
+    tampered[70..90].copy_from_slice(validators("S1")[0].as_bytes());
+    assert_noop!(
+      BscLightClient::submit_headers(Origin::signed(1), vec![tampered]),
+      Error::<Test>::InvalidSeal
+    );
+
+    // the whole batch is rejected
+    let mut batch = headers(10, 11);
+    batch.push(HEADERS[1].to_vec());
+    assert_noop!(
+      BscLightClient::submit_headers(Origin::signed(1), batch),
+      Error::<Test>::AlreadyImported
+    );
+    assert_eq!(BscLightClient::head(), Some((9, HASH_9)));
+  });
+}
+
+#[test]
+fn fork_choice_should_work() {
+  new_test_ext().execute_with(|| {
+    import_to(9);
+    // the fork extends the head
+    assert_ok!(BscLightClient::submit_headers(
+      Origin::signed(1),
+      vec![FORKS[0].to_vec()]
+    ));
+    assert_eq!(BscLightClient::head(), Some((10, hash_of(FORKS[0]))));
+
+    // the in turn header is heavier
+    assert_ok!(BscLightClient::submit_headers(
+      Origin::signed(1),
+      headers(10, 10)
+    ));
+    assert_eq!(BscLightClient::head(), Some((10, hash_of(HEADERS[2]))));
+    assert_eq!(
+      BscLightClient::canonical_hash(10),
+      Some(hash_of(HEADERS[2]))
+    );
+    assert_eq!(
+      BscLightClient::numbered_headers(10),
+      vec![hash_of(FORKS[0]), hash_of(HEADERS[2])]
+    );
+
+    // a fork of the same difficulty does not switch
+    assert_ok!(BscLightClient::submit_headers(
+      Origin::signed(1),
+      vec![FORKS[1].to_vec()]
+    ));
+    assert_eq!(BscLightClient::head(), Some((10, hash_of(HEADERS[2]))));
+    assert_eq!(BscLightClient::canonical_hash(11), None);
+    assert!(!BscLightClient::is_finalized(9));
+
+    assert_ok!(BscLightClient::submit_headers(
+      Origin::signed(1),
+      vec![FORKS[2].to_vec()]
+    ));
+    assert_eq!(BscLightClient::head(), Some((12, hash_of(FORKS[2]))));
+    for (number, fork) in (10..=12).zip(FORKS.iter()) {
+      assert_eq!(BscLightClient::canonical_hash(number), Some(hash_of(fork)));
+    }
+    assert_eq!(BscLightClient::finalized(), Some((10, hash_of(FORKS[0]))));
+    assert_eq!(
+      BscLightClient::next_validators(),
+      Some((13, validators("S2")))
+    );
+
+    assert_noop!(
+      BscLightClient::submit_headers(Origin::signed(1), headers(10, 10)),
+      Error::<Test>::AlreadyImported
+    );
+    // the block is an ancestor of both branches
+    assert_ok!(BscLightClient::verify_receipt(56, &deposit_proof()));
+  });
+}
+
+#[test]
+fn forks_below_final_header_should_fail() {
+  new_test_ext().execute_with(|| {
+    import_to(9);
+    assert_ok!(BscLightClient::submit_headers(
+      Origin::signed(1),
+      FORKS.iter().map(|fork| fork.to_vec()).collect()
+    ));
+    assert_eq!(BscLightClient::finalized(), Some((10, hash_of(FORKS[0]))));
+
+    assert_noop!(
+      BscLightClient::submit_headers(Origin::signed(1), headers(10, 10)),
+      Error::<Test>::ConflictsWithFinalized
+    );
+    assert_noop!(
+      BscLightClient::submit_headers(Origin::signed(1), vec![UNAUTHORIZED.to_vec()]),
+      Error::<Test>::ConflictsWithFinalized
+    );
+    // header 12 of the previous best chain was never imported
+    assert_noop!(
+      BscLightClient::submit_headers(Origin::signed(1), headers(13, 13)),
+      Error::<Test>::UnknownParent
+    );
+  });
+}
+
+#[test]
+fn heavier_fork_below_final_header_should_fail() {
+  new_test_ext().execute_with(|| {
+    import_to(10);
+    assert_ok!(BscLightClient::submit_headers(
+      Origin::signed(1),
+      FORKS.iter().map(|fork| fork.to_vec()).collect()
+    ));
+    assert_eq!(BscLightClient::head(), Some((12, hash_of(FORKS[2]))));
+
+    // header 11 is heavier than the head, but its parent is not the final header 10
+    assert_noop!(
+      BscLightClient::submit_headers(Origin::signed(1), headers(11, 11)),
+      Error::<Test>::ConflictsWithFinalized
+    );
+    assert_eq!(BscLightClient::head(), Some((12, hash_of(FORKS[2]))));
+  });
+}
+
+#[test]
+fn prune_should_work() {
+  new_test_ext().execute_with(|| {
+    import_to(24);
+    // the set of four validators needs three confirmations
+    assert_eq!(
+      BscLightClient::finalized(),
+      Some((21, hash_of(HEADERS[13])))
+    );
+    assert_eq!(BscLightClient::stored_range(), Some((8, 24)));
+
+    BscLightClient::on_initialize(1);
+    // numbers below `21 - 8` are pruned
+    assert_eq!(BscLightClient::stored_range(), Some((13, 24)));
+    for number in 8..13 {
+      assert_eq!(BscLightClient::canonical_hash(number), None);
+      assert!(BscLightClient::numbered_headers(number).is_empty());
+    }
+    assert_eq!(BscLightClient::headers(HASH_9), None);
+    assert!(BscLightClient::validator_set(hash_of(HEADERS[0])).is_empty());
+    assert_eq!(
+      BscLightClient::canonical_hash(13),
+      Some(hash_of(HEADERS[5]))
+    );
+    assert_noop!(
+      BscLightClient::verify_receipt(56, &deposit_proof()),
+      Error::<Test>::UnknownHeader
+    );
+
+    BscLightClient::on_initialize(2);
+    assert_eq!(BscLightClient::stored_range(), Some((13, 24)));
+    assert_eq!(BscLightClient::validators(), validators("S2"));
+  });
+}
+
+#[test]
+fn reset_should_prune_stored_headers() {
+  new_test_ext().execute_with(|| {
+    import_to(13);
+    assert_ok!(BscLightClient::initialize(
+      Origin::root(),
+      HEADERS[4].to_vec()
+    ));
+    assert_eq!(BscLightClient::head(), None);
+    assert_eq!(
+      BscLightClient::pending_initialization(),
+      Some(HEADERS[4].to_vec())
+    );
+    assert_noop!(
+      BscLightClient::submit_headers(Origin::signed(1), headers(14, 14)),
+      Error::<Test>::NotInitialized
+    );
+    assert_noop!(
+      BscLightClient::verify_receipt(56, &deposit_proof()),
+      Error::<Test>::HeaderNotFinalized
+    );
+
+    BscLightClient::on_initialize(1);
+    assert_eq!(BscLightClient::pending_initialization(), None);
+    assert_eq!(BscLightClient::head(), Some((12, hash_of(HEADERS[4]))));
+    assert_eq!(BscLightClient::stored_range(), Some((12, 12)));
+    assert_eq!(BscLightClient::headers(HASH_9), None);
+    assert_eq!(BscLightClient::canonical_hash(13), None);
+    assert!(BscLightClient::numbered_headers(13).is_empty());
+    // the trusted set applies right away
+    assert_eq!(BscLightClient::validators(), validators("S2"));
+  });
+}
+
+#[test]
+fn verify_receipt_should_work() {
+  new_test_ext().execute_with(|| {
+    import_to(10);
+    assert_noop!(
+      BscLightClient::verify_receipt(56, &deposit_proof()),
+      Error::<Test>::HeaderNotFinalized
+    );
+
+    assert_ok!(BscLightClient::submit_headers(
+      Origin::signed(1),
+      headers(11, 11)
+    ));
+    assert!(BscLightClient::is_finalized(9));
+    assert!(!BscLightClient::is_finalized(10));
+
+    let receipt = BscLightClient::verify_receipt(56, &deposit_proof()).unwrap();
+    assert_eq!(receipt.tx_hash, DEPOSIT_TX_HASH);
+    assert_eq!(receipt.block_number, 9);
+    assert!(receipt.success);
+    assert_eq!(receipt.logs.len(), 2);
+    let mut amount = [0u8; 32];
+    amount[30..].copy_from_slice(&1000u16.to_be_bytes());
+    let mut depositor = H256::zero();
+    depositor.0[12..].copy_from_slice(&hex_literal::hex![
+      "243E34C336F3D2c08BBC79b99E6BCA1fA7c58595"
+    ]);
+    assert_eq!(
+      receipt.logs[1],
+      EvmLog {
+        address: DEPOSIT_CONTRACT,
+        topics: vec![DEPOSIT_TOPIC, depositor],
+        data: amount.to_vec(),
+      }
+    );
+
+    // the legacy receipt of the first transaction
+    let other = BscLightClient::verify_receipt(
+      56,
+      &ReceiptProof {
+        tx_index: 0,
+        tx_proof: other_tx_proof(),
+        receipt_proof: other_receipt_proof(),
+        ..deposit_proof()
+      },
+    )
+    .unwrap();
+    assert!(other.success);
+    assert_eq!(other.logs.len(), 1);
+  });
+}
+
+#[test]
+fn invalid_receipt_proofs_should_fail() {
+  new_test_ext().execute_with(|| {
+    import_to(11);
+
+    assert_noop!(
+      BscLightClient::verify_receipt(1, &deposit_proof()),
+      Error::<Test>::UnsupportedChain
+    );
+    assert_noop!(
+      BscLightClient::verify_receipt(
+        56,
+        &ReceiptProof {
+          block_hash: H256::repeat_byte(1),
+          ..deposit_proof()
+        }
+      ),
+      Error::<Test>::UnknownHeader
+    );
+    // proofs of another index
+    assert_noop!(
+      BscLightClient::verify_receipt(
+        56,
+        &ReceiptProof {
+          tx_index: 0,
+          ..deposit_proof()
+        }
+      ),
+      Error::<Test>::InvalidProof
+    );
+    // a receipt proof against the transactions root
+    assert_noop!(
+      BscLightClient::verify_receipt(
+        56,
+        &ReceiptProof {
+          tx_proof: deposit_receipt_proof(),
+          ..deposit_proof()
+        }
+      ),
+      Error::<Test>::InvalidProof
+    );
+    let mut tampered = deposit_receipt_proof();
+    let last = tampered.len() - 1;
+    let len = tampered[last].len();
+    tampered[last][len - 1] ^= 1;
+    assert_noop!(
+      BscLightClient::verify_receipt(
+        56,
+        &ReceiptProof {
+          receipt_proof: tampered,
+          ..deposit_proof()
+        }
+      ),
+      Error::<Test>::InvalidProof
+    );
+  });
+}
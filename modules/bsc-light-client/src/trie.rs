@@ -0,0 +1,89 @@
+// Copyright (C) 2021 Clover Network
+// This file is part of Clover.
+
+//! Merkle-Patricia trie proofs.
+
+use crate::rlp::Item;
+use sp_core::H256;
+use sp_io::hashing::keccak_256;
+use sp_std::prelude::*;
+
+fn nibbles(key: &[u8]) -> Vec<u8> {
+  key.iter().flat_map(|b| vec![b >> 4, b & 0x0f]).collect()
+}
+
+/// Decode the hex-prefix encoded path of a leaf or an extension.
+fn decode_path(encoded: &[u8]) -> Option<(Vec<u8>, bool)> {
+  let first = *encoded.first()?;
+  let is_leaf = first & 0x20 != 0;
+  let mut path = if first & 0x10 != 0 {
+    vec![first & 0x0f]
+  } else {
+    vec![]
+  };
+  path.extend(nibbles(&encoded[1..]));
+  Some((path, is_leaf))
+}
+
+/// Verify the proof of `key` in the trie of `root` and return the value of the key.
+/// `proof` holds the nodes from the root to the value which are referenced by hash.
+pub fn verify_proof(root: &H256, key: &[u8], proof: &[Vec<u8>]) -> Option<Vec<u8>> {
+  let key = nibbles(key);
+  let mut path = &key[..];
+  let mut proof = proof.iter();
+  let mut node = proof.next()?.clone();
+  if keccak_256(&node) != root.0 {
+    return None;
+  }
+
+  loop {
+    let items = Item::decode(&node)?.list()?;
+    let child = match items.len() {
+      17 => {
+        if path.is_empty() {
+          let value = items[16].bytes()?;
+          return if value.is_empty() {
+            None
+          } else {
+            Some(value.to_vec())
+          };
+        }
+        let child = items[path[0] as usize];
+        path = &path[1..];
+        child
+      }
+      2 => {
+        let (prefix, is_leaf) = decode_path(items[0].bytes()?)?;
+        if is_leaf {
+          return if path == &prefix[..] {
+            Some(items[1].bytes()?.to_vec())
+          } else {
+            None
+          };
+        }
+        if !path.starts_with(&prefix) {
+          return None;
+        }
+        path = &path[prefix.len()..];
+        items[1]
+      }
+      _ => return None,
+    };
+
+    // nodes shorter than 32 bytes are embedded in the parent
+    let next = if child.is_list {
+      child.raw.to_vec()
+    } else {
+      let hash = child.bytes()?;
+      if hash.len() != 32 {
+        return None;
+      }
+      let next = proof.next()?.clone();
+      if keccak_256(&next)[..] != hash[..] {
+        return None;
+      }
+      next
+    };
+    node = next;
+  }
+}
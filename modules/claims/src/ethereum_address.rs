@@ -70,13 +70,21 @@ impl Serialize for EthereumTxHash {
     }
 }
 
-/// Converts the given binary data into ASCII-encoded hex. It will be twice the length.
-pub fn to_ascii_hex(data: &[u8]) -> Vec<u8> {
-  let mut r = Vec::with_capacity(data.len() * 2);
-  let mut push_nibble = |n| r.push(if n < 10 { b'0' + n } else { b'a' - 10 + n });
-  for &b in data.iter() {
-      push_nibble(b / 16);
-      push_nibble(b % 16);
-  }
-  r
+#[cfg(feature = "std")]
+impl<'de> Deserialize<'de> for EthereumTxHash {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        let base_string = String::deserialize(deserializer)?;
+        let offset = if base_string.starts_with("0x") { 2 } else { 0 };
+        let s = &base_string[offset..];
+        if s.len() != 64 {
+            Err(serde::de::Error::custom("Bad length of Ethereum tx hash (should be 66 including '0x')"))?;
+        }
+        let raw: Vec<u8> = rustc_hex::FromHex::from_hex(s)
+            .map_err(|e| serde::de::Error::custom(format!("{:?}", e)))?;
+        let mut r = Self::default();
+        r.0.copy_from_slice(&raw);
+        Ok(r)
+    }
 }
+
+pub use clover_eth_signing::to_ascii_hex;
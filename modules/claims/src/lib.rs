@@ -4,16 +4,45 @@
 //! Module to process claims from ethereum like addresses(e.g. bsc).
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::Encode;
-use frame_support::traits::{Currency, ExistenceRequirement, Get, WithdrawReasons};
-use frame_system::ensure_signed;
-use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
+use clover_eth_signing::{eip712, personal_message, recover};
+use clover_traits::{EvmLog, ReceiptProof, VerifyReceipt};
+use codec::{Decode, Encode};
+use frame_support::{
+  storage::{
+    migration::{put_storage_value, take_storage_value, StorageIterator, StorageKeyIterator},
+    unhashed, with_transaction,
+  },
+  traits::{
+    schedule::{DispatchTime, Named as ScheduleNamed, HARD_DEADLINE},
+    Currency, ExistenceRequirement, Get, Imbalance, OnUnbalanced, OneSessionHandler, PalletInfo,
+    ReservableCurrency, VestingSchedule, WithdrawReasons,
+  },
+  StorageHasher,
+};
+use frame_system::{
+  ensure_signed,
+  offchain::{SendTransactionTypes, SubmitTransaction},
+};
+use orml_traits::MultiCurrency;
+use pallet_evm::AddressMapping;
+pub use primitives::CurrencyId;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_core::crypto::KeyTypeId;
+pub use sp_core::H160;
+use sp_io::{
+  crypto::{ecdsa_sign_prehashed, secp256k1_ecdsa_recover_compressed},
+  hashing::keccak_256,
+};
 use sp_runtime::{
-  traits::{AccountIdConversion, Saturating},
-  transaction_validity::{InvalidTransaction, TransactionValidity, ValidTransaction},
-  ModuleId,
+  traits::{AccountIdConversion, AtLeast32BitUnsigned, Saturating, Zero},
+  transaction_validity::{
+    InvalidTransaction, TransactionPriority, TransactionValidity, ValidTransaction,
+  },
+  BoundToRuntimeAppPublic, DispatchError, DispatchResult, ModuleId, Permill, RuntimeAppPublic,
+  RuntimeDebug, SaturatedConversion, TransactionOutcome,
 };
-use sp_std::prelude::*;
+use sp_std::{convert::TryFrom, prelude::*};
 
 pub use pallet::*;
 pub mod ethereum_address;
@@ -24,8 +53,414 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub use clover_eth_signing::EthereumSignature;
 pub use ethereum_address::*;
 
+/// The EVM chain id of a bridged chain, e.g. 56 for BSC.
+pub type ChainId = u64;
+
+/// EIP-712 domain name of the typed data signed for `claim_typed`.
+pub const CLAIM_DOMAIN_NAME: &[u8] = b"Clover Claims";
+/// EIP-712 domain version of the typed data signed for `claim_typed`.
+pub const CLAIM_DOMAIN_VERSION: &[u8] = b"1";
+
+/// Signature of the event emitted by the deposit contracts, `who` is indexed.
+pub const DEPOSIT_EVENT: &[u8] = b"Deposit(address,uint256)";
+
+/// Prefix of the scheduler ids of the `finalize_mint` calls.
+const LARGE_MINT_ID: [u8; 8] = *b"clvlgmnt";
+
+/// Maximum number of burns an offchain worker signs per block.
+pub const MAX_OFFCHAIN_SIGNATURES: usize = 64;
+
+/// Estimated weight of recovering the signer of a claim, the keccak of the message and
+/// the secp256k1 recovery; the rest of the claim weight is its storage access.
+pub const CLAIM_SIGNATURE_WEIGHT: frame_support::weights::Weight = 100_000_000;
+
+/// Key type of the bridge keys which the validators sign burn certificates with.
+pub const BRIDGE_KEY_TYPE: KeyTypeId = KeyTypeId(*b"brdg");
+
+/// The ecdsa bridge keys of the validators, part of the session keys.
+pub mod ecdsa {
+  mod app_ecdsa {
+    use sp_application_crypto::{app_crypto, ecdsa};
+    app_crypto!(ecdsa, crate::BRIDGE_KEY_TYPE);
+  }
+
+  sp_application_crypto::with_pair! {
+    /// A bridge authority keypair.
+    pub type AuthorityPair = app_ecdsa::Pair;
+  }
+
+  /// A bridge authority signature.
+  pub type AuthoritySignature = app_ecdsa::Signature;
+
+  /// A bridge authority identifier.
+  pub type AuthorityId = app_ecdsa::Public;
+
+  /// A bridge key unique to the validator whose secret key no one knows, the bridge key of
+  /// validators which did not set theirs yet.
+  pub fn placeholder<AccountId: codec::Encode>(validator: &AccountId) -> AuthorityId {
+    let mut key = [2u8; 33];
+    key[1..].copy_from_slice(&sp_io::hashing::blake2_256(&codec::Encode::encode(&(
+      b"clover_bridge",
+      validator,
+    ))));
+    sp_core::ecdsa::Public::from_raw(key).into()
+  }
+}
+
+/// Storage layout versions of the module.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Releases {
+  /// Single bridged chain.
+  V1_0_0,
+  /// Claims, limits, fees and relayers are scoped by `ChainId`.
+  V2_0_0,
+  /// Mint and burn fees are `FeeSchedule`s.
+  V3_0_0,
+  /// Claims, burns, limits and fees are scoped by `CurrencyId`.
+  V4_0_0,
+  /// Claims are `ClaimRecord`s.
+  V5_0_0,
+  /// Attestations carry the vesting of the mint.
+  V6_0_0,
+  /// Bridged supplies are tracked.
+  V7_0_0,
+  /// The bridge keys of the validators are part of the session keys.
+  V8_0_0,
+}
+
+impl Default for Releases {
+  fn default() -> Self {
+    Releases::V1_0_0
+  }
+}
+
+/// Bridge operations which can be paused.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum BridgeOperation {
+  /// `mint_claim` and `mint_with_proof`
+  Mint,
+  /// `claim` and `claim_typed`
+  Claim,
+  /// `burn`
+  Burn,
+}
+
+/// Maximum number of tiers of a `FeeSchedule`.
+pub const MAX_FEE_TIERS: usize = 16;
+
+/// Maximum number of items of `mint_claims_batch` and `confirm_burns`.
+pub const MAX_BATCH_SIZE: usize = 256;
+
+/// Number of blocks an unsigned `burn_from_evm` stays valid in the pool.
+pub const EVM_BURN_LONGEVITY: u64 = 64;
+
+/// Number of burns the `LowestPendingBurn` cursor skips at most per released or refunded burn,
+/// beyond the burns of the call.
+pub const MAX_BURN_CURSOR_STEPS: u64 = 16;
+
+/// Fee charged for bridging an amount.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug)]
+pub struct FeeSchedule<Balance> {
+  /// Rate of the amount charged
+  pub rate: Permill,
+  /// Minimum fee
+  pub min: Balance,
+  /// Maximum fee, unlimited if `None`
+  pub max: Option<Balance>,
+  /// `(amount, rate)` tiers sorted by amount, the rate of the last tier not above
+  /// the bridged amount is charged instead of `rate`
+  pub tiers: Vec<(Balance, Permill)>,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> FeeSchedule<Balance> {
+  /// A flat fee for any amount
+  pub fn flat(fee: Balance) -> Self {
+    Self {
+      rate: Permill::zero(),
+      min: fee,
+      max: Some(fee),
+      tiers: Vec::new(),
+    }
+  }
+
+  /// The fee of bridging `amount`
+  pub fn fee(&self, amount: Balance) -> Balance {
+    let rate = self
+      .tiers
+      .iter()
+      .take_while(|(from, _)| *from <= amount)
+      .last()
+      .map_or(self.rate, |(_, rate)| *rate);
+    let fee = (rate * amount).max(self.min);
+    self.max.map_or(fee, |max| fee.min(max))
+  }
+
+  /// `min` is not above `max` and the tiers are sorted by amount
+  pub fn is_valid(&self) -> bool {
+    self.max.map_or(true, |max| self.min <= max)
+      && self.tiers.len() <= MAX_FEE_TIERS
+      && self.tiers.windows(2).all(|w| w[0].0 < w[1].0)
+  }
+}
+
+/// Rolling window of the claim limit of a chain.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct ClaimWindow<Balance, BlockNumber> {
+  /// Claim limit restored at the start of every window
+  pub limit: Balance,
+  /// Length of the window in blocks
+  pub period: BlockNumber,
+  /// Block number the current window started at
+  pub start: BlockNumber,
+}
+
+/// Vesting of a claim, the claimed amount is locked and unlocks linearly.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct ClaimVesting<Balance, BlockNumber> {
+  /// Block number the unlocking starts at
+  pub start: BlockNumber,
+  /// Amount unlocked per block from `start`
+  pub per_block: Balance,
+}
+
+/// Details of a minted claim.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct MintInfo<AccountId, Balance, BlockNumber> {
+  /// Currency minted
+  pub currency_id: CurrencyId,
+  /// Block number of the mint
+  pub block: BlockNumber,
+  /// The mint can be revoked from this block if it is not claimed
+  pub expiry: BlockNumber,
+  /// Relayers whose attestations minted the claim
+  pub relayers: Vec<AccountId>,
+  /// Mint fee charged
+  pub fee: Balance,
+}
+
+/// A minted claim of a tx on the source chain.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct ClaimRecord<AccountId, Balance, BlockNumber> {
+  /// The ethereum address which can claim
+  pub who: EthereumAddress,
+  /// Amount to claim, the mint fee excluded
+  pub amount: Balance,
+  /// Block number of the mint, zero for mints recorded before `MintInfo`
+  pub mint_block: BlockNumber,
+  /// The relayer whose attestation minted the claim or which submitted its proof
+  pub relayer: Option<AccountId>,
+  /// Mint fee charged
+  pub fee: Balance,
+  pub claimed: bool,
+  /// The account which received the claim, `None` for claims before `Releases::V5_0_0`
+  pub claimed_by: Option<AccountId>,
+  /// Block number of the claim, `None` for claims before `Releases::V5_0_0`
+  pub claim_block: Option<BlockNumber>,
+}
+
+/// A mint above the large mint threshold of its chain and currency, finalized after the
+/// delay unless vetoed.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct PendingMint<AccountId, Balance, BlockNumber> {
+  /// Currency to mint
+  pub currency_id: CurrencyId,
+  /// The ethereum address which can claim
+  pub who: EthereumAddress,
+  /// Amount to claim, the mint fee excluded
+  pub amount: Balance,
+  /// Mint fee charged when finalized
+  pub fee: Balance,
+  /// Vesting of the claimed amount
+  pub vesting: Option<ClaimVesting<Balance, BlockNumber>>,
+  /// The relayer whose attestation minted the claim or which submitted its proof
+  pub relayer: AccountId,
+  /// Relayers whose attestations minted the claim
+  pub relayers: Vec<AccountId>,
+  /// The mint is finalized at this block
+  pub finalize_at: BlockNumber,
+}
+
+/// Details of a minted claim, returned by the runtime api.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct ClaimDetails<Balance, BlockNumber> {
+  /// Currency minted
+  pub currency_id: CurrencyId,
+  /// The ethereum address which can claim
+  pub who: EthereumAddress,
+  /// Amount to claim
+  pub amount: Balance,
+  /// The mint can be revoked from this block if it is not claimed,
+  /// `None` for mints before the expiry was recorded
+  pub expiry: Option<BlockNumber>,
+  /// Vesting of the claimed amount
+  pub vesting: Option<ClaimVesting<Balance, BlockNumber>>,
+}
+
+/// Status of the claim of a tx on the source chain.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum ClaimStatus<Balance, BlockNumber> {
+  /// No relayer attested the tx
+  Unknown,
+  /// Attested by the number of relayers, not minted yet
+  Attesting(u32),
+  /// Minted and waiting to be claimed
+  Minted(ClaimDetails<Balance, BlockNumber>),
+  /// Claimed
+  Claimed(ClaimDetails<Balance, BlockNumber>),
+  /// Revoked, the tx can not be minted again
+  Revoked,
+  /// Above the large mint threshold, can be vetoed until finalized at the block
+  Delayed(ClaimDetails<Balance, BlockNumber>, BlockNumber),
+}
+
+/// Current limits of minting a currency from a chain.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct ClaimLimits<Balance, BlockNumber> {
+  /// Remaining value can be minted
+  pub claim_limit: Balance,
+  /// Rolling window restoring the claim limit
+  pub claim_window: Option<ClaimWindow<Balance, BlockNumber>>,
+  /// Maximum value of a single claim
+  pub max_claim_amount: Option<Balance>,
+  /// Maximum value minted to an address per day
+  pub address_daily_cap: Option<Balance>,
+}
+
+/// Rewards of a relayer in a currency, funded from the bridge fees.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug)]
+pub struct RewardLedger<Balance> {
+  /// Total rewards credited
+  pub earned: Balance,
+  /// Total rewards claimed
+  pub claimed: Balance,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> RewardLedger<Balance> {
+  /// Rewards which can be claimed
+  pub fn unclaimed(&self) -> Balance {
+    self.earned.saturating_sub(self.claimed)
+  }
+}
+
+/// Bond reserved by a relayer, slashable until withdrawn.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug)]
+pub struct BondInfo<Balance, BlockNumber> {
+  /// Bond backing the attestations of the relayer
+  pub active: Balance,
+  /// Bond being unbonded
+  pub unbonding: Balance,
+  /// `unbonding` can be withdrawn from this block
+  pub unlock_at: BlockNumber,
+}
+
+/// Running totals of a currency bridged from and to a chain.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug)]
+pub struct BridgedSupply<Balance> {
+  /// Claims minted, the mint fees excluded, voided and revoked claims are deducted unless
+  /// refunded
+  pub minted: Balance,
+  /// Minted claims which were claimed, or revoked and refunded to the depositor
+  pub claimed: Balance,
+  /// Burns released or to be released on the chain, the burn fees excluded, refunded burns
+  /// are deducted
+  pub burned: Balance,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> BridgedSupply<Balance> {
+  /// Value which must still be locked on the chain, the claimed value not burned back
+  pub fn outstanding(&self) -> Balance {
+    self.claimed.saturating_sub(self.burned)
+  }
+}
+
+/// Value of a currency locked by the bridge on a chain, reported by a relayer.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct LockReport<AccountId, Balance, BlockNumber> {
+  /// The relayer which reported it
+  pub relayer: AccountId,
+  /// Value locked
+  pub locked: Balance,
+  /// Block number of the report
+  pub block: BlockNumber,
+}
+
+/// Bridged supply of a currency from a chain, returned by the runtime api.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct SupplyAudit<AccountId, Balance, BlockNumber> {
+  pub minted: Balance,
+  pub claimed: Balance,
+  pub burned: Balance,
+  /// Mint and burn fees collected
+  pub fees: Balance,
+  /// The latest lock report of the chain
+  pub lock_report: Option<LockReport<AccountId, Balance, BlockNumber>>,
+}
+
+/// Sequential nonce of the outbound burns to a chain.
+pub type BurnNonce = u64;
+
+/// Status of an outbound burn.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum BurnStatus {
+  /// Waiting to be released on the dest chain
+  Pending,
+  /// Released on the dest chain by the tx
+  Relayed(EthereumTxHash),
+  /// Refunded to the burner
+  Refunded,
+}
+
+/// An outbound burn to be released on the dest chain.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct BurnInfo<AccountId, Balance, BlockNumber> {
+  /// The account funds were burned from
+  pub who: AccountId,
+  /// Currency burned
+  pub currency_id: CurrencyId,
+  /// The receiver on the dest chain
+  pub dest: EthereumAddress,
+  /// Amount to release on the dest chain, the fee excluded
+  pub amount: Balance,
+  /// Fee charged by the bridge
+  pub fee: Balance,
+  /// Block number of the burn
+  pub block: BlockNumber,
+  pub status: BurnStatus,
+}
+
+/// Signatures of more than two thirds of a bridge authority set over the
+/// `burn_certificate_message` of a burn.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct BurnCertificate<AuthorityId, Signature> {
+  /// Id of the authority set which signed the burn
+  pub set_id: u64,
+  pub signatures: Vec<(AuthorityId, Signature)>,
+}
+
+/// A certified burn in the form verified by the contracts of the dest chain.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct CertifiedBurn<Balance> {
+  /// Currency burned
+  pub currency_id: CurrencyId,
+  /// The contract of the currency on the dest chain
+  pub token: H160,
+  /// The receiver on the dest chain
+  pub dest: EthereumAddress,
+  /// Amount to release on the dest chain
+  pub amount: Balance,
+  /// The `burn_certificate_message` of the burn, signed by the authorities
+  pub message: [u8; 32],
+  /// Id of the authority set which signed the burn
+  pub set_id: u64,
+  /// Ethereum addresses of the signing authorities and their `r || s || v` signatures of
+  /// `message`, `v` of 0 or 1
+  pub signatures: Vec<(EthereumAddress, EthereumSignature)>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
   use super::*;
@@ -34,20 +469,156 @@ pub mod pallet {
 
   pub type BalanceOf<T> =
     <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+  pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+    <T as frame_system::Config>::AccountId,
+  >>::NegativeImbalance;
+  pub type VestingOf<T> = ClaimVesting<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+  /// `(relayer, currency_id, who, value, vesting)` attested by a relayer
+  pub type AttestationOf<T> = (
+    <T as frame_system::Config>::AccountId,
+    CurrencyId,
+    EthereumAddress,
+    BalanceOf<T>,
+    Option<VestingOf<T>>,
+  );
+  pub type BridgeSignatureOf<T> = <<T as Config>::BridgeAuthorityId as RuntimeAppPublic>::Signature;
+  pub type BurnCertificateOf<T> =
+    BurnCertificate<<T as Config>::BridgeAuthorityId, BridgeSignatureOf<T>>;
 
   #[pallet::config]
-  pub trait Config: frame_system::Config {
+  pub trait Config: frame_system::Config + SendTransactionTypes<Call<Self>> {
     type ModuleId: Get<ModuleId>;
     type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
-    type Currency: Currency<Self::AccountId>;
+    type Currency: ReservableCurrency<Self::AccountId>;
     type Prefix: Get<&'static [u8]>;
+    /// The chain which claims recorded before multi-chain support belong to
+    type LegacyChainId: Get<ChainId>;
+    /// Number of blocks after which a pending burn can be refunded
+    #[pallet::constant]
+    type BurnRefundDelay: Get<Self::BlockNumber>;
+    /// Number of blocks per day, the period of the per address claim cap
+    #[pallet::constant]
+    type BlocksPerDay: Get<Self::BlockNumber>;
+    /// Number of blocks after which an unclaimed mint can be revoked
+    #[pallet::constant]
+    type ClaimExpiry: Get<Self::BlockNumber>;
+    /// Where the native fees are sent by `sweep_fees`, e.g. the treasury
+    type FeeDestination: OnUnbalanced<NegativeImbalanceOf<Self>>;
+    /// The bridged currencies other than the native one
+    type MultiCurrency: MultiCurrency<
+      Self::AccountId,
+      CurrencyId = CurrencyId,
+      Balance = BalanceOf<Self>,
+    >;
+    /// The currency of `Currency`
+    #[pallet::constant]
+    type NativeCurrencyId: Get<CurrencyId>;
+    /// The account fees in currencies other than the native one are sent to by `sweep_fees`
+    type TokenFeeDestination: Get<Self::AccountId>;
+    /// The origin which can configure the bridge and lift pauses
+    type AdminOrigin: EnsureOrigin<Self::Origin>;
+    /// The origin which can pause bridge operations in an emergency
+    type PauseOrigin: EnsureOrigin<Self::Origin>;
+    /// Mapping from EVM addresses to the accounts holding their funds
+    type AddressMapping: AddressMapping<Self::AccountId>;
+    /// Verifies receipts of the source chains for `mint_with_proof`
+    type ReceiptVerifier: VerifyReceipt;
+    /// Minimum bond of a relayer to join a committee
+    #[pallet::constant]
+    type RelayerBond: Get<BalanceOf<Self>>;
+    /// Number of blocks an unbonded relayer bond stays slashable
+    #[pallet::constant]
+    type UnbondingDelay: Get<Self::BlockNumber>;
+    /// The origin which can slash relayers which attested wrong deposits
+    type SlashOrigin: EnsureOrigin<Self::Origin>;
+    /// Where the slashed relayer bonds are sent, e.g. the treasury
+    type Slashed: OnUnbalanced<NegativeImbalanceOf<Self>>;
+    /// Locks the vested claims of the native currency
+    type VestingSchedule: VestingSchedule<
+      Self::AccountId,
+      Moment = Self::BlockNumber,
+      Currency = Self::Currency,
+    >;
+    /// Number of blocks the mints above the `LargeMintThreshold` stay pending
+    #[pallet::constant]
+    type LargeMintDelay: Get<Self::BlockNumber>;
+    /// The origin which can veto pending large mints
+    type VetoOrigin: EnsureOrigin<Self::Origin>;
+    /// The runtime call `finalize_mint` is scheduled as
+    type MintCall: From<Call<Self>>;
+    /// Finalizes the pending large mints once the delay passed
+    type Scheduler: ScheduleNamed<Self::BlockNumber, Self::MintCall, Self::PalletsOrigin>;
+    /// The origin the finalization is scheduled with
+    type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+    /// The bridge key of the validators, e.g. `ecdsa::AuthorityId`
+    type BridgeAuthorityId: Member
+      + Parameter
+      + RuntimeAppPublic
+      + Default
+      + AsRef<sp_core::ecdsa::Public>;
+    /// Priority of the burn signatures submitted by the offchain workers
+    #[pallet::constant]
+    type CertificateUnsignedPriority: Get<TransactionPriority>;
+    /// The origin which can freeze and unfreeze addresses and accounts
+    type FreezeOrigin: EnsureOrigin<Self::Origin>;
   }
 
   #[pallet::pallet]
   pub struct Pallet<T>(sp_std::marker::PhantomData<T>);
 
   #[pallet::hooks]
-  impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+  impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+    /// Refill the claim limit of the chains whose window has passed
+    fn on_initialize(now: T::BlockNumber) -> Weight {
+      let windows = ClaimWindows::<T>::iter().collect::<Vec<_>>();
+      let reads = windows.len() as Weight;
+      let mut writes: Weight = 0;
+      for (chain_id, currency_id, mut window) in windows {
+        if now >= window.start.saturating_add(window.period) {
+          window.start = now;
+          ClaimLimit::<T>::insert(chain_id, currency_id, window.limit);
+          ClaimWindows::<T>::insert(chain_id, currency_id, window);
+          writes += 2;
+        }
+      }
+      T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    /// Sign the uncertified burns with the local bridge keys of the current authorities
+    fn offchain_worker(_now: T::BlockNumber) {
+      if sp_io::offchain::is_validator() {
+        Self::sign_burns();
+      }
+    }
+
+    fn on_runtime_upgrade() -> Weight {
+      let version = StorageVersion::<T>::get();
+      let mut weight = match version {
+        Releases::V1_0_0 => Self::migrate_to_multi_chain(),
+        Releases::V2_0_0 => {
+          Self::migrate_to_fee_schedules().saturating_add(Self::migrate_to_multi_currency())
+        }
+        Releases::V3_0_0 => Self::migrate_to_multi_currency(),
+        Releases::V4_0_0 | Releases::V5_0_0 | Releases::V6_0_0 | Releases::V7_0_0 => 0,
+        Releases::V8_0_0 => return 0,
+      };
+      if !matches!(
+        version,
+        Releases::V5_0_0 | Releases::V6_0_0 | Releases::V7_0_0
+      ) {
+        weight = weight.saturating_add(Self::migrate_to_claim_records());
+      }
+      if !matches!(version, Releases::V6_0_0 | Releases::V7_0_0) {
+        weight = weight.saturating_add(Self::migrate_to_vested_attestations());
+      }
+      if version != Releases::V7_0_0 {
+        weight = weight.saturating_add(Self::migrate_to_bridged_supplies());
+      }
+      // the runtime adds the bridge keys to the session keys before this upgrade
+      StorageVersion::<T>::put(Releases::V8_0_0);
+      weight.saturating_add(T::DbWeight::get().writes(1))
+    }
+  }
 
   #[pallet::error]
   pub enum Error<T> {
@@ -59,293 +630,3344 @@ pub mod pallet {
     InvalidEthereumSignature,
     SignatureNotMatch,
     InvalidAmount,
+    /// The account is already a relayer
+    AlreadyRelayer,
+    /// The account is not a relayer
+    NotRelayer,
+    /// The relayer already attested this tx
+    AlreadyAttested,
+    /// Threshold must be between 1 and the number of relayers
+    InvalidThreshold,
+    /// The bridged chain is not enabled
+    ChainDisabled,
+    /// The burn does not exist
+    BurnNotFound,
+    /// The burn is already relayed or refunded
+    BurnNotPending,
+    /// The burn can not be refunded before `BurnRefundDelay` passed
+    RefundNotAvailable,
+    /// The value exceeds the per claim cap of the chain
+    ClaimAmountTooLarge,
+    /// The value exceeds the daily cap of the ethereum address
+    AddressCapExceeded,
+    /// The window period must not be zero
+    InvalidPeriod,
+    /// The fee schedule is invalid
+    InvalidFeeSchedule,
+    /// The mint is not expired yet
+    ClaimNotExpired,
+    /// The claim of the tx was revoked
+    AlreadyRevoked,
+    /// Minting is paused
+    MintPaused,
+    /// Claiming is paused
+    ClaimPaused,
+    /// Burning is paused
+    BurnPaused,
+    /// The proven transaction failed
+    TxFailed,
+    /// The log is not a deposit of a deposit contract of the chain
+    DepositNotFound,
+    /// The deposit log can not be decoded or the amount overflows
+    InvalidDeposit,
+    /// More than `MAX_BATCH_SIZE` items
+    BatchTooLarge,
+    /// The vesting unlocks nothing per block
+    InvalidVestingSchedule,
+    /// Only claims of the native currency can vest
+    VestingNotSupported,
+    /// The dest account already has a vesting schedule
+    VestedBalanceExists,
+    /// No relayer rewards of the currency to claim
+    NoRewards,
+    /// The account has no relayer bond
+    NotBonded,
+    /// The active bond is below `RelayerBond`
+    InsufficientBond,
+    /// The unbonding delay has not passed
+    BondLocked,
+    /// No unbonded bond to withdraw
+    NothingToWithdraw,
+    /// No pending large mint of the tx
+    MintNotPending,
+    /// The large mint delay has not passed
+    MintDelayNotPassed,
+    /// The finalization of the large mint can not be scheduled
+    ScheduleFailed,
+    /// The key is not a bridge key of the current session
+    NotBridgeAuthority,
+    /// The burn is not pending or already certified
+    BurnNotCertifiable,
+    /// The authority already signed the burn
+    BurnAlreadySigned,
+    /// The signature does not match the burn certificate message
+    InvalidBurnSignature,
+    /// The burn is certified and can be released on the dest chain
+    BurnCertified,
+    /// The Ethereum address is frozen
+    AddressFrozen,
+    /// The account is frozen
+    AccountFrozen,
+    /// The address or account is already frozen
+    AlreadyFrozen,
+    /// The address or account is not frozen
+    NotFrozen,
+    /// The attestation differs from the attestations of other relayers of the tx
+    ConflictingAttestation,
+    /// The relayer already voted to void the mint
+    AlreadyVoted,
   }
 
   #[pallet::event]
   #[pallet::generate_deposit(pub(super) fn deposit_event)]
   #[pallet::metadata(T::AccountId = "AccountId")]
   pub enum Event<T: Config> {
-    /// Bridge Account Changed
-    BridgeAccountChanged(T::AccountId),
+    /// Bridged chain enabled or disabled
+    ChainStatusUpdated(ChainId, bool),
+    /// Relayer added to the committee of the chain
+    RelayerAdded(ChainId, T::AccountId),
+    /// Relayer removed from the committee of the chain
+    RelayerRemoved(ChainId, T::AccountId),
+    /// Number of matching attestations required to mint updated
+    RelayerThresholdUpdated(ChainId, u32),
+    /// A relayer attested a tx. \[chain_id, tx, relayer, attestations\]
+    MintAttested(ChainId, EthereumTxHash, T::AccountId, u32),
     /// Mint claims successfully
-    MintSuccess(EthereumTxHash, EthereumAddress, BalanceOf<T>),
+    MintSuccess(
+      ChainId,
+      CurrencyId,
+      EthereumTxHash,
+      EthereumAddress,
+      BalanceOf<T>,
+    ),
     /// claim limit updated
-    ClaimLimitUpdated(BalanceOf<T>),
-    /// CLV claimed
-    Claimed(T::AccountId, EthereumAddress, BalanceOf<T>),
-    // burned some balance and will bridge to bsc
-    Burned(T::AccountId, EthereumAddress, BalanceOf<T>),
-
-    MintFeeUpdated(BalanceOf<T>),
-    BurnFeeUpdated(BalanceOf<T>),
+    ClaimLimitUpdated(ChainId, CurrencyId, BalanceOf<T>),
+    /// Claim limit window updated. \[chain_id, currency_id, window\]
+    ClaimWindowUpdated(
+      ChainId,
+      CurrencyId,
+      Option<ClaimWindow<BalanceOf<T>, T::BlockNumber>>,
+    ),
+    /// Per claim cap updated
+    MaxClaimAmountUpdated(ChainId, CurrencyId, Option<BalanceOf<T>>),
+    /// Per ethereum address daily cap updated
+    AddressDailyCapUpdated(ChainId, CurrencyId, Option<BalanceOf<T>>),
+    /// Bridged currency claimed
+    Claimed(
+      T::AccountId,
+      ChainId,
+      CurrencyId,
+      EthereumTxHash,
+      EthereumAddress,
+      BalanceOf<T>,
+    ),
+    // burned some balance and will bridge to the dest chain
+    Burned(
+      T::AccountId,
+      ChainId,
+      CurrencyId,
+      EthereumAddress,
+      BalanceOf<T>,
+      BurnNonce,
+    ),
+    /// Burn released on the dest chain. \[chain_id, nonce, tx\]
+    BurnRelayed(ChainId, BurnNonce, EthereumTxHash),
+    /// Pending burn refunded to the burner. \[chain_id, currency_id, nonce, who, amount\]
+    BurnRefunded(ChainId, CurrencyId, BurnNonce, T::AccountId, BalanceOf<T>),
+    /// Expired mint revoked, with the nonce of the burn refunding it on the source chain.
+    /// \[chain_id, currency_id, tx, who, amount, refund_nonce\]
+    ClaimRevoked(
+      ChainId,
+      CurrencyId,
+      EthereumTxHash,
+      EthereumAddress,
+      BalanceOf<T>,
+      Option<BurnNonce>,
+    ),
+    /// Mint voided by the relayers or the admin origin, the tx can not be minted again.
+    /// \[chain_id, tx\]
+    MintVoided(ChainId, EthereumTxHash),
+    /// Fees of the currency held by the module sent to the fee destination
+    FeesSwept(CurrencyId, BalanceOf<T>),
+
+    MintFeeUpdated(ChainId, CurrencyId, Option<FeeSchedule<BalanceOf<T>>>),
+    BurnFeeUpdated(ChainId, CurrencyId, Option<FeeSchedule<BalanceOf<T>>>),
+    /// Bridge operation paused
+    OperationPaused(BridgeOperation),
+    /// Bridge operation unpaused
+    OperationUnpaused(BridgeOperation),
+    /// An item of `mint_claims_batch` was skipped. \[chain_id, tx, error\]
+    MintSkipped(ChainId, EthereumTxHash, DispatchError),
+    /// An item of `confirm_burns` was skipped. \[chain_id, nonce, error\]
+    BurnConfirmationSkipped(ChainId, BurnNonce, DispatchError),
+    /// Deposit contract of the chain added or removed. \[chain_id, contract, currency_id\]
+    DepositContractUpdated(ChainId, H160, Option<CurrencyId>),
+    /// Share of the fees of the chain rewarded to relayers updated
+    RelayerRewardShareUpdated(ChainId, Permill),
+    /// Relayer credited a share of the fee of a mint or burn it relayed.
+    /// \[relayer, chain_id, currency_id, reward\]
+    RelayerRewarded(T::AccountId, ChainId, CurrencyId, BalanceOf<T>),
+    /// Relayer rewards paid out. \[relayer, currency_id, amount\]
+    RelayerRewardsClaimed(T::AccountId, CurrencyId, BalanceOf<T>),
+    /// Relayer bond reserved. \[relayer, amount\]
+    RelayerBonded(T::AccountId, BalanceOf<T>),
+    /// Relayer bond started unbonding. \[relayer, amount, unlock_at\]
+    RelayerUnbonding(T::AccountId, BalanceOf<T>, T::BlockNumber),
+    /// Unbonded relayer bond unreserved. \[relayer, amount\]
+    RelayerBondWithdrawn(T::AccountId, BalanceOf<T>),
+    /// Relayer bond slashed for attesting a wrong deposit. \[chain_id, tx, relayer, amount\]
+    RelayerSlashed(ChainId, EthereumTxHash, T::AccountId, BalanceOf<T>),
+    /// Threshold above which mints of the currency from the chain are delayed updated
+    LargeMintThresholdUpdated(ChainId, CurrencyId, Option<BalanceOf<T>>),
+    /// Mint above the large mint threshold pending until finalized, it can be vetoed until then.
+    /// \[chain_id, currency_id, tx, who, amount, finalize_at\]
+    MintDelayed(
+      ChainId,
+      CurrencyId,
+      EthereumTxHash,
+      EthereumAddress,
+      BalanceOf<T>,
+      T::BlockNumber,
+    ),
+    /// Pending large mint vetoed, the tx can not be minted again. \[chain_id, currency_id, tx\]
+    MintVetoed(ChainId, CurrencyId, EthereumTxHash),
+    /// Value of the currency locked on the chain reported. \[chain_id, currency_id, relayer, locked\]
+    LockReported(ChainId, CurrencyId, T::AccountId, BalanceOf<T>),
+    /// The claimed supply not burned back exceeds the locked value reported by the relayer
+    /// threshold, minting and claiming are paused. \[chain_id, currency_id, outstanding, locked\]
+    SupplyInvariantViolated(ChainId, CurrencyId, BalanceOf<T>, BalanceOf<T>),
+    /// The bridge keys of the new session differ. \[set_id, authorities\]
+    BridgeAuthoritiesChanged(u64, u32),
+    /// Burn signed by more than two thirds of the bridge authorities. \[chain_id, nonce, set_id\]
+    BurnCertified(ChainId, BurnNonce, u64),
+    /// Ethereum address frozen, it can not be minted to, claimed from or burned to
+    AddressFrozen(EthereumAddress),
+    /// Ethereum address unfrozen
+    AddressUnfrozen(EthereumAddress),
+    /// Account frozen, it can not claim or burn
+    AccountFrozen(T::AccountId),
+    /// Account unfrozen
+    AccountUnfrozen(T::AccountId),
+    /// A relayer voted to void the mint of the tx. \[chain_id, tx, relayer, votes\]
+    VoidAttested(ChainId, EthereumTxHash, T::AccountId, u32),
+    /// Part of the fee of a voided mint could not be reversed, it was swept already.
+    /// \[chain_id, currency_id, tx, shortfall\]
+    VoidFeeShortfall(ChainId, CurrencyId, EthereumTxHash, BalanceOf<T>),
   }
 
   #[pallet::storage]
-  #[pallet::getter(fn bridge_account)]
-  pub(super) type BridgeAccount<T: Config> = StorageValue<_, Option<T::AccountId>, ValueQuery>;
+  #[pallet::getter(fn storage_version)]
+  pub(super) type StorageVersion<T: Config> = StorageValue<_, Releases, ValueQuery>;
+
+  /// Nonce of the next burn signed by the EVM address
+  #[pallet::storage]
+  #[pallet::getter(fn evm_burn_nonce)]
+  pub(super) type EvmBurnNonce<T: Config> = StorageMap<_, Blake2_128Concat, H160, u64, ValueQuery>;
+
+  /// The currency deposited into the contract of the source chain, for `mint_with_proof`
+  #[pallet::storage]
+  #[pallet::getter(fn deposit_contracts)]
+  pub(super) type DepositContracts<T: Config> =
+    StorageDoubleMap<_, Twox64Concat, ChainId, Blake2_128Concat, H160, CurrencyId, OptionQuery>;
+
+  /// Whether the bridge operation is paused
+  #[pallet::storage]
+  #[pallet::getter(fn paused)]
+  pub(super) type Paused<T: Config> =
+    StorageMap<_, Twox64Concat, BridgeOperation, bool, ValueQuery>;
+
+  /// Whether minting from and burning to the chain is allowed
+  #[pallet::storage]
+  #[pallet::getter(fn chain_enabled)]
+  pub(super) type ChainEnabled<T: Config> = StorageMap<_, Twox64Concat, ChainId, bool, ValueQuery>;
+
+  /// The relayer committee which can attest mints of the chain
+  #[pallet::storage]
+  #[pallet::getter(fn relayers)]
+  pub(super) type Relayers<T: Config> =
+    StorageMap<_, Twox64Concat, ChainId, Vec<T::AccountId>, ValueQuery>;
+
+  /// Bonds reserved by the relayers
+  #[pallet::storage]
+  #[pallet::getter(fn bonds)]
+  pub(super) type Bonds<T: Config> = StorageMap<
+    _,
+    Blake2_128Concat,
+    T::AccountId,
+    BondInfo<BalanceOf<T>, T::BlockNumber>,
+    OptionQuery,
+  >;
+
+  /// Number of matching attestations required to mint a claim of the chain
+  #[pallet::storage]
+  #[pallet::getter(fn relayer_threshold)]
+  pub(super) type RelayerThreshold<T: Config> =
+    StorageMap<_, Twox64Concat, ChainId, u32, ValueQuery>;
+
+  /// Pending relayer attestations of the tx which has not been minted yet
+  #[pallet::storage]
+  #[pallet::getter(fn attestations)]
+  pub(super) type Attestations<T: Config> = StorageDoubleMap<
+    _,
+    Twox64Concat,
+    ChainId,
+    Blake2_128Concat,
+    EthereumTxHash,
+    Vec<AttestationOf<T>>,
+    ValueQuery,
+  >;
+
+  /// Share of the mint and burn fees of the chain credited to the relayer of the operation
+  #[pallet::storage]
+  #[pallet::getter(fn relayer_reward_share)]
+  pub(super) type RelayerRewardShare<T: Config> =
+    StorageMap<_, Twox64Concat, ChainId, Permill, ValueQuery>;
+
+  /// Reward ledger of the relayer in the currency
+  #[pallet::storage]
+  #[pallet::getter(fn relayer_rewards)]
+  pub(super) type RelayerRewards<T: Config> = StorageDoubleMap<
+    _,
+    Blake2_128Concat,
+    T::AccountId,
+    Twox64Concat,
+    CurrencyId,
+    RewardLedger<BalanceOf<T>>,
+    ValueQuery,
+  >;
+
+  /// Total unclaimed relayer rewards of the currency, held in the module account
+  /// and kept by `sweep_fees`
+  #[pallet::storage]
+  #[pallet::getter(fn unclaimed_rewards)]
+  pub(super) type UnclaimedRewards<T: Config> =
+    StorageMap<_, Twox64Concat, CurrencyId, BalanceOf<T>, ValueQuery>;
 
   #[pallet::storage]
   #[pallet::getter(fn claim_limit)]
-  pub(super) type ClaimLimit<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+  pub(super) type ClaimLimit<T: Config> =
+    StorageDoubleMap<_, Twox64Concat, ChainId, Twox64Concat, CurrencyId, BalanceOf<T>, ValueQuery>;
+
+  /// Rolling window which refills `ClaimLimit` of the currency from the chain
+  #[pallet::storage]
+  #[pallet::getter(fn claim_window)]
+  pub(super) type ClaimWindows<T: Config> = StorageDoubleMap<
+    _,
+    Twox64Concat,
+    ChainId,
+    Twox64Concat,
+    CurrencyId,
+    ClaimWindow<BalanceOf<T>, T::BlockNumber>,
+    OptionQuery,
+  >;
+
+  /// Maximum value of a single claim of the currency from the chain
+  #[pallet::storage]
+  #[pallet::getter(fn max_claim_amount)]
+  pub(super) type MaxClaimAmount<T: Config> =
+    StorageDoubleMap<_, Twox64Concat, ChainId, Twox64Concat, CurrencyId, BalanceOf<T>, OptionQuery>;
+
+  /// Maximum value of the currency minted to an ethereum address of the chain per day
+  #[pallet::storage]
+  #[pallet::getter(fn address_daily_cap)]
+  pub(super) type AddressDailyCap<T: Config> =
+    StorageDoubleMap<_, Twox64Concat, ChainId, Twox64Concat, CurrencyId, BalanceOf<T>, OptionQuery>;
+
+  /// The day index and value of the currency minted to the ethereum address on that day
+  #[pallet::storage]
+  pub(super) type AddressMinted<T: Config> = StorageDoubleMap<
+    _,
+    Twox64Concat,
+    (ChainId, CurrencyId),
+    Blake2_128Concat,
+    EthereumAddress,
+    (T::BlockNumber, BalanceOf<T>),
+    ValueQuery,
+  >;
+
+  /// Running totals of the currency bridged from and to the chain
+  #[pallet::storage]
+  #[pallet::getter(fn bridged_supply)]
+  pub(super) type BridgedSupplies<T: Config> = StorageDoubleMap<
+    _,
+    Twox64Concat,
+    ChainId,
+    Twox64Concat,
+    CurrencyId,
+    BridgedSupply<BalanceOf<T>>,
+    ValueQuery,
+  >;
+
+  /// The latest report of the value of the currency locked on the chain
+  #[pallet::storage]
+  #[pallet::getter(fn lock_report)]
+  pub(super) type LockReports<T: Config> = StorageDoubleMap<
+    _,
+    Twox64Concat,
+    ChainId,
+    Twox64Concat,
+    CurrencyId,
+    LockReport<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+    OptionQuery,
+  >;
+
+  /// Mints of the currency from the chain above this value are delayed by `LargeMintDelay`
+  #[pallet::storage]
+  #[pallet::getter(fn large_mint_threshold)]
+  pub(super) type LargeMintThreshold<T: Config> =
+    StorageDoubleMap<_, Twox64Concat, ChainId, Twox64Concat, CurrencyId, BalanceOf<T>, OptionQuery>;
 
+  /// Large mints waiting to be finalized or vetoed, their value is already taken from the limits
+  #[pallet::storage]
+  #[pallet::getter(fn pending_mints)]
+  pub(super) type PendingMints<T: Config> = StorageDoubleMap<
+    _,
+    Twox64Concat,
+    ChainId,
+    Blake2_128Concat,
+    EthereumTxHash,
+    PendingMint<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+    OptionQuery,
+  >;
+
+  /// The minted claims
   #[pallet::storage]
   #[pallet::getter(fn claims)]
-  pub(super) type Claims<T: Config> = StorageMap<
+  pub(super) type Claims<T: Config> = StorageDoubleMap<
+    _,
+    Twox64Concat,
+    ChainId,
+    Blake2_128Concat,
+    EthereumTxHash,
+    ClaimRecord<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+    OptionQuery,
+  >;
+
+  /// Vesting of the minted claims, the claimed amount is locked by `T::VestingSchedule`
+  #[pallet::storage]
+  #[pallet::getter(fn vesting)]
+  pub(super) type Vesting<T: Config> =
+    StorageDoubleMap<_, Twox64Concat, ChainId, Blake2_128Concat, EthereumTxHash, VestingOf<T>>;
+
+  /// Details of the minted claims
+  #[pallet::storage]
+  #[pallet::getter(fn mints)]
+  pub(super) type Mints<T: Config> = StorageDoubleMap<
+    _,
+    Twox64Concat,
+    ChainId,
+    Blake2_128Concat,
+    EthereumTxHash,
+    MintInfo<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+    OptionQuery,
+  >;
+
+  /// The revoked txs, which can not be minted again
+  #[pallet::storage]
+  #[pallet::getter(fn revoked_claims)]
+  pub(super) type RevokedClaims<T: Config> =
+    StorageDoubleMap<_, Twox64Concat, ChainId, Blake2_128Concat, EthereumTxHash, (), OptionQuery>;
+
+  /// Relayers which voted to void the unclaimed mint of the tx
+  #[pallet::storage]
+  #[pallet::getter(fn void_votes)]
+  pub(super) type VoidVotes<T: Config> = StorageDoubleMap<
     _,
+    Twox64Concat,
+    ChainId,
     Blake2_128Concat,
     EthereumTxHash,
-    Option<(EthereumAddress, BalanceOf<T>, bool)>,
+    Vec<T::AccountId>,
+    ValueQuery,
+  >;
+
+  /// Relayers whose latest lock report of the currency on the chain is short of the bridged
+  /// supply, with the locked value they reported
+  #[pallet::storage]
+  #[pallet::getter(fn shortfall_reports)]
+  pub(super) type ShortfallReports<T: Config> = StorageDoubleMap<
+    _,
+    Twox64Concat,
+    ChainId,
+    Twox64Concat,
+    CurrencyId,
+    Vec<(T::AccountId, BalanceOf<T>)>,
     ValueQuery,
   >;
 
+  /// Total mint and burn fees of the currency collected from the chain
+  #[pallet::storage]
+  #[pallet::getter(fn fees_collected)]
+  pub(super) type FeesCollected<T: Config> =
+    StorageDoubleMap<_, Twox64Concat, ChainId, Twox64Concat, CurrencyId, BalanceOf<T>, ValueQuery>;
+
   #[pallet::storage]
   #[pallet::getter(fn mint_fee)]
-  pub(super) type MintFee<T: Config> = StorageValue<_, Option<BalanceOf<T>>, ValueQuery>;
+  pub(super) type MintFee<T: Config> = StorageDoubleMap<
+    _,
+    Twox64Concat,
+    ChainId,
+    Twox64Concat,
+    CurrencyId,
+    FeeSchedule<BalanceOf<T>>,
+    OptionQuery,
+  >;
 
   #[pallet::storage]
   #[pallet::getter(fn burn_fee)]
-  pub(super) type BurnFee<T: Config> = StorageValue<_, Option<BalanceOf<T>>, ValueQuery>;
+  pub(super) type BurnFee<T: Config> = StorageDoubleMap<
+    _,
+    Twox64Concat,
+    ChainId,
+    Twox64Concat,
+    CurrencyId,
+    FeeSchedule<BalanceOf<T>>,
+    OptionQuery,
+  >;
+
+  /// The nonce of the next burn to the chain
+  #[pallet::storage]
+  #[pallet::getter(fn next_burn_nonce)]
+  pub(super) type NextBurnNonce<T: Config> =
+    StorageMap<_, Twox64Concat, ChainId, BurnNonce, ValueQuery>;
+
+  /// The burns of the chain below this nonce are not pending, where `pending_burns` starts
+  #[pallet::storage]
+  #[pallet::getter(fn lowest_pending_burn)]
+  pub(super) type LowestPendingBurn<T: Config> =
+    StorageMap<_, Twox64Concat, ChainId, BurnNonce, ValueQuery>;
+
+  /// Outbound burns by nonce
+  #[pallet::storage]
+  #[pallet::getter(fn burns)]
+  pub(super) type Burns<T: Config> = StorageDoubleMap<
+    _,
+    Twox64Concat,
+    ChainId,
+    Twox64Concat,
+    BurnNonce,
+    BurnInfo<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+    OptionQuery,
+  >;
+
+  /// Bridge keys of the validators of the current session
+  #[pallet::storage]
+  #[pallet::getter(fn bridge_authorities)]
+  pub(super) type BridgeAuthorities<T: Config> =
+    StorageValue<_, Vec<T::BridgeAuthorityId>, ValueQuery>;
+
+  /// Id of the current bridge authority set, incremented when the keys of a session differ
+  #[pallet::storage]
+  #[pallet::getter(fn bridge_authority_set_id)]
+  pub(super) type BridgeAuthoritySetId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+  /// Pending burns waiting for a certificate
+  #[pallet::storage]
+  pub(super) type UncertifiedBurns<T: Config> =
+    StorageDoubleMap<_, Twox64Concat, ChainId, Twox64Concat, BurnNonce, (), OptionQuery>;
+
+  /// Signatures of the bridge authorities collected for an uncertified burn
+  #[pallet::storage]
+  #[pallet::getter(fn burn_signatures)]
+  pub(super) type BurnSignatures<T: Config> = StorageDoubleMap<
+    _,
+    Twox64Concat,
+    ChainId,
+    Twox64Concat,
+    BurnNonce,
+    Vec<(T::BridgeAuthorityId, BridgeSignatureOf<T>)>,
+    ValueQuery,
+  >;
+
+  /// Certificates of the burns signed by more than two thirds of the bridge authorities
+  #[pallet::storage]
+  #[pallet::getter(fn burn_certificates)]
+  pub(super) type BurnCertificates<T: Config> = StorageDoubleMap<
+    _,
+    Twox64Concat,
+    ChainId,
+    Twox64Concat,
+    BurnNonce,
+    BurnCertificateOf<T>,
+    OptionQuery,
+  >;
+
+  /// Ethereum addresses which are blocked from bridging
+  #[pallet::storage]
+  #[pallet::getter(fn frozen_address)]
+  pub(super) type FrozenAddresses<T: Config> =
+    StorageMap<_, Blake2_128Concat, EthereumAddress, bool, ValueQuery>;
+
+  /// Accounts which are blocked from bridging
+  #[pallet::storage]
+  #[pallet::getter(fn frozen_account)]
+  pub(super) type FrozenAccounts<T: Config> =
+    StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+  #[pallet::genesis_config]
+  pub struct GenesisConfig<T: Config> {
+    /// Chains minting from and burning to are enabled
+    pub enabled_chains: Vec<ChainId>,
+    /// `(chain_id, relayer)`, the bridge accounts attesting mints of the chain,
+    /// not required to bond
+    pub relayers: Vec<(ChainId, T::AccountId)>,
+    /// `(chain_id, threshold)`, chains not listed mint with 1 attestation
+    pub relayer_thresholds: Vec<(ChainId, u32)>,
+    /// `(chain_id, currency_id, limit)`
+    pub claim_limits: Vec<(ChainId, CurrencyId, BalanceOf<T>)>,
+    /// `(chain_id, currency_id, schedule)`
+    pub mint_fees: Vec<(ChainId, CurrencyId, FeeSchedule<BalanceOf<T>>)>,
+    /// `(chain_id, currency_id, schedule)`
+    pub burn_fees: Vec<(ChainId, CurrencyId, FeeSchedule<BalanceOf<T>>)>,
+    /// `(chain_id, currency_id, tx, who, amount)`, minted claims waiting to be claimed
+    pub claims: Vec<(
+      ChainId,
+      CurrencyId,
+      EthereumTxHash,
+      EthereumAddress,
+      BalanceOf<T>,
+    )>,
+  }
+
+  #[cfg(feature = "std")]
+  impl<T: Config> Default for GenesisConfig<T> {
+    fn default() -> Self {
+      Self {
+        enabled_chains: Vec::new(),
+        relayers: Vec::new(),
+        relayer_thresholds: Vec::new(),
+        claim_limits: Vec::new(),
+        mint_fees: Vec::new(),
+        burn_fees: Vec::new(),
+        claims: Vec::new(),
+      }
+    }
+  }
+
+  #[pallet::genesis_build]
+  impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+    fn build(&self) {
+      StorageVersion::<T>::put(Releases::V8_0_0);
+
+      for chain_id in &self.enabled_chains {
+        ChainEnabled::<T>::insert(chain_id, true);
+      }
+      for (chain_id, relayer) in &self.relayers {
+        Relayers::<T>::mutate(chain_id, |relayers| {
+          assert!(!relayers.contains(relayer), "duplicate relayer");
+          relayers.push(relayer.clone());
+        });
+      }
+      for (chain_id, threshold) in &self.relayer_thresholds {
+        assert!(
+          *threshold >= 1 && *threshold as usize <= Relayers::<T>::get(chain_id).len(),
+          "invalid relayer threshold"
+        );
+        RelayerThreshold::<T>::insert(chain_id, threshold);
+      }
+      for (chain_id, currency_id, limit) in &self.claim_limits {
+        ClaimLimit::<T>::insert(chain_id, currency_id, limit);
+      }
+      for (chain_id, currency_id, schedule) in &self.mint_fees {
+        assert!(schedule.is_valid(), "invalid mint fee schedule");
+        MintFee::<T>::insert(chain_id, currency_id, schedule);
+      }
+      for (chain_id, currency_id, schedule) in &self.burn_fees {
+        assert!(schedule.is_valid(), "invalid burn fee schedule");
+        BurnFee::<T>::insert(chain_id, currency_id, schedule);
+      }
+      for (chain_id, currency_id, tx, who, amount) in &self.claims {
+        assert!(!Claims::<T>::contains_key(chain_id, tx), "duplicate claim");
+        BridgedSupplies::<T>::mutate(chain_id, currency_id, |supply| {
+          supply.minted = supply.minted.saturating_add(*amount)
+        });
+        Claims::<T>::insert(
+          chain_id,
+          tx,
+          ClaimRecord {
+            who: who.clone(),
+            amount: *amount,
+            mint_block: Zero::zero(),
+            relayer: None,
+            fee: Zero::zero(),
+            claimed: false,
+            claimed_by: None,
+            claim_block: None,
+          },
+        );
+        Mints::<T>::insert(
+          chain_id,
+          tx,
+          MintInfo {
+            currency_id: *currency_id,
+            block: Zero::zero(),
+            expiry: T::ClaimExpiry::get(),
+            relayers: Vec::new(),
+            fee: Zero::zero(),
+          },
+        );
+      }
+    }
+  }
 
   #[pallet::call]
   impl<T: Config> Pallet<T> {
-    #[pallet::weight(T::DbWeight::get().writes(2))]
+    #[pallet::weight(T::DbWeight::get().writes(1))]
     #[frame_support::transactional]
-    pub(super) fn set_bridge_account(
+    pub fn set_chain_enabled(
       origin: OriginFor<T>,
-      to: T::AccountId,
+      chain_id: ChainId,
+      enabled: bool,
     ) -> DispatchResultWithPostInfo {
-      ensure_root(origin)?;
-
-      <BridgeAccount<T>>::put(Some(to.clone()));
+      T::AdminOrigin::ensure_origin(origin)?;
 
-      Self::deposit_event(Event::BridgeAccountChanged(to));
+      ChainEnabled::<T>::insert(chain_id, enabled);
 
+      Self::deposit_event(Event::ChainStatusUpdated(chain_id, enabled));
       Ok(().into())
     }
 
-    #[pallet::weight(T::DbWeight::get().writes(2))]
+    /// Add the account to the relayer committee of the chain, it must have bonded at least
+    /// `RelayerBond` with `bond_relayer`.
+    #[pallet::weight(T::DbWeight::get().reads_writes(2, 1))]
     #[frame_support::transactional]
-    pub fn set_claim_limit(
+    pub fn add_relayer(
       origin: OriginFor<T>,
-      limit: BalanceOf<T>,
+      chain_id: ChainId,
+      who: T::AccountId,
     ) -> DispatchResultWithPostInfo {
-      ensure_root(origin)?;
+      T::AdminOrigin::ensure_origin(origin)?;
 
-      ClaimLimit::<T>::put(limit.clone());
+      let bond = Self::bonds(&who).map_or_else(Zero::zero, |bond| bond.active);
+      ensure!(bond >= T::RelayerBond::get(), Error::<T>::InsufficientBond);
+      Relayers::<T>::try_mutate(chain_id, |relayers| -> DispatchResult {
+        ensure!(!relayers.contains(&who), Error::<T>::AlreadyRelayer);
+        relayers.push(who.clone());
+        Ok(())
+      })?;
 
-      Self::deposit_event(Event::ClaimLimitUpdated(limit));
+      Self::deposit_event(Event::RelayerAdded(chain_id, who));
       Ok(().into())
     }
 
-    #[pallet::weight(T::DbWeight::get().writes(1))]
+    #[pallet::weight(T::DbWeight::get().reads_writes(2, 1))]
     #[frame_support::transactional]
-    pub fn set_mint_fee(origin: OriginFor<T>, fee: BalanceOf<T>) -> DispatchResultWithPostInfo {
-      ensure_root(origin)?;
+    pub fn remove_relayer(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      who: T::AccountId,
+    ) -> DispatchResultWithPostInfo {
+      T::AdminOrigin::ensure_origin(origin)?;
 
-      MintFee::<T>::put(Some(fee.clone()));
-      Self::deposit_event(Event::MintFeeUpdated(fee));
+      let threshold = Self::relayer_threshold(chain_id);
+      Relayers::<T>::try_mutate(chain_id, |relayers| -> DispatchResult {
+        let index = relayers
+          .iter()
+          .position(|r| r == &who)
+          .ok_or(Error::<T>::NotRelayer)?;
+        relayers.remove(index);
+        // the remaining committee must still be able to reach the threshold
+        ensure!(
+          relayers.len() as u32 >= threshold,
+          Error::<T>::InvalidThreshold
+        );
+        Ok(())
+      })?;
+
+      Self::deposit_event(Event::RelayerRemoved(chain_id, who));
       Ok(().into())
     }
 
-    #[pallet::weight(T::DbWeight::get().writes(1))]
+    #[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
     #[frame_support::transactional]
-    pub fn set_burn_fee(origin: OriginFor<T>, fee: BalanceOf<T>) -> DispatchResultWithPostInfo {
-      ensure_root(origin)?;
+    pub fn set_relayer_threshold(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      threshold: u32,
+    ) -> DispatchResultWithPostInfo {
+      T::AdminOrigin::ensure_origin(origin)?;
+
+      ensure!(
+        threshold > 0 && threshold <= Self::relayers(chain_id).len() as u32,
+        Error::<T>::InvalidThreshold
+      );
+      RelayerThreshold::<T>::insert(chain_id, threshold);
 
-      BurnFee::<T>::put(Some(fee.clone()));
-      Self::deposit_event(Event::BurnFeeUpdated(fee));
+      Self::deposit_event(Event::RelayerThresholdUpdated(chain_id, threshold));
       Ok(().into())
     }
 
-    #[pallet::weight(T::DbWeight::get().writes(3))]
+    /// Set the share of the mint and burn fees of the chain credited to the relayer which
+    /// minted or confirmed the operation.
+    #[pallet::weight(T::DbWeight::get().writes(1))]
     #[frame_support::transactional]
-    pub fn mint_claim(
+    pub fn set_relayer_reward_share(
       origin: OriginFor<T>,
-      tx: EthereumTxHash,
-      who: EthereumAddress,
-      value: BalanceOf<T>,
+      chain_id: ChainId,
+      share: Permill,
     ) -> DispatchResultWithPostInfo {
-      let signer = ensure_signed(origin)?;
-      let bridge_account = Self::bridge_account();
+      T::AdminOrigin::ensure_origin(origin)?;
 
-      // mint must be orginated from bridge account
-      ensure!(
-        Some(&signer) == bridge_account.as_ref(),
-        Error::<T>::NoPermission
-      );
-      // Check if this tx already be mint or be claimed
-      ensure!(!Claims::<T>::contains_key(&tx), Error::<T>::AlreadyMinted);
-      // Check claim limit
-      ensure!(Self::claim_limit() >= value, Error::<T>::ClaimLimitExceeded);
+      RelayerRewardShare::<T>::insert(chain_id, share);
+
+      Self::deposit_event(Event::RelayerRewardShareUpdated(chain_id, share));
+      Ok(().into())
+    }
+
+    /// Reserve `value` more of the signer as its relayer bond.
+    #[pallet::weight(T::DbWeight::get().reads_writes(2, 2))]
+    #[frame_support::transactional]
+    pub fn bond_relayer(origin: OriginFor<T>, value: BalanceOf<T>) -> DispatchResultWithPostInfo {
+      let who = ensure_signed(origin)?;
+      ensure!(!value.is_zero(), Error::<T>::InvalidAmount);
+
+      T::Currency::reserve(&who, value)?;
+      Bonds::<T>::mutate(&who, |bond| {
+        let bond = bond.get_or_insert_with(Default::default);
+        bond.active = bond.active.saturating_add(value);
+      });
+
+      Self::deposit_event(Event::RelayerBonded(who, value));
+      Ok(().into())
+    }
+
+    /// Start unbonding `value` of the active bond of the signer, it stays slashable for
+    /// `UnbondingDelay` blocks. Unbonding more restarts the delay of the whole unbonding
+    /// amount. A relayer of any committee must keep at least `RelayerBond` active.
+    #[pallet::weight(T::DbWeight::get().reads_writes(3, 1))]
+    #[frame_support::transactional]
+    pub fn unbond_relayer(origin: OriginFor<T>, value: BalanceOf<T>) -> DispatchResultWithPostInfo {
+      let who = ensure_signed(origin)?;
+      ensure!(!value.is_zero(), Error::<T>::InvalidAmount);
+
+      let mut bond = Self::bonds(&who).ok_or(Error::<T>::NotBonded)?;
+      ensure!(bond.active >= value, Error::<T>::InsufficientBond);
+      bond.active = bond.active.saturating_sub(value);
+      if Self::is_relayer(&who) {
+        ensure!(
+          bond.active >= T::RelayerBond::get(),
+          Error::<T>::InsufficientBond
+        );
+      }
+      bond.unbonding = bond.unbonding.saturating_add(value);
+      bond.unlock_at =
+        frame_system::Module::<T>::block_number().saturating_add(T::UnbondingDelay::get());
+      let unlock_at = bond.unlock_at;
+      Bonds::<T>::insert(&who, bond);
+
+      Self::deposit_event(Event::RelayerUnbonding(who, value, unlock_at));
+      Ok(().into())
+    }
+
+    /// Unreserve the unbonding bond of the signer once `UnbondingDelay` passed.
+    #[pallet::weight(T::DbWeight::get().reads_writes(2, 2))]
+    #[frame_support::transactional]
+    pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+      let who = ensure_signed(origin)?;
+
+      let mut bond = Self::bonds(&who).ok_or(Error::<T>::NotBonded)?;
+      ensure!(!bond.unbonding.is_zero(), Error::<T>::NothingToWithdraw);
+      ensure!(
+        frame_system::Module::<T>::block_number() >= bond.unlock_at,
+        Error::<T>::BondLocked
+      );
+      let amount = bond.unbonding;
+      T::Currency::unreserve(&who, amount);
+      bond.unbonding = Zero::zero();
+      if bond.active.is_zero() {
+        Bonds::<T>::remove(&who);
+      } else {
+        Bonds::<T>::insert(&who, bond);
+      }
+
+      Self::deposit_event(Event::RelayerBondWithdrawn(who, amount));
+      Ok(().into())
+    }
+
+    /// Slash up to `value` of the bond of a relayer proven to have attested a non-existent
+    /// or wrong deposit `tx` of the chain, the active bond first. The slashed funds go to
+    /// `T::Slashed`. A relayer left with less than `RelayerBond` active is removed from
+    /// every committee.
+    #[pallet::weight(T::DbWeight::get().reads_writes(4, 3))]
+    #[frame_support::transactional]
+    pub fn slash_relayer(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      tx: EthereumTxHash,
+      who: T::AccountId,
+      value: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+      T::SlashOrigin::ensure_origin(origin)?;
+
+      let mut bond = Self::bonds(&who).ok_or(Error::<T>::NotBonded)?;
+      // other reserves of the account are not touched
+      let value = value.min(bond.active.saturating_add(bond.unbonding));
+      let (imbalance, _) = T::Currency::slash_reserved(&who, value);
+      let slashed = imbalance.peek();
+      T::Slashed::on_unbalanced(imbalance);
+
+      let from_active = slashed.min(bond.active);
+      bond.active = bond.active.saturating_sub(from_active);
+      bond.unbonding = bond
+        .unbonding
+        .saturating_sub(slashed.saturating_sub(from_active));
+      let active = bond.active;
+      if active.is_zero() && bond.unbonding.is_zero() {
+        Bonds::<T>::remove(&who);
+      } else {
+        Bonds::<T>::insert(&who, bond);
+      }
+
+      Self::deposit_event(Event::RelayerSlashed(chain_id, tx, who.clone(), slashed));
+      if active < T::RelayerBond::get() {
+        Self::remove_from_committees(&who);
+      }
+      Ok(().into())
+    }
+
+    #[pallet::weight(T::DbWeight::get().writes(2))]
+    #[frame_support::transactional]
+    pub fn set_claim_limit(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      limit: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+      T::AdminOrigin::ensure_origin(origin)?;
+
+      ClaimLimit::<T>::insert(chain_id, currency_id, limit.clone());
+
+      Self::deposit_event(Event::ClaimLimitUpdated(chain_id, currency_id, limit));
+      Ok(().into())
+    }
+
+    /// Refill the claim limit of the currency from the chain to `limit` every `period` blocks,
+    /// the first window starts now. `None` stops refilling.
+    #[pallet::weight(T::DbWeight::get().writes(3))]
+    #[frame_support::transactional]
+    pub fn set_claim_window(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      window: Option<(BalanceOf<T>, T::BlockNumber)>,
+    ) -> DispatchResultWithPostInfo {
+      T::AdminOrigin::ensure_origin(origin)?;
+
+      let window = match window {
+        Some((limit, period)) => {
+          ensure!(!period.is_zero(), Error::<T>::InvalidPeriod);
+          let window = ClaimWindow {
+            limit,
+            period,
+            start: frame_system::Module::<T>::block_number(),
+          };
+          ClaimWindows::<T>::insert(chain_id, currency_id, window.clone());
+          ClaimLimit::<T>::insert(chain_id, currency_id, limit);
+          Some(window)
+        }
+        None => {
+          ClaimWindows::<T>::remove(chain_id, currency_id);
+          None
+        }
+      };
+
+      Self::deposit_event(Event::ClaimWindowUpdated(chain_id, currency_id, window));
+      Ok(().into())
+    }
+
+    #[pallet::weight(T::DbWeight::get().writes(1))]
+    #[frame_support::transactional]
+    pub fn set_max_claim_amount(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      amount: Option<BalanceOf<T>>,
+    ) -> DispatchResultWithPostInfo {
+      T::AdminOrigin::ensure_origin(origin)?;
+
+      match amount {
+        Some(amount) => MaxClaimAmount::<T>::insert(chain_id, currency_id, amount),
+        None => MaxClaimAmount::<T>::remove(chain_id, currency_id),
+      }
+      Self::deposit_event(Event::MaxClaimAmountUpdated(chain_id, currency_id, amount));
+      Ok(().into())
+    }
+
+    #[pallet::weight(T::DbWeight::get().writes(1))]
+    #[frame_support::transactional]
+    pub fn set_address_daily_cap(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      cap: Option<BalanceOf<T>>,
+    ) -> DispatchResultWithPostInfo {
+      T::AdminOrigin::ensure_origin(origin)?;
+
+      match cap {
+        Some(cap) => AddressDailyCap::<T>::insert(chain_id, currency_id, cap),
+        None => AddressDailyCap::<T>::remove(chain_id, currency_id),
+      }
+      Self::deposit_event(Event::AddressDailyCapUpdated(chain_id, currency_id, cap));
+      Ok(().into())
+    }
+
+    /// Delay the mints of the currency from the chain above `threshold` by `LargeMintDelay`
+    /// blocks, in which `VetoOrigin` can veto them. `None` mints any value instantly.
+    #[pallet::weight(T::DbWeight::get().writes(1))]
+    #[frame_support::transactional]
+    pub fn set_large_mint_threshold(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      threshold: Option<BalanceOf<T>>,
+    ) -> DispatchResultWithPostInfo {
+      T::AdminOrigin::ensure_origin(origin)?;
+
+      match threshold {
+        Some(threshold) => LargeMintThreshold::<T>::insert(chain_id, currency_id, threshold),
+        None => LargeMintThreshold::<T>::remove(chain_id, currency_id),
+      }
+      Self::deposit_event(Event::LargeMintThresholdUpdated(
+        chain_id,
+        currency_id,
+        threshold,
+      ));
+      Ok(().into())
+    }
+
+    #[pallet::weight(T::DbWeight::get().writes(1))]
+    #[frame_support::transactional]
+    pub fn set_mint_fee(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      fee: Option<FeeSchedule<BalanceOf<T>>>,
+    ) -> DispatchResultWithPostInfo {
+      T::AdminOrigin::ensure_origin(origin)?;
+
+      match fee {
+        Some(ref schedule) => {
+          ensure!(schedule.is_valid(), Error::<T>::InvalidFeeSchedule);
+          MintFee::<T>::insert(chain_id, currency_id, schedule);
+        }
+        None => MintFee::<T>::remove(chain_id, currency_id),
+      }
+      Self::deposit_event(Event::MintFeeUpdated(chain_id, currency_id, fee));
+      Ok(().into())
+    }
+
+    #[pallet::weight(T::DbWeight::get().writes(1))]
+    #[frame_support::transactional]
+    pub fn set_burn_fee(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      fee: Option<FeeSchedule<BalanceOf<T>>>,
+    ) -> DispatchResultWithPostInfo {
+      T::AdminOrigin::ensure_origin(origin)?;
+
+      match fee {
+        Some(ref schedule) => {
+          ensure!(schedule.is_valid(), Error::<T>::InvalidFeeSchedule);
+          BurnFee::<T>::insert(chain_id, currency_id, schedule);
+        }
+        None => BurnFee::<T>::remove(chain_id, currency_id),
+      }
+      Self::deposit_event(Event::BurnFeeUpdated(chain_id, currency_id, fee));
+      Ok(().into())
+    }
+
+    /// Pause the bridge operation until the admin origin unpauses it.
+    #[pallet::weight(T::DbWeight::get().writes(1))]
+    #[frame_support::transactional]
+    pub fn pause(origin: OriginFor<T>, operation: BridgeOperation) -> DispatchResultWithPostInfo {
+      T::PauseOrigin::ensure_origin(origin)?;
+
+      Paused::<T>::insert(operation, true);
+
+      Self::deposit_event(Event::OperationPaused(operation));
+      Ok(().into())
+    }
+
+    /// Lift the pause of the bridge operation.
+    #[pallet::weight(T::DbWeight::get().writes(1))]
+    #[frame_support::transactional]
+    pub fn unpause(origin: OriginFor<T>, operation: BridgeOperation) -> DispatchResultWithPostInfo {
+      T::AdminOrigin::ensure_origin(origin)?;
+
+      Paused::<T>::remove(operation);
+
+      Self::deposit_event(Event::OperationUnpaused(operation));
+      Ok(().into())
+    }
+
+    /// Freeze the Ethereum address, deposits to it are not minted and its claims and burns
+    /// to it are rejected until it is unfrozen.
+    #[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+    #[frame_support::transactional]
+    pub fn freeze_address(
+      origin: OriginFor<T>,
+      address: EthereumAddress,
+    ) -> DispatchResultWithPostInfo {
+      T::FreezeOrigin::ensure_origin(origin)?;
+      ensure!(!Self::frozen_address(&address), Error::<T>::AlreadyFrozen);
+
+      FrozenAddresses::<T>::insert(&address, true);
+
+      Self::deposit_event(Event::AddressFrozen(address));
+      Ok(().into())
+    }
+
+    /// Lift the freeze of the Ethereum address.
+    #[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+    #[frame_support::transactional]
+    pub fn unfreeze_address(
+      origin: OriginFor<T>,
+      address: EthereumAddress,
+    ) -> DispatchResultWithPostInfo {
+      T::FreezeOrigin::ensure_origin(origin)?;
+      ensure!(Self::frozen_address(&address), Error::<T>::NotFrozen);
+
+      FrozenAddresses::<T>::remove(&address);
+
+      Self::deposit_event(Event::AddressUnfrozen(address));
+      Ok(().into())
+    }
+
+    /// Freeze the account, claims to it and burns from it are rejected until it is unfrozen.
+    #[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+    #[frame_support::transactional]
+    pub fn freeze_account(
+      origin: OriginFor<T>,
+      account: T::AccountId,
+    ) -> DispatchResultWithPostInfo {
+      T::FreezeOrigin::ensure_origin(origin)?;
+      ensure!(!Self::frozen_account(&account), Error::<T>::AlreadyFrozen);
+
+      FrozenAccounts::<T>::insert(&account, true);
+
+      Self::deposit_event(Event::AccountFrozen(account));
+      Ok(().into())
+    }
+
+    /// Lift the freeze of the account.
+    #[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+    #[frame_support::transactional]
+    pub fn unfreeze_account(
+      origin: OriginFor<T>,
+      account: T::AccountId,
+    ) -> DispatchResultWithPostInfo {
+      T::FreezeOrigin::ensure_origin(origin)?;
+      ensure!(Self::frozen_account(&account), Error::<T>::NotFrozen);
+
+      FrozenAccounts::<T>::remove(&account);
+
+      Self::deposit_event(Event::AccountUnfrozen(account));
+      Ok(().into())
+    }
+
+    /// Attest a deposit on the source chain, the claim is minted once `RelayerThreshold`
+    /// relayers attested the same `(tx, currency_id, who, value, vesting)`. A claim with
+    /// `vesting` is locked by `T::VestingSchedule` when it is claimed, native currency only.
+    #[pallet::weight(T::DbWeight::get().reads_writes(12, 7))]
+    #[frame_support::transactional]
+    pub fn mint_claim(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      tx: EthereumTxHash,
+      who: EthereumAddress,
+      value: BalanceOf<T>,
+      vesting: Option<VestingOf<T>>,
+    ) -> DispatchResultWithPostInfo {
+      let signer = ensure_signed(origin)?;
+      ensure!(!Self::paused(BridgeOperation::Mint), Error::<T>::MintPaused);
+      ensure!(Self::chain_enabled(chain_id), Error::<T>::ChainDisabled);
+      let relayers = Self::relayers(chain_id);
+
+      // mint must be orginated from a relayer of the chain
+      ensure!(relayers.contains(&signer), Error::<T>::NoPermission);
+      if let Some(vesting) = &vesting {
+        ensure!(
+          currency_id == T::NativeCurrencyId::get(),
+          Error::<T>::VestingNotSupported
+        );
+        ensure!(
+          !vesting.per_block.is_zero(),
+          Error::<T>::InvalidVestingSchedule
+        );
+      }
+      Self::attest(
+        signer,
+        &relayers,
+        chain_id,
+        currency_id,
+        tx,
+        who,
+        value,
+        vesting,
+      )?;
+      Ok(().into())
+    }
+
+    /// Attest a batch of `(tx, who, value)` deposits of the currency, same as `mint_claim`
+    /// without vesting for each item. Items which can not be attested or minted, e.g. already
+    /// minted or over the claim limit, are skipped with a `MintSkipped` event.
+    #[pallet::weight(
+      T::DbWeight::get()
+        .reads_writes(12, 6)
+        .saturating_mul(items.len() as Weight)
+        .saturating_add(T::DbWeight::get().reads(3))
+    )]
+    #[frame_support::transactional]
+    pub fn mint_claims_batch(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      items: Vec<(EthereumTxHash, EthereumAddress, BalanceOf<T>)>,
+    ) -> DispatchResultWithPostInfo {
+      let signer = ensure_signed(origin)?;
+      ensure!(items.len() <= MAX_BATCH_SIZE, Error::<T>::BatchTooLarge);
+      ensure!(!Self::paused(BridgeOperation::Mint), Error::<T>::MintPaused);
+      ensure!(Self::chain_enabled(chain_id), Error::<T>::ChainDisabled);
+      let relayers = Self::relayers(chain_id);
+      ensure!(relayers.contains(&signer), Error::<T>::NoPermission);
+
+      for (tx, who, value) in items {
+        // a skipped item leaves no change behind
+        let result = with_transaction(|| {
+          match Self::attest(
+            signer.clone(),
+            &relayers,
+            chain_id,
+            currency_id,
+            tx,
+            who,
+            value,
+            None,
+          ) {
+            Ok(()) => TransactionOutcome::Commit(Ok(())),
+            Err(e) => TransactionOutcome::Rollback(Err(e)),
+          }
+        });
+        if let Err(e) = result {
+          Self::deposit_event(Event::MintSkipped(chain_id, tx, e));
+        }
+      }
+      Ok(().into())
+    }
+
+    /// Mint the deposit proven by the receipt of a finalized block of the source chain,
+    /// without relayers. The `log_index`th log of the receipt must be a `DEPOSIT_EVENT` of a
+    /// deposit contract, a tx mints at most one deposit.
+    #[pallet::weight(T::DbWeight::get().reads_writes(13, 6))]
+    #[frame_support::transactional]
+    pub fn mint_with_proof(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      proof: ReceiptProof,
+      log_index: u32,
+    ) -> DispatchResultWithPostInfo {
+      let signer = ensure_signed(origin)?;
+      ensure!(!Self::paused(BridgeOperation::Mint), Error::<T>::MintPaused);
+      ensure!(Self::chain_enabled(chain_id), Error::<T>::ChainDisabled);
+
+      let receipt = T::ReceiptVerifier::verify_receipt(chain_id, &proof)?;
+      ensure!(receipt.success, Error::<T>::TxFailed);
+      let log = receipt
+        .logs
+        .get(log_index as usize)
+        .ok_or(Error::<T>::DepositNotFound)?;
+      let currency_id =
+        Self::deposit_contracts(chain_id, &log.address).ok_or(Error::<T>::DepositNotFound)?;
+      let (who, value) = Self::decode_deposit(log)?;
+      ensure!(!Self::frozen_address(&who), Error::<T>::AddressFrozen);
+
+      let tx = EthereumTxHash(receipt.tx_hash.0);
+      ensure!(
+        !Claims::<T>::contains_key(chain_id, &tx)
+          && !PendingMints::<T>::contains_key(chain_id, &tx),
+        Error::<T>::AlreadyMinted
+      );
+      ensure!(
+        !RevokedClaims::<T>::contains_key(chain_id, &tx),
+        Error::<T>::AlreadyRevoked
+      );
+
+      Self::do_mint(
+        chain_id,
+        currency_id,
+        tx,
+        who,
+        value,
+        None,
+        signer,
+        Vec::new(),
+      )?;
+      Ok(().into())
+    }
+
+    /// Add the deposit contract of the currency on the chain, or remove it with `None`.
+    #[pallet::weight(T::DbWeight::get().writes(1))]
+    #[frame_support::transactional]
+    pub fn set_deposit_contract(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      contract: H160,
+      currency_id: Option<CurrencyId>,
+    ) -> DispatchResultWithPostInfo {
+      T::AdminOrigin::ensure_origin(origin)?;
+
+      match currency_id {
+        Some(currency_id) => DepositContracts::<T>::insert(chain_id, contract, currency_id),
+        None => DepositContracts::<T>::remove(chain_id, contract),
+      }
+
+      Self::deposit_event(Event::DepositContractUpdated(
+        chain_id,
+        contract,
+        currency_id,
+      ));
+      Ok(().into())
+    }
+
+    #[pallet::weight(
+      T::DbWeight::get()
+        .reads_writes(14, 9)
+        .saturating_add(CLAIM_SIGNATURE_WEIGHT)
+    )]
+    #[frame_support::transactional]
+    pub fn claim(
+      origin: OriginFor<T>,
+      dest: T::AccountId,
+      chain_id: ChainId,
+      tx: EthereumTxHash,
+      sig: EcdsaSignature,
+    ) -> DispatchResultWithPostInfo {
+      ensure_none(origin)?;
+
+      let signer = Self::eth_recover(&sig, &Self::claim_message(&dest, &tx), chain_id);
+
+      Self::do_claim(dest, chain_id, tx, signer)
+    }
+
+    /// Same as `claim`, but signed as the EIP-712 typed data of `claim_typed_data_hash`,
+    /// which wallets display field by field.
+    #[pallet::weight(
+      T::DbWeight::get()
+        .reads_writes(14, 9)
+        .saturating_add(CLAIM_SIGNATURE_WEIGHT)
+    )]
+    #[frame_support::transactional]
+    pub fn claim_typed(
+      origin: OriginFor<T>,
+      dest: T::AccountId,
+      chain_id: ChainId,
+      tx: EthereumTxHash,
+      sig: EthereumSignature,
+    ) -> DispatchResultWithPostInfo {
+      ensure_none(origin)?;
+
+      let hash = Self::claim_typed_data_hash(&dest, chain_id, &tx);
+      let signer = recover(&sig, &hash, chain_id).map(EthereumAddress);
+
+      Self::do_claim(dest, chain_id, tx, signer)
+    }
+
+    /// Same as `claim`, but the funds are claimed to the account of the EVM address `dest`
+    /// which is resolved by `T::AddressMapping`.
+    #[pallet::weight(
+      T::DbWeight::get()
+        .reads_writes(14, 9)
+        .saturating_add(CLAIM_SIGNATURE_WEIGHT)
+    )]
+    #[frame_support::transactional]
+    pub fn claim_to_evm(
+      origin: OriginFor<T>,
+      dest: H160,
+      chain_id: ChainId,
+      tx: EthereumTxHash,
+      sig: EcdsaSignature,
+    ) -> DispatchResultWithPostInfo {
+      ensure_none(origin)?;
+
+      let signer = Self::eth_recover(&sig, &Self::evm_claim_message(&dest, &tx), chain_id);
+
+      Self::do_claim(
+        T::AddressMapping::into_account_id(dest),
+        chain_id,
+        tx,
+        signer,
+      )
+    }
+
+    #[pallet::weight(T::DbWeight::get().reads_writes(5, 6))]
+    #[frame_support::transactional]
+    pub fn burn(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      dest: EthereumAddress,
+      amount: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+      let who = ensure_signed(origin)?;
+
+      Self::do_burn(who, chain_id, currency_id, dest, amount)
+    }
+
+    /// Same as `burn`, but burns the funds held by the account of the EVM address `source`,
+    /// signed by `source` as `evm_burn_message`, which is bound to the genesis of this chain.
+    #[pallet::weight(T::DbWeight::get().reads_writes(6, 7))]
+    #[frame_support::transactional]
+    pub fn burn_from_evm(
+      origin: OriginFor<T>,
+      source: H160,
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      dest: EthereumAddress,
+      amount: BalanceOf<T>,
+      sig: EcdsaSignature,
+    ) -> DispatchResultWithPostInfo {
+      ensure_none(origin)?;
+
+      let nonce = Self::evm_burn_nonce(&source);
+      let msg = Self::evm_burn_message(chain_id, currency_id, &dest, amount, nonce);
+      ensure!(
+        Self::eth_recover(&sig, &msg, chain_id) == Some(EthereumAddress(source.0)),
+        Error::<T>::SignatureNotMatch
+      );
+      EvmBurnNonce::<T>::insert(&source, nonce.saturating_add(1));
+
+      let who = T::AddressMapping::into_account_id(source);
+      Self::do_burn(who, chain_id, currency_id, dest, amount)
+    }
+
+    /// Send all the fees of the currency held by the module account to `T::FeeDestination`,
+    /// or `T::TokenFeeDestination` for currencies other than the native one.
+    #[pallet::weight(T::DbWeight::get().reads_writes(2, 2))]
+    #[frame_support::transactional]
+    pub fn sweep_fees(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResultWithPostInfo {
+      T::AdminOrigin::ensure_origin(origin)?;
+
+      let account = Self::account_id();
+      // the unclaimed relayer rewards are kept
+      let rewards = Self::unclaimed_rewards(currency_id);
+      let amount = if currency_id == T::NativeCurrencyId::get() {
+        let amount = T::Currency::free_balance(&account).saturating_sub(rewards);
+        let fees = T::Currency::withdraw(
+          &account,
+          amount,
+          WithdrawReasons::TRANSFER,
+          ExistenceRequirement::AllowDeath,
+        )?;
+        T::FeeDestination::on_unbalanced(fees);
+        amount
+      } else {
+        let amount = T::MultiCurrency::free_balance(currency_id, &account).saturating_sub(rewards);
+        T::MultiCurrency::transfer(
+          currency_id,
+          &account,
+          &T::TokenFeeDestination::get(),
+          amount,
+        )?;
+        amount
+      };
+
+      Self::deposit_event(Event::FeesSwept(currency_id, amount));
+      Ok(().into())
+    }
+
+    /// Pay out the unclaimed relayer rewards of the currency to the signer.
+    #[pallet::weight(T::DbWeight::get().reads_writes(4, 4))]
+    #[frame_support::transactional]
+    pub fn claim_relayer_rewards(
+      origin: OriginFor<T>,
+      currency_id: CurrencyId,
+    ) -> DispatchResultWithPostInfo {
+      let who = ensure_signed(origin)?;
+
+      let amount = RelayerRewards::<T>::mutate(&who, currency_id, |ledger| {
+        let amount = ledger.unclaimed();
+        ledger.claimed = ledger.claimed.saturating_add(amount);
+        amount
+      });
+      ensure!(!amount.is_zero(), Error::<T>::NoRewards);
+      UnclaimedRewards::<T>::mutate(currency_id, |r| *r = r.saturating_sub(amount));
+
+      let account = Self::account_id();
+      if currency_id == T::NativeCurrencyId::get() {
+        T::Currency::transfer(&account, &who, amount, ExistenceRequirement::AllowDeath)?;
+      } else {
+        T::MultiCurrency::transfer(currency_id, &account, &who, amount)?;
+      }
+
+      Self::deposit_event(Event::RelayerRewardsClaimed(who, currency_id, amount));
+      Ok(().into())
+    }
+
+    /// Revoke an expired mint which is not claimed, the tx can not be minted again.
+    /// With `refund` a burn releasing the amount to the depositor on the source chain is queued.
+    #[pallet::weight(T::DbWeight::get().reads_writes(4, 8))]
+    #[frame_support::transactional]
+    pub fn revoke_claim(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      tx: EthereumTxHash,
+      refund: bool,
+    ) -> DispatchResultWithPostInfo {
+      T::AdminOrigin::ensure_origin(origin)?;
+
+      // mints before expiry was recorded can be revoked any time
+      if let Some(mint) = Self::mints(chain_id, &tx) {
+        ensure!(
+          frame_system::Module::<T>::block_number() >= mint.expiry,
+          Error::<T>::ClaimNotExpired
+        );
+      }
+      let (currency_id, who, amount) = Self::remove_unclaimed(chain_id, &tx)?;
+      RevokedClaims::<T>::insert(chain_id, &tx, ());
+
+      // a failed release is refunded to the account of the depositor's address on Clover
+      let nonce = if refund {
+        // the refund burn is backed by the deposit, count the mint as claimed for it
+        BridgedSupplies::<T>::mutate(chain_id, currency_id, |supply| {
+          supply.minted = supply.minted.saturating_add(amount);
+          supply.claimed = supply.claimed.saturating_add(amount);
+        });
+        Some(Self::queue_burn(
+          T::AddressMapping::into_account_id(H160(who.0)),
+          chain_id,
+          currency_id,
+          who,
+          amount,
+          Zero::zero(),
+        ))
+      } else {
+        None
+      };
+
+      Self::deposit_event(Event::ClaimRevoked(
+        chain_id,
+        currency_id,
+        tx,
+        who,
+        amount,
+        nonce,
+      ));
+      Ok(().into())
+    }
+
+    /// Void a mint which is not claimed, called by the admin origin or once `RelayerThreshold`
+    /// relayers of the chain voted for it. The tx can not be minted again, as with
+    /// `revoke_claim`.
+    #[pallet::weight(T::DbWeight::get().reads_writes(8, 7))]
+    #[frame_support::transactional]
+    pub fn void_mint(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      tx: EthereumTxHash,
+    ) -> DispatchResultWithPostInfo {
+      let relayer = match T::AdminOrigin::try_origin(origin) {
+        Ok(_) => None,
+        Err(origin) => Some(ensure_signed(origin)?),
+      };
+
+      let mint = Self::mints(chain_id, &tx).ok_or(Error::<T>::TxNotMinted)?;
+      if let Some(relayer) = relayer {
+        let relayers = Self::relayers(chain_id);
+        ensure!(relayers.contains(&relayer), Error::<T>::NoPermission);
+        let mut votes = Self::void_votes(chain_id, &tx);
+        // votes of removed relayers are not counted
+        votes.retain(|r| relayers.contains(r));
+        ensure!(!votes.contains(&relayer), Error::<T>::AlreadyVoted);
+        votes.push(relayer.clone());
+
+        let count = votes.len() as u32;
+        Self::deposit_event(Event::VoidAttested(chain_id, tx, relayer, count));
+        if count < Self::relayer_threshold(chain_id).max(1) {
+          // a claimed mint can not be voided
+          ensure!(
+            !Self::claims(chain_id, &tx).map_or(false, |record| record.claimed),
+            Error::<T>::AlreadyClaimed
+          );
+          VoidVotes::<T>::insert(chain_id, &tx, votes);
+          return Ok(().into());
+        }
+      }
+      Self::remove_unclaimed(chain_id, &tx)?;
+      RevokedClaims::<T>::insert(chain_id, &tx, ());
+      // the fee was charged by mistake as well, only what is left of it after `sweep_fees`
+      // is reversed
+      if mint.fee > Zero::zero() {
+        let reversed = Self::reverse_fee(mint.currency_id, mint.fee);
+        FeesCollected::<T>::mutate(chain_id, mint.currency_id, |f| {
+          *f = f.saturating_sub(reversed)
+        });
+        let shortfall = mint.fee.saturating_sub(reversed);
+        if !shortfall.is_zero() {
+          Self::deposit_event(Event::VoidFeeShortfall(
+            chain_id,
+            mint.currency_id,
+            tx,
+            shortfall,
+          ));
+        }
+      }
+
+      Self::deposit_event(Event::MintVoided(chain_id, tx));
+      Ok(().into())
+    }
+
+    /// Mint the pending large mint once `LargeMintDelay` passed. Scheduled with the root origin
+    /// when the mint is delayed, any account can finalize it if the scheduled call failed.
+    #[pallet::weight(T::DbWeight::get().reads_writes(5, 7))]
+    #[frame_support::transactional]
+    pub fn finalize_mint(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      tx: EthereumTxHash,
+    ) -> DispatchResultWithPostInfo {
+      if ensure_signed(origin.clone()).is_err() {
+        ensure_root(origin)?;
+      }
+      ensure!(!Self::paused(BridgeOperation::Mint), Error::<T>::MintPaused);
+
+      let mint = Self::pending_mints(chain_id, &tx).ok_or(Error::<T>::MintNotPending)?;
+      ensure!(
+        frame_system::Module::<T>::block_number() >= mint.finalize_at,
+        Error::<T>::MintDelayNotPassed
+      );
+      ensure!(!Self::frozen_address(&mint.who), Error::<T>::AddressFrozen);
+      PendingMints::<T>::remove(chain_id, &tx);
+      // no-op when dispatched by the scheduler
+      let _ = T::Scheduler::cancel_named((LARGE_MINT_ID, chain_id, tx).encode());
+
+      Self::record_mint(
+        chain_id,
+        tx,
+        mint.currency_id,
+        mint.who,
+        mint.amount,
+        mint.fee,
+        mint.vesting,
+        mint.relayer,
+        mint.relayers,
+      )?;
+      Ok(().into())
+    }
+
+    /// Veto a pending large mint, its value is given back to the limits and the tx can not be
+    /// minted again.
+    #[pallet::weight(T::DbWeight::get().reads_writes(4, 6))]
+    #[frame_support::transactional]
+    pub fn veto_mint(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      tx: EthereumTxHash,
+    ) -> DispatchResultWithPostInfo {
+      T::VetoOrigin::ensure_origin(origin)?;
+
+      let mint = PendingMints::<T>::take(chain_id, &tx).ok_or(Error::<T>::MintNotPending)?;
+      let _ = T::Scheduler::cancel_named((LARGE_MINT_ID, chain_id, tx).encode());
+      Self::release_limits(chain_id, mint.currency_id, &mint.who, mint.amount, mint.fee);
+      RevokedClaims::<T>::insert(chain_id, &tx, ());
+
+      Self::deposit_event(Event::MintVetoed(chain_id, mint.currency_id, tx));
+      Ok(().into())
+    }
+
+    /// Report the value of the currency locked by the bridge on the chain, called by a relayer
+    /// of the chain. Once `RelayerThreshold` relayers report less locked than the claimed
+    /// supply not burned back, minting and claiming are paused until the admin origin unpauses
+    /// them.
+    #[pallet::weight(T::DbWeight::get().reads_writes(7, 4))]
+    #[frame_support::transactional]
+    pub fn report_locked(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      locked: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+      let signer = ensure_signed(origin)?;
+      let relayers = Self::relayers(chain_id);
+      ensure!(relayers.contains(&signer), Error::<T>::NoPermission);
+
+      LockReports::<T>::insert(
+        chain_id,
+        currency_id,
+        LockReport {
+          relayer: signer.clone(),
+          locked,
+          block: frame_system::Module::<T>::block_number(),
+        },
+      );
+      Self::deposit_event(Event::LockReported(
+        chain_id,
+        currency_id,
+        signer.clone(),
+        locked,
+      ));
+
+      // only the latest report of each current relayer counts, checked against the supply now
+      let outstanding = Self::bridged_supply(chain_id, currency_id).outstanding();
+      let mut reports = Self::shortfall_reports(chain_id, currency_id);
+      reports
+        .retain(|(r, reported)| r != &signer && relayers.contains(r) && outstanding > *reported);
+      if outstanding > locked {
+        reports.push((signer, locked));
+      }
+
+      let count = reports.len() as u32;
+      if count >= Self::relayer_threshold(chain_id).max(1) {
+        let locked = reports
+          .iter()
+          .map(|(_, reported)| *reported)
+          .max()
+          .unwrap_or(locked);
+        Self::deposit_event(Event::SupplyInvariantViolated(
+          chain_id,
+          currency_id,
+          outstanding,
+          locked,
+        ));
+        for operation in [BridgeOperation::Mint, BridgeOperation::Claim].iter() {
+          if !Self::paused(operation) {
+            Paused::<T>::insert(operation, true);
+            Self::deposit_event(Event::OperationPaused(*operation));
+          }
+        }
+        reports.clear();
+      }
+      if reports.is_empty() {
+        ShortfallReports::<T>::remove(chain_id, currency_id);
+      } else {
+        ShortfallReports::<T>::insert(chain_id, currency_id, reports);
+      }
+      Ok(().into())
+    }
+
+    /// Confirm the burn is released on the dest chain by `tx`, the signer is credited its
+    /// `RelayerRewardShare` of the burn fee.
+    #[pallet::weight(
+      T::DbWeight::get()
+        .reads_writes(6 + MAX_BURN_CURSOR_STEPS as Weight, 4)
+    )]
+    #[frame_support::transactional]
+    pub fn confirm_burn(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      nonce: BurnNonce,
+      tx: EthereumTxHash,
+    ) -> DispatchResultWithPostInfo {
+      let signer = ensure_signed(origin)?;
+      ensure!(
+        Self::relayers(chain_id).contains(&signer),
+        Error::<T>::NoPermission
+      );
+
+      Self::do_confirm_burn(&signer, chain_id, nonce, tx)?;
+      Self::advance_lowest_pending_burn(chain_id, MAX_BURN_CURSOR_STEPS);
+      Ok(().into())
+    }
+
+    /// Confirm a batch of `(nonce, tx)` burns released on the dest chain. Burns which are
+    /// not pending are skipped with a `BurnConfirmationSkipped` event.
+    #[pallet::weight(
+      T::DbWeight::get()
+        .reads_writes(5, 3)
+        .saturating_mul(burns.len() as Weight)
+        .saturating_add(
+          T::DbWeight::get().reads_writes(2 + MAX_BURN_CURSOR_STEPS as Weight, 1)
+        )
+    )]
+    #[frame_support::transactional]
+    pub fn confirm_burns(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      burns: Vec<(BurnNonce, EthereumTxHash)>,
+    ) -> DispatchResultWithPostInfo {
+      let signer = ensure_signed(origin)?;
+      ensure!(burns.len() <= MAX_BATCH_SIZE, Error::<T>::BatchTooLarge);
+      ensure!(
+        Self::relayers(chain_id).contains(&signer),
+        Error::<T>::NoPermission
+      );
+
+      let steps = burns.len() as u64 + MAX_BURN_CURSOR_STEPS;
+      for (nonce, tx) in burns {
+        if let Err(e) = Self::do_confirm_burn(&signer, chain_id, nonce, tx) {
+          Self::deposit_event(Event::BurnConfirmationSkipped(chain_id, nonce, e));
+        }
+      }
+      Self::advance_lowest_pending_burn(chain_id, steps);
+      Ok(().into())
+    }
+
+    /// Refund a burn which is still pending after `BurnRefundDelay` blocks,
+    /// called by the burner or the admin origin. The bridge fee is not refunded.
+    #[pallet::weight(
+      T::DbWeight::get()
+        .reads_writes(6 + MAX_BURN_CURSOR_STEPS as Weight, 6)
+    )]
+    #[frame_support::transactional]
+    pub fn refund_burn(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      nonce: BurnNonce,
+    ) -> DispatchResultWithPostInfo {
+      let maybe_who = match ensure_signed(origin.clone()) {
+        Ok(who) => Some(who),
+        Err(_) => {
+          T::AdminOrigin::ensure_origin(origin)?;
+          None
+        }
+      };
+
+      let (who, currency_id, amount) = Burns::<T>::try_mutate(
+        chain_id,
+        nonce,
+        |burn| -> Result<(T::AccountId, CurrencyId, BalanceOf<T>), DispatchError> {
+          let burn = burn.as_mut().ok_or(Error::<T>::BurnNotFound)?;
+          if let Some(ref who) = maybe_who {
+            ensure!(who == &burn.who, Error::<T>::NoPermission);
+          }
+          ensure!(
+            burn.status == BurnStatus::Pending,
+            Error::<T>::BurnNotPending
+          );
+          ensure!(
+            !BurnCertificates::<T>::contains_key(chain_id, nonce),
+            Error::<T>::BurnCertified
+          );
+          ensure!(
+            frame_system::Module::<T>::block_number()
+              >= burn.block.saturating_add(T::BurnRefundDelay::get()),
+            Error::<T>::RefundNotAvailable
+          );
+          burn.status = BurnStatus::Refunded;
+          Ok((burn.who.clone(), burn.currency_id, burn.amount))
+        },
+      )?;
+      Self::deposit(currency_id, &who, amount)?;
+      BridgedSupplies::<T>::mutate(chain_id, currency_id, |supply| {
+        supply.burned = supply.burned.saturating_sub(amount)
+      });
+      UncertifiedBurns::<T>::remove(chain_id, nonce);
+      BurnSignatures::<T>::remove(chain_id, nonce);
+      Self::advance_lowest_pending_burn(chain_id, MAX_BURN_CURSOR_STEPS);
+
+      Self::deposit_event(Event::BurnRefunded(
+        chain_id,
+        currency_id,
+        nonce,
+        who,
+        amount,
+      ));
+      Ok(().into())
+    }
+
+    /// Signature of a bridge authority over the `burn_certificate_message` of an uncertified
+    /// burn, submitted unsigned by the offchain workers of the validators. The burn is
+    /// certified once more than two thirds of the current authorities signed it.
+    #[pallet::weight(T::DbWeight::get().reads_writes(5, 3))]
+    pub fn submit_burn_signature(
+      origin: OriginFor<T>,
+      chain_id: ChainId,
+      nonce: BurnNonce,
+      authority: T::BridgeAuthorityId,
+      signature: BridgeSignatureOf<T>,
+    ) -> DispatchResultWithPostInfo {
+      ensure_none(origin)?;
+      Self::check_burn_signature(chain_id, nonce, &authority, &signature)?;
+
+      // signatures of the authorities of previous sessions no longer count
+      let authorities = Self::bridge_authorities();
+      let mut signatures = Self::burn_signatures(chain_id, nonce);
+      signatures.retain(|(signer, _)| authorities.contains(signer));
+      signatures.push((authority, signature));
+
+      if signatures.len() >= Self::certificate_threshold(authorities.len()) {
+        let set_id = Self::bridge_authority_set_id();
+        UncertifiedBurns::<T>::remove(chain_id, nonce);
+        BurnSignatures::<T>::remove(chain_id, nonce);
+        BurnCertificates::<T>::insert(chain_id, nonce, BurnCertificate { set_id, signatures });
+        Self::deposit_event(Event::BurnCertified(chain_id, nonce, set_id));
+      } else {
+        BurnSignatures::<T>::insert(chain_id, nonce, signatures);
+      }
+      Ok(().into())
+    }
+  }
+
+  #[repr(u8)]
+  pub enum ValidityError {
+    /// The Ethereum signature is invalid.
+    InvalidEthereumSignature = 0,
+    /// The signer has no claim.
+    SignerHasNoClaim = 1,
+    /// No permission to execute the call.
+    SignatureNotMatch = 2,
+    /// This tx already be claimed.
+    AlreadyClaimed = 3,
+    /// Claiming is paused.
+    ClaimPaused = 4,
+    /// Burning is paused.
+    BurnPaused = 5,
+    /// The dest account of a vested claim already has a vesting schedule.
+    VestedBalanceExists = 6,
+    /// The key is not a bridge key of the current session.
+    NotBridgeAuthority = 7,
+    /// The burn is not pending or already certified.
+    BurnNotCertifiable = 8,
+    /// The authority already signed the burn.
+    BurnAlreadySigned = 9,
+    /// The signature does not match the burn certificate message.
+    InvalidBurnSignature = 10,
+    /// The address or account is frozen.
+    Frozen = 11,
+    /// Bridging to the chain is disabled.
+    ChainDisabled = 12,
+    /// The amount does not cover the burn fee.
+    InvalidAmount = 13,
+  }
+
+  impl From<ValidityError> for u8 {
+    fn from(err: ValidityError) -> Self {
+      err as u8
+    }
+  }
+
+  #[pallet::validate_unsigned]
+  impl<T: Config> ValidateUnsigned for Pallet<T> {
+    type Call = Call<T>;
+
+    fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+      const PRIORITY: u64 = 100;
+
+      if let Call::burn_from_evm(source, chain_id, currency_id, dest, amount, sig) = call {
+        // the checks of the dispatch, so burns bound to fail do not enter the pool
+        let who = T::AddressMapping::into_account_id(*source);
+        Self::check_burn(&who, *chain_id, *currency_id, dest, *amount).map_err(|e| {
+          let e = match e {
+            Error::<T>::ChainDisabled => ValidityError::ChainDisabled,
+            Error::<T>::AccountFrozen | Error::<T>::AddressFrozen => ValidityError::Frozen,
+            Error::<T>::InvalidAmount => ValidityError::InvalidAmount,
+            _ => ValidityError::BurnPaused,
+          };
+          InvalidTransaction::Custom(e.into())
+        })?;
+
+        let nonce = Self::evm_burn_nonce(source);
+        let msg = Self::evm_burn_message(*chain_id, *currency_id, dest, *amount, nonce);
+        let e = InvalidTransaction::Custom(ValidityError::SignatureNotMatch.into());
+        ensure!(
+          Self::eth_recover(sig, &msg, *chain_id) == Some(EthereumAddress(source.0)),
+          e
+        );
+
+        ensure!(
+          Self::free_balance(*currency_id, &who) >= *amount,
+          InvalidTransaction::Payment
+        );
+
+        return Ok(ValidTransaction {
+          priority: PRIORITY,
+          requires: vec![],
+          provides: vec![("claims_evm_burn", source, nonce).encode()],
+          longevity: EVM_BURN_LONGEVITY,
+          propagate: true,
+        });
+      }
+
+      if let Call::submit_burn_signature(chain_id, nonce, authority, signature) = call {
+        Self::check_burn_signature(*chain_id, *nonce, authority, signature).map_err(|e| {
+          let e = match e {
+            Error::<T>::NotBridgeAuthority => ValidityError::NotBridgeAuthority,
+            Error::<T>::BurnAlreadySigned => ValidityError::BurnAlreadySigned,
+            Error::<T>::InvalidBurnSignature => ValidityError::InvalidBurnSignature,
+            _ => ValidityError::BurnNotCertifiable,
+          };
+          InvalidTransaction::Custom(e.into())
+        })?;
+
+        return Ok(ValidTransaction {
+          priority: T::CertificateUnsignedPriority::get(),
+          requires: vec![],
+          provides: vec![("claims_burn_signature", chain_id, nonce, authority).encode()],
+          longevity: TransactionLongevity::max_value(),
+          propagate: true,
+        });
+      }
+
+      let e = InvalidTransaction::Custom(ValidityError::ClaimPaused.into());
+      ensure!(!Self::paused(BridgeOperation::Claim), e);
+
+      let (dest, chain_id, tx, signer) = match call {
+        Call::claim(account, chain_id, tx, sig) => {
+          let msg = Self::claim_message(account, tx);
+          (
+            account.clone(),
+            *chain_id,
+            *tx,
+            Self::eth_recover(&sig, &msg, *chain_id),
+          )
+        }
+        Call::claim_typed(account, chain_id, tx, sig) => {
+          let hash = Self::claim_typed_data_hash(account, *chain_id, tx);
+          (
+            account.clone(),
+            *chain_id,
+            *tx,
+            recover(sig, &hash, *chain_id).map(EthereumAddress),
+          )
+        }
+        Call::claim_to_evm(dest, chain_id, tx, sig) => {
+          let msg = Self::evm_claim_message(dest, tx);
+          (
+            T::AddressMapping::into_account_id(*dest),
+            *chain_id,
+            *tx,
+            Self::eth_recover(&sig, &msg, *chain_id),
+          )
+        }
+        _ => return InvalidTransaction::Call.into(),
+      };
+
+      let signer = signer.ok_or(InvalidTransaction::Custom(
+        ValidityError::InvalidEthereumSignature.into(),
+      ))?;
+
+      let e = InvalidTransaction::Custom(ValidityError::SignerHasNoClaim.into());
+      let record = Self::claims(chain_id, &tx).ok_or(e)?;
+
+      let e = InvalidTransaction::Custom(ValidityError::SignatureNotMatch.into());
+      ensure!(record.who == signer, e);
+
+      let e = InvalidTransaction::Custom(ValidityError::AlreadyClaimed.into());
+      ensure!(!record.claimed, e);
+
+      let e = InvalidTransaction::Custom(ValidityError::Frozen.into());
+      ensure!(
+        !Self::frozen_address(&signer) && !Self::frozen_account(&dest),
+        e
+      );
+
+      let e = InvalidTransaction::Custom(ValidityError::VestedBalanceExists.into());
+      ensure!(
+        !Vesting::<T>::contains_key(chain_id, &tx)
+          || T::VestingSchedule::vesting_balance(&dest).is_none(),
+        e
+      );
+
+      Ok(ValidTransaction {
+        priority: PRIORITY,
+        requires: vec![],
+        provides: vec![("claims", chain_id, tx).encode()],
+        longevity: TransactionLongevity::max_value(),
+        propagate: true,
+      })
+    }
+  }
+
+  impl<T: Config> Pallet<T> {
+    /// the account to store the mint/claim fees
+    pub fn account_id() -> T::AccountId {
+      T::ModuleId::get().into_account()
+    }
+
+    /// The message signed with `personal_sign` for `claim`, the prefixed hex of `dest` and `tx`.
+    pub fn claim_message(dest: &T::AccountId, tx: &EthereumTxHash) -> Vec<u8> {
+      Self::ethereum_signable_message(
+        &dest.using_encoded(to_ascii_hex),
+        &tx.using_encoded(to_ascii_hex),
+      )
+    }
+
+    /// The message signed with `personal_sign` for `claim_to_evm`, the prefixed hex of
+    /// `evm:` with `dest` and `tx`.
+    pub fn evm_claim_message(dest: &H160, tx: &EthereumTxHash) -> Vec<u8> {
+      Self::ethereum_signable_message(
+        &[&b"evm:"[..], &dest.using_encoded(to_ascii_hex)].concat(),
+        &tx.using_encoded(to_ascii_hex),
+      )
+    }
+
+    /// The message signed with `personal_sign` for the `nonce`th `burn_from_evm` of an EVM
+    /// address, the prefixed hex of `burn:` with the genesis hash and the burn, so it can not
+    /// be replayed on another Clover network.
+    pub fn evm_burn_message(
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      dest: &EthereumAddress,
+      amount: BalanceOf<T>,
+      nonce: u64,
+    ) -> Vec<u8> {
+      let genesis = frame_system::Module::<T>::block_hash(T::BlockNumber::zero());
+      Self::ethereum_signable_message(
+        b"burn:",
+        &(genesis, chain_id, currency_id, dest, amount, nonce).using_encoded(to_ascii_hex),
+      )
+    }
+
+    /// The EIP-712 hash signed for `claim_typed`, the typed data is
+    /// `Claim(bytes dest,bytes32 tx)` in the `Clover Claims` domain of the source chain.
+    pub fn claim_typed_data_hash(
+      dest: &T::AccountId,
+      chain_id: ChainId,
+      tx: &EthereumTxHash,
+    ) -> [u8; 32] {
+      let domain = eip712::Domain {
+        name: CLAIM_DOMAIN_NAME,
+        version: CLAIM_DOMAIN_VERSION,
+        chain_id,
+        verifying_contract: None,
+      };
+      let struct_hash = eip712::hash_struct(
+        b"Claim(bytes dest,bytes32 tx)",
+        &[eip712::encode_bytes(&dest.encode()), tx.0],
+      );
+      eip712::typed_data_hash(&domain, &struct_hash)
+    }
+
+    fn do_claim(
+      dest: T::AccountId,
+      chain_id: ChainId,
+      tx: EthereumTxHash,
+      signer: Option<EthereumAddress>,
+    ) -> DispatchResultWithPostInfo {
+      ensure!(
+        !Self::paused(BridgeOperation::Claim),
+        Error::<T>::ClaimPaused
+      );
+      let mut record = Self::claims(chain_id, &tx).ok_or(Error::<T>::TxNotMinted)?;
+      ensure!(!record.claimed, Error::<T>::AlreadyClaimed);
+
+      let signer = signer.ok_or(Error::<T>::InvalidEthereumSignature)?;
+      ensure!(record.who == signer, Error::<T>::SignatureNotMatch);
+      ensure!(!Self::frozen_address(&signer), Error::<T>::AddressFrozen);
+      ensure!(!Self::frozen_account(&dest), Error::<T>::AccountFrozen);
+
+      let currency_id = Self::claim_currency(chain_id, &tx);
+      let amount = record.amount;
+      let vesting = Self::vesting(chain_id, &tx);
+      // only one vesting schedule can lock an account
+      ensure!(
+        vesting.is_none() || T::VestingSchedule::vesting_balance(&dest).is_none(),
+        Error::<T>::VestedBalanceExists
+      );
+      Self::deposit(currency_id, &dest, amount)?;
+      if let Some(vesting) = vesting {
+        T::VestingSchedule::add_vesting_schedule(&dest, amount, vesting.per_block, vesting.start)?;
+      }
+      // the mint fee is rewarded once claimed, voided and revoked mints earn nothing
+      if let Some(relayer) = &record.relayer {
+        Self::reward_relayer(relayer, chain_id, currency_id, record.fee);
+      }
+      BridgedSupplies::<T>::mutate(chain_id, currency_id, |supply| {
+        supply.claimed = supply.claimed.saturating_add(amount)
+      });
+      record.claimed = true;
+      record.claimed_by = Some(dest.clone());
+      record.claim_block = Some(frame_system::Module::<T>::block_number());
+      Claims::<T>::insert(chain_id, tx, record);
+      VoidVotes::<T>::remove(chain_id, tx);
+
+      Self::deposit_event(Event::Claimed(
+        dest,
+        chain_id,
+        currency_id,
+        tx,
+        signer,
+        amount,
+      ));
+
+      Ok(().into())
+    }
+
+    /// Record the attestation of a relayer of the chain, and mint the claim once the
+    /// threshold is reached
+    #[allow(clippy::too_many_arguments)]
+    fn attest(
+      signer: T::AccountId,
+      relayers: &[T::AccountId],
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      tx: EthereumTxHash,
+      who: EthereumAddress,
+      value: BalanceOf<T>,
+      vesting: Option<VestingOf<T>>,
+    ) -> DispatchResult {
+      ensure!(!Self::frozen_address(&who), Error::<T>::AddressFrozen);
+      // Check if this tx already be mint or be claimed, delayed mints count as minted
+      ensure!(
+        !Claims::<T>::contains_key(chain_id, &tx)
+          && !PendingMints::<T>::contains_key(chain_id, &tx),
+        Error::<T>::AlreadyMinted
+      );
+      ensure!(
+        !RevokedClaims::<T>::contains_key(chain_id, &tx),
+        Error::<T>::AlreadyRevoked
+      );
+
+      let mut votes = Self::attestations(chain_id, &tx);
+      // attestations of removed relayers neither count nor conflict
+      votes.retain(|(r, _, _, _, _)| relayers.contains(r));
+      ensure!(
+        !votes.iter().any(|(r, _, _, _, _)| r == &signer),
+        Error::<T>::AlreadyAttested
+      );
+      // the relayers attesting different data are resolved by governance, e.g. with
+      // `slash_relayer` and `remove_relayer`
+      ensure!(
+        votes.iter().all(|(_, c, w, v, s)| {
+          c == &currency_id && w == &who && v == &value && s == &vesting
+        }),
+        Error::<T>::ConflictingAttestation
+      );
+      votes.push((
+        signer.clone(),
+        currency_id,
+        who.clone(),
+        value.clone(),
+        vesting.clone(),
+      ));
+
+      let count = votes.len() as u32;
+      Self::deposit_event(Event::MintAttested(
+        chain_id,
+        tx.clone(),
+        signer.clone(),
+        count,
+      ));
+      if count < Self::relayer_threshold(chain_id).max(1) {
+        Attestations::<T>::insert(chain_id, &tx, votes);
+        return Ok(());
+      }
+
+      let attesters = votes.into_iter().map(|(r, _, _, _, _)| r).collect();
+      Self::do_mint(
+        chain_id,
+        currency_id,
+        tx,
+        who,
+        value,
+        vesting,
+        signer,
+        attesters,
+      )
+    }
+
+    /// Mark the pending burn as released on the dest chain by `tx`, and reward the relayer
+    /// confirming it
+    fn do_confirm_burn(
+      relayer: &T::AccountId,
+      chain_id: ChainId,
+      nonce: BurnNonce,
+      tx: EthereumTxHash,
+    ) -> DispatchResult {
+      let (currency_id, fee) = Burns::<T>::try_mutate(
+        chain_id,
+        nonce,
+        |burn| -> Result<(CurrencyId, BalanceOf<T>), DispatchError> {
+          let burn = burn.as_mut().ok_or(Error::<T>::BurnNotFound)?;
+          ensure!(
+            burn.status == BurnStatus::Pending,
+            Error::<T>::BurnNotPending
+          );
+          burn.status = BurnStatus::Relayed(tx);
+          Ok((burn.currency_id, burn.fee))
+        },
+      )?;
+      UncertifiedBurns::<T>::remove(chain_id, nonce);
+      BurnSignatures::<T>::remove(chain_id, nonce);
+
+      Self::deposit_event(Event::BurnRelayed(chain_id, nonce, tx));
+      Self::reward_relayer(relayer, chain_id, currency_id, fee);
+      Ok(())
+    }
+
+    /// Mint the claim of the tx once it is attested or proven, mints above the
+    /// `LargeMintThreshold` are delayed
+    #[allow(clippy::too_many_arguments)]
+    fn do_mint(
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      tx: EthereumTxHash,
+      who: EthereumAddress,
+      value: BalanceOf<T>,
+      vesting: Option<VestingOf<T>>,
+      relayer: T::AccountId,
+      relayers: Vec<T::AccountId>,
+    ) -> DispatchResult {
+      // Check claim limit
+      ensure!(
+        Self::claim_limit(chain_id, currency_id) >= value,
+        Error::<T>::ClaimLimitExceeded
+      );
+      if let Some(max) = Self::max_claim_amount(chain_id, currency_id) {
+        ensure!(value <= max, Error::<T>::ClaimAmountTooLarge);
+      }
+      let address_minted =
+        Self::address_minted_today(chain_id, currency_id, &who).saturating_add(value);
+      if let Some(cap) = Self::address_daily_cap(chain_id, currency_id) {
+        ensure!(address_minted <= cap, Error::<T>::AddressCapExceeded);
+      }
       let mut claim_amount = value.clone();
       let mut mint_fee = 0u32.into();
-      if let Some(fee) = Self::mint_fee() {
+      if let Some(schedule) = Self::mint_fee(chain_id, currency_id) {
+        let fee = schedule.fee(value);
         ensure!(value > fee, Error::<T>::InvalidAmount);
         claim_amount = value.saturating_sub(fee);
         mint_fee = fee;
       }
-      // insert into claims
-      Claims::<T>::insert(tx.clone(), Some((who.clone(), claim_amount.clone(), false)));
-      // update claim limit
-      ClaimLimit::<T>::mutate(|l| *l = l.saturating_sub(claim_amount));
-      if mint_fee > 0u32.into() {
-        T::Currency::deposit_creating(&Self::account_id(), mint_fee);
+      Attestations::<T>::remove(chain_id, &tx);
+      // update claim limit, delayed mints keep it until vetoed
+      ClaimLimit::<T>::mutate(chain_id, currency_id, |l| {
+        *l = l.saturating_sub(claim_amount)
+      });
+      AddressMinted::<T>::insert(
+        (chain_id, currency_id),
+        &who,
+        (Self::current_day(), address_minted),
+      );
+
+      if let Some(threshold) = Self::large_mint_threshold(chain_id, currency_id) {
+        if value > threshold {
+          let finalize_at =
+            frame_system::Module::<T>::block_number().saturating_add(T::LargeMintDelay::get());
+          T::Scheduler::schedule_named(
+            (LARGE_MINT_ID, chain_id, tx).encode(),
+            DispatchTime::At(finalize_at),
+            None,
+            HARD_DEADLINE,
+            frame_system::RawOrigin::Root.into(),
+            Call::<T>::finalize_mint(chain_id, tx).into(),
+          )
+          .map_err(|_| Error::<T>::ScheduleFailed)?;
+          PendingMints::<T>::insert(
+            chain_id,
+            &tx,
+            PendingMint {
+              currency_id,
+              who,
+              amount: claim_amount,
+              fee: mint_fee,
+              vesting,
+              relayer,
+              relayers,
+              finalize_at,
+            },
+          );
+
+          Self::deposit_event(Event::MintDelayed(
+            chain_id,
+            currency_id,
+            tx,
+            who,
+            claim_amount,
+            finalize_at,
+          ));
+          return Ok(());
+        }
       }
 
-      Self::deposit_event(Event::MintSuccess(tx, who, claim_amount));
-      Ok(().into())
+      Self::record_mint(
+        chain_id,
+        tx,
+        currency_id,
+        who,
+        claim_amount,
+        mint_fee,
+        vesting,
+        relayer,
+        relayers,
+      )
     }
 
-    #[pallet::weight(0)]
-    #[frame_support::transactional]
-    pub fn claim(
-      origin: OriginFor<T>,
-      dest: T::AccountId,
+    /// Record the claim of a mint whose value is taken from the limits, and collect its fee
+    #[allow(clippy::too_many_arguments)]
+    fn record_mint(
+      chain_id: ChainId,
       tx: EthereumTxHash,
-      sig: EcdsaSignature,
-    ) -> DispatchResultWithPostInfo {
-      ensure_none(origin)?;
-      let tx_info = Self::claims(&tx);
-      ensure!(tx_info.is_some(), Error::<T>::TxNotMinted);
-      let (address, amount, claimed) = tx_info.unwrap();
+      currency_id: CurrencyId,
+      who: EthereumAddress,
+      amount: BalanceOf<T>,
+      fee: BalanceOf<T>,
+      vesting: Option<VestingOf<T>>,
+      relayer: T::AccountId,
+      relayers: Vec<T::AccountId>,
+    ) -> DispatchResult {
+      let now = frame_system::Module::<T>::block_number();
+      // insert into claims
+      Claims::<T>::insert(
+        chain_id,
+        tx.clone(),
+        ClaimRecord {
+          who: who.clone(),
+          amount: amount.clone(),
+          mint_block: now,
+          relayer: Some(relayer),
+          fee,
+          claimed: false,
+          claimed_by: None,
+          claim_block: None,
+        },
+      );
+      Mints::<T>::insert(
+        chain_id,
+        &tx,
+        MintInfo {
+          currency_id,
+          block: now,
+          expiry: now.saturating_add(T::ClaimExpiry::get()),
+          relayers,
+          fee,
+        },
+      );
+      if let Some(vesting) = vesting {
+        Vesting::<T>::insert(chain_id, &tx, vesting);
+      }
+      BridgedSupplies::<T>::mutate(chain_id, currency_id, |supply| {
+        supply.minted = supply.minted.saturating_add(amount)
+      });
+      Self::collect_fee(chain_id, currency_id, fee)?;
 
-      ensure!(!claimed, Error::<T>::AlreadyClaimed);
+      Self::deposit_event(Event::MintSuccess(chain_id, currency_id, tx, who, amount));
+      Ok(())
+    }
 
-      let data = dest.using_encoded(to_ascii_hex);
-      let tx_data = tx.using_encoded(to_ascii_hex);
+    /// The depositor and amount of a `DEPOSIT_EVENT` log
+    fn decode_deposit(log: &EvmLog) -> Result<(EthereumAddress, BalanceOf<T>), Error<T>> {
+      ensure!(
+        log.topics.first().map(|topic| topic.0) == Some(keccak_256(DEPOSIT_EVENT)),
+        Error::<T>::DepositNotFound
+      );
+      ensure!(
+        log.topics.len() == 2 && log.data.len() == 32,
+        Error::<T>::InvalidDeposit
+      );
+      // abi encoded as 32 bytes words
+      let topic = &log.topics[1].0;
+      ensure!(
+        topic[..12].iter().all(|b| *b == 0) && log.data[..16].iter().all(|b| *b == 0),
+        Error::<T>::InvalidDeposit
+      );
+      let mut who = [0u8; 20];
+      who.copy_from_slice(&topic[12..]);
+      let mut amount = [0u8; 16];
+      amount.copy_from_slice(&log.data[16..]);
+      let value = BalanceOf::<T>::try_from(u128::from_be_bytes(amount))
+        .map_err(|_| Error::<T>::InvalidDeposit)?;
+      Ok((EthereumAddress(who), value))
+    }
 
-      let signer =
-        Self::eth_recover(&sig, &data, &tx_data).ok_or(Error::<T>::InvalidEthereumSignature)?;
+    /// The currency minted by the tx, mints recorded before `MintInfo` are all native
+    fn claim_currency(chain_id: ChainId, tx: &EthereumTxHash) -> CurrencyId {
+      Self::mints(chain_id, tx).map_or_else(T::NativeCurrencyId::get, |mint| mint.currency_id)
+    }
 
-      ensure!(address == signer, Error::<T>::SignatureNotMatch);
+    /// Deposit the currency into `who`
+    fn deposit(
+      currency_id: CurrencyId,
+      who: &T::AccountId,
+      amount: BalanceOf<T>,
+    ) -> DispatchResult {
+      if currency_id == T::NativeCurrencyId::get() {
+        T::Currency::deposit_creating(who, amount);
+        Ok(())
+      } else {
+        T::MultiCurrency::deposit(currency_id, who, amount)
+      }
+    }
 
-      T::Currency::deposit_creating(&dest, amount);
-      Claims::<T>::insert(tx, Some((address, amount, true)));
+    /// Withdraw the currency from `who`, the native account is kept alive
+    fn do_burn(
+      who: T::AccountId,
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      dest: EthereumAddress,
+      amount: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+      let burn_fee = Self::check_burn(&who, chain_id, currency_id, &dest, amount)?;
+      let burn_amount = amount.saturating_sub(burn_fee);
 
-      Self::deposit_event(Event::Claimed(dest, signer, amount));
+      Self::withdraw(currency_id, &who, amount)?;
+      Self::collect_fee(chain_id, currency_id, burn_fee)?;
 
+      Self::queue_burn(who, chain_id, currency_id, dest, burn_amount, burn_fee);
       Ok(().into())
     }
 
-    #[pallet::weight(T::DbWeight::get().reads_writes(2, 3))]
-    #[frame_support::transactional]
-    pub fn burn(
-      origin: OriginFor<T>,
-      dest: EthereumAddress,
+    /// Checks of a burn made before the funds are withdrawn, shared with `validate_unsigned`.
+    /// Returns the burn fee.
+    fn check_burn(
+      who: &T::AccountId,
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      dest: &EthereumAddress,
       amount: BalanceOf<T>,
-    ) -> DispatchResultWithPostInfo {
-      let who = ensure_signed(origin)?;
-      let mut burn_amount = amount.clone();
-      let mut burn_fee = 0u32.into();
-      if let Some(fee) = Self::burn_fee() {
+    ) -> Result<BalanceOf<T>, Error<T>> {
+      ensure!(!Self::paused(BridgeOperation::Burn), Error::<T>::BurnPaused);
+      ensure!(Self::chain_enabled(chain_id), Error::<T>::ChainDisabled);
+      ensure!(!Self::frozen_account(who), Error::<T>::AccountFrozen);
+      ensure!(!Self::frozen_address(dest), Error::<T>::AddressFrozen);
+      let mut fee = Zero::zero();
+      if let Some(schedule) = Self::burn_fee(chain_id, currency_id) {
+        fee = schedule.fee(amount);
         ensure!(amount > fee, Error::<T>::InvalidAmount);
-        burn_amount = amount.saturating_sub(fee);
-        burn_fee = fee;
       }
+      Ok(fee)
+    }
 
-      T::Currency::withdraw(
-        &who,
-        amount,
-        WithdrawReasons::TRANSFER,
-        ExistenceRequirement::KeepAlive,
-      )?;
-      if burn_fee > 0u32.into() {
-        T::Currency::deposit_creating(&Self::account_id(), burn_fee);
+    fn free_balance(currency_id: CurrencyId, who: &T::AccountId) -> BalanceOf<T> {
+      if currency_id == T::NativeCurrencyId::get() {
+        T::Currency::free_balance(who)
+      } else {
+        T::MultiCurrency::free_balance(currency_id, who)
       }
+    }
 
-      Self::deposit_event(Event::Burned(who, dest, burn_amount));
-      Ok(().into())
+    fn withdraw(
+      currency_id: CurrencyId,
+      who: &T::AccountId,
+      amount: BalanceOf<T>,
+    ) -> DispatchResult {
+      if currency_id == T::NativeCurrencyId::get() {
+        T::Currency::withdraw(
+          who,
+          amount,
+          WithdrawReasons::TRANSFER,
+          ExistenceRequirement::KeepAlive,
+        )?;
+        Ok(())
+      } else {
+        T::MultiCurrency::withdraw(currency_id, who, amount)
+      }
     }
-  }
 
-  #[repr(u8)]
-  pub enum ValidityError {
-    /// The Ethereum signature is invalid.
-    InvalidEthereumSignature = 0,
-    /// The signer has no claim.
-    SignerHasNoClaim = 1,
-    /// No permission to execute the call.
-    SignatureNotMatch = 2,
-    /// This tx already be claimed.
-    AlreadyClaimed = 3,
-  }
+    /// Hold the fee charged on the chain in the module account until swept
+    fn collect_fee(
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      fee: BalanceOf<T>,
+    ) -> DispatchResult {
+      if fee > Zero::zero() {
+        Self::deposit(currency_id, &Self::account_id(), fee)?;
+        FeesCollected::<T>::mutate(chain_id, currency_id, |f| *f = f.saturating_add(fee));
+      }
+      Ok(())
+    }
 
-  impl From<ValidityError> for u8 {
-    fn from(err: ValidityError) -> Self {
-      err as u8
+    /// Slash up to `fee` from the fees of the currency held by the module account, the
+    /// unclaimed relayer rewards are kept. Returns the amount slashed.
+    fn reverse_fee(currency_id: CurrencyId, fee: BalanceOf<T>) -> BalanceOf<T> {
+      let account = Self::account_id();
+      let rewards = Self::unclaimed_rewards(currency_id);
+      if currency_id == T::NativeCurrencyId::get() {
+        let fee = fee.min(T::Currency::free_balance(&account).saturating_sub(rewards));
+        let (_, remaining) = T::Currency::slash(&account, fee);
+        fee.saturating_sub(remaining)
+      } else {
+        let fee =
+          fee.min(T::MultiCurrency::free_balance(currency_id, &account).saturating_sub(rewards));
+        fee.saturating_sub(T::MultiCurrency::slash(currency_id, &account, fee))
+      }
     }
-  }
 
-  #[pallet::validate_unsigned]
-  impl<T: Config> ValidateUnsigned for Pallet<T> {
-    type Call = Call<T>;
+    /// Whether the account is a relayer of any chain
+    fn is_relayer(who: &T::AccountId) -> bool {
+      Relayers::<T>::iter().any(|(_, relayers)| relayers.contains(who))
+    }
 
-    fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
-      const PRIORITY: u64 = 100;
+    /// Remove the relayer from the committees of all chains, lowering the thresholds
+    /// which can not be reached any more
+    fn remove_from_committees(who: &T::AccountId) {
+      for (chain_id, mut relayers) in Relayers::<T>::iter().collect::<Vec<_>>() {
+        let index = match relayers.iter().position(|r| r == who) {
+          Some(index) => index,
+          None => continue,
+        };
+        relayers.remove(index);
+        let len = relayers.len() as u32;
+        Relayers::<T>::insert(chain_id, relayers);
+        Self::deposit_event(Event::RelayerRemoved(chain_id, who.clone()));
 
-      if let Call::claim(account, tx, sig) = call {
-        let data = account.using_encoded(to_ascii_hex);
-        let tx_data = tx.using_encoded(to_ascii_hex);
-        let signer = Self::eth_recover(&sig, &data, &tx_data).ok_or(InvalidTransaction::Custom(
-          ValidityError::InvalidEthereumSignature.into(),
-        ))?;
+        if Self::relayer_threshold(chain_id) > len {
+          RelayerThreshold::<T>::insert(chain_id, len);
+          Self::deposit_event(Event::RelayerThresholdUpdated(chain_id, len));
+        }
+      }
+    }
 
-        let e = InvalidTransaction::Custom(ValidityError::SignerHasNoClaim.into());
-        let tx_info = Self::claims(&tx);
-        ensure!(tx_info.is_some(), e);
+    /// Credit the relayer its `RelayerRewardShare` of the fee, held in the module account
+    fn reward_relayer(
+      relayer: &T::AccountId,
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      fee: BalanceOf<T>,
+    ) {
+      let reward = Self::relayer_reward_share(chain_id) * fee;
+      if reward.is_zero() {
+        return;
+      }
+      RelayerRewards::<T>::mutate(relayer, currency_id, |ledger| {
+        ledger.earned = ledger.earned.saturating_add(reward)
+      });
+      UnclaimedRewards::<T>::mutate(currency_id, |r| *r = r.saturating_add(reward));
 
-        let (address, _, claimed) = tx_info.unwrap();
-        let e = InvalidTransaction::Custom(ValidityError::SignatureNotMatch.into());
-        ensure!(address == signer, e);
+      Self::deposit_event(Event::RelayerRewarded(
+        relayer.clone(),
+        chain_id,
+        currency_id,
+        reward,
+      ));
+    }
 
-        let e = InvalidTransaction::Custom(ValidityError::AlreadyClaimed.into());
-        ensure!(!claimed, e);
+    /// Move the `LowestPendingBurn` cursor of the chain past the burns which are no longer
+    /// pending, by at most `max_steps` burns. A cursor left behind, e.g. by the burns made
+    /// before it was introduced, catches up with the following releases and refunds.
+    fn advance_lowest_pending_burn(chain_id: ChainId, max_steps: u64) {
+      let start = Self::lowest_pending_burn(chain_id);
+      let end = Self::next_burn_nonce(chain_id).min(start.saturating_add(max_steps));
+      let lowest = (start..end)
+        .find(|nonce| {
+          Self::burns(chain_id, *nonce).map_or(false, |burn| burn.status == BurnStatus::Pending)
+        })
+        .unwrap_or(end);
+      if lowest != start {
+        LowestPendingBurn::<T>::insert(chain_id, lowest);
+      }
+    }
 
-        Ok(ValidTransaction {
-          priority: PRIORITY,
-          requires: vec![],
-          provides: vec![("claims", signer).encode()],
-          longevity: TransactionLongevity::max_value(),
-          propagate: true,
+    /// Remove an unclaimed mint and give its amount back to the limits
+    fn remove_unclaimed(
+      chain_id: ChainId,
+      tx: &EthereumTxHash,
+    ) -> Result<(CurrencyId, EthereumAddress, BalanceOf<T>), DispatchError> {
+      let ClaimRecord {
+        who,
+        amount,
+        fee,
+        claimed,
+        ..
+      } = Self::claims(chain_id, tx).ok_or(Error::<T>::TxNotMinted)?;
+      ensure!(!claimed, Error::<T>::AlreadyClaimed);
+
+      let currency_id = Self::claim_currency(chain_id, tx);
+      Claims::<T>::remove(chain_id, tx);
+      Mints::<T>::remove(chain_id, tx);
+      Vesting::<T>::remove(chain_id, tx);
+      VoidVotes::<T>::remove(chain_id, tx);
+      BridgedSupplies::<T>::mutate(chain_id, currency_id, |supply| {
+        supply.minted = supply.minted.saturating_sub(amount)
+      });
+      Self::release_limits(chain_id, currency_id, &who, amount, fee);
+      Ok((currency_id, who, amount))
+    }
+
+    /// Give the amount of a removed mint back to the claim limit and the daily cap
+    fn release_limits(
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      who: &EthereumAddress,
+      amount: BalanceOf<T>,
+      fee: BalanceOf<T>,
+    ) {
+      ClaimLimit::<T>::mutate(chain_id, currency_id, |l| *l = l.saturating_add(amount));
+      AddressMinted::<T>::mutate((chain_id, currency_id), who, |(day, minted)| {
+        if *day == Self::current_day() {
+          *minted = minted.saturating_sub(amount.saturating_add(fee));
+        }
+      });
+    }
+
+    /// Queue a burn to be released on the dest chain
+    fn queue_burn(
+      who: T::AccountId,
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      dest: EthereumAddress,
+      amount: BalanceOf<T>,
+      fee: BalanceOf<T>,
+    ) -> BurnNonce {
+      let nonce = Self::next_burn_nonce(chain_id);
+      NextBurnNonce::<T>::insert(chain_id, nonce.saturating_add(1));
+      BridgedSupplies::<T>::mutate(chain_id, currency_id, |supply| {
+        supply.burned = supply.burned.saturating_add(amount)
+      });
+      Burns::<T>::insert(
+        chain_id,
+        nonce,
+        BurnInfo {
+          who: who.clone(),
+          currency_id,
+          dest,
+          amount,
+          fee,
+          block: frame_system::Module::<T>::block_number(),
+          status: BurnStatus::Pending,
+        },
+      );
+      UncertifiedBurns::<T>::insert(chain_id, nonce, ());
+
+      Self::deposit_event(Event::Burned(
+        who,
+        chain_id,
+        currency_id,
+        dest,
+        amount,
+        nonce,
+      ));
+      nonce
+    }
+
+    /// Status of the claim of the tx from the chain
+    pub fn claim_status(
+      chain_id: ChainId,
+      tx: &EthereumTxHash,
+    ) -> ClaimStatus<BalanceOf<T>, T::BlockNumber> {
+      if RevokedClaims::<T>::contains_key(chain_id, tx) {
+        return ClaimStatus::Revoked;
+      }
+      if let Some(mint) = Self::pending_mints(chain_id, tx) {
+        let details = ClaimDetails {
+          currency_id: mint.currency_id,
+          who: mint.who,
+          amount: mint.amount,
+          expiry: None,
+          vesting: mint.vesting,
+        };
+        return ClaimStatus::Delayed(details, mint.finalize_at);
+      }
+      match Self::claims(chain_id, tx) {
+        Some(record) => {
+          let details = ClaimDetails {
+            currency_id: Self::claim_currency(chain_id, tx),
+            who: record.who,
+            amount: record.amount,
+            expiry: Self::mints(chain_id, tx).map(|mint| mint.expiry),
+            vesting: Self::vesting(chain_id, tx),
+          };
+          if record.claimed {
+            ClaimStatus::Claimed(details)
+          } else {
+            ClaimStatus::Minted(details)
+          }
+        }
+        None => match Self::attestations(chain_id, tx).len() as u32 {
+          0 => ClaimStatus::Unknown,
+          votes => ClaimStatus::Attesting(votes),
+        },
+      }
+    }
+
+    /// Minted claims from the chain to the ethereum address which are not claimed yet
+    pub fn pending_claims(
+      chain_id: ChainId,
+      who: &EthereumAddress,
+    ) -> Vec<(EthereumTxHash, ClaimDetails<BalanceOf<T>, T::BlockNumber>)> {
+      Claims::<T>::iter_prefix(chain_id)
+        .filter(|(_, record)| !record.claimed && record.who == *who)
+        .map(|(tx, record)| {
+          (
+            tx,
+            ClaimDetails {
+              currency_id: Self::claim_currency(chain_id, &tx),
+              who: record.who,
+              amount: record.amount,
+              expiry: Self::mints(chain_id, &tx).map(|mint| mint.expiry),
+              vesting: Self::vesting(chain_id, &tx),
+            },
+          )
         })
+        .collect()
+    }
+
+    /// Reward ledgers of the relayer by currency
+    pub fn relayer_ledger(who: &T::AccountId) -> Vec<(CurrencyId, RewardLedger<BalanceOf<T>>)> {
+      RelayerRewards::<T>::iter_prefix(who).collect()
+    }
+
+    /// Current limits of minting the currency from the chain
+    pub fn claim_limits(
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+    ) -> ClaimLimits<BalanceOf<T>, T::BlockNumber> {
+      ClaimLimits {
+        claim_limit: Self::claim_limit(chain_id, currency_id),
+        claim_window: Self::claim_window(chain_id, currency_id),
+        max_claim_amount: Self::max_claim_amount(chain_id, currency_id),
+        address_daily_cap: Self::address_daily_cap(chain_id, currency_id),
+      }
+    }
+
+    /// Bridged supply of the currency from the chain and its latest lock report
+    pub fn supply_audit(
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+    ) -> SupplyAudit<T::AccountId, BalanceOf<T>, T::BlockNumber> {
+      let supply = Self::bridged_supply(chain_id, currency_id);
+      SupplyAudit {
+        minted: supply.minted,
+        claimed: supply.claimed,
+        burned: supply.burned,
+        fees: Self::fees_collected(chain_id, currency_id),
+        lock_report: Self::lock_report(chain_id, currency_id),
+      }
+    }
+
+    /// The remaining value of the currency can be minted on the chain, to `who` if given
+    pub fn remaining_claim_limit(
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      who: Option<EthereumAddress>,
+    ) -> BalanceOf<T> {
+      let limit = Self::claim_limit(chain_id, currency_id);
+      match (who, Self::address_daily_cap(chain_id, currency_id)) {
+        (Some(who), Some(cap)) => {
+          limit.min(cap.saturating_sub(Self::address_minted_today(chain_id, currency_id, &who)))
+        }
+        _ => limit,
+      }
+    }
+
+    fn current_day() -> T::BlockNumber {
+      let blocks_per_day = T::BlocksPerDay::get().max(1u32.into());
+      frame_system::Module::<T>::block_number() / blocks_per_day
+    }
+
+    fn address_minted_today(
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      who: &EthereumAddress,
+    ) -> BalanceOf<T> {
+      let (day, minted) = AddressMinted::<T>::get((chain_id, currency_id), who);
+      if day == Self::current_day() {
+        minted
       } else {
-        InvalidTransaction::Call.into()
+        Zero::zero()
       }
     }
-  }
 
-  impl<T: Config> Pallet<T> {
-    /// the account to store the mint/claim fees
-    pub fn account_id() -> T::AccountId {
-      T::ModuleId::get().into_account()
+    /// Pending burns to the chain from the `start` nonce, at most `limit` items. The burns
+    /// below `LowestPendingBurn` are not scanned.
+    pub fn pending_burns(
+      chain_id: ChainId,
+      start: BurnNonce,
+      limit: u32,
+    ) -> Vec<(
+      BurnNonce,
+      BurnInfo<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+    )> {
+      (start.max(Self::lowest_pending_burn(chain_id))..Self::next_burn_nonce(chain_id))
+        .filter_map(|nonce| {
+          Self::burns(chain_id, nonce)
+            .filter(|burn| burn.status == BurnStatus::Pending)
+            .map(|burn| (nonce, burn))
+        })
+        .take(limit as usize)
+        .collect()
+    }
+
+    /// The contract of the currency on the chain, the deposit contract which releases burns
+    pub fn token_contract(chain_id: ChainId, currency_id: CurrencyId) -> Option<H160> {
+      DepositContracts::<T>::iter_prefix(chain_id)
+        .find(|(_, currency)| *currency == currency_id)
+        .map(|(contract, _)| contract)
+    }
+
+    /// The message signed by the bridge authorities for a burn, the keccak-256 hash of
+    /// `abi.encode(address token, address dest, uint256 amount, uint256 nonce,
+    /// uint256 chain_id)` which the token contract recovers the signers from with `ecrecover`.
+    /// `None` if the currency has no contract on the chain.
+    pub fn burn_certificate_message(
+      chain_id: ChainId,
+      nonce: BurnNonce,
+      burn: &BurnInfo<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+    ) -> Option<[u8; 32]> {
+      let token = Self::token_contract(chain_id, burn.currency_id)?;
+      let uint256 = |value: u128| {
+        let mut word = [0u8; 32];
+        word[16..].copy_from_slice(&value.to_be_bytes());
+        word
+      };
+      let address = |address: &[u8; 20]| {
+        let mut word = [0u8; 32];
+        word[12..].copy_from_slice(address);
+        word
+      };
+      let mut message = Vec::with_capacity(5 * 32);
+      message.extend_from_slice(&address(&token.0));
+      message.extend_from_slice(&address(&burn.dest.0));
+      message.extend_from_slice(&uint256(burn.amount.saturated_into()));
+      message.extend_from_slice(&uint256(nonce.into()));
+      message.extend_from_slice(&uint256(chain_id.into()));
+      Some(keccak_256(&message))
+    }
+
+    /// Number of signatures which certify a burn, more than two thirds of the authorities
+    pub fn certificate_threshold(authorities: usize) -> usize {
+      authorities.saturating_mul(2) / 3 + 1
+    }
+
+    /// The certified burn, with the ethereum addresses of its signers
+    pub fn burn_certificate(
+      chain_id: ChainId,
+      nonce: BurnNonce,
+    ) -> Option<CertifiedBurn<BalanceOf<T>>> {
+      let burn = Self::burns(chain_id, nonce)?;
+      let certificate = Self::burn_certificates(chain_id, nonce)?;
+      let token = Self::token_contract(chain_id, burn.currency_id)?;
+      let message = Self::burn_certificate_message(chain_id, nonce, &burn)?;
+      let signatures = certificate
+        .signatures
+        .into_iter()
+        .filter_map(|(_, signature)| {
+          let signature = <[u8; 65]>::decode(&mut &signature.encode()[..]).ok()?;
+          let signature = EthereumSignature::Standard(signature);
+          let signer = recover(&signature, &message, chain_id)?;
+          Some((EthereumAddress(signer), signature))
+        })
+        .collect();
+      Some(CertifiedBurn {
+        currency_id: burn.currency_id,
+        token,
+        dest: burn.dest,
+        amount: burn.amount,
+        message,
+        set_id: certificate.set_id,
+        signatures,
+      })
+    }
+
+    /// Check the authority of the current session can sign the burn with the signature
+    fn check_burn_signature(
+      chain_id: ChainId,
+      nonce: BurnNonce,
+      authority: &T::BridgeAuthorityId,
+      signature: &BridgeSignatureOf<T>,
+    ) -> Result<(), Error<T>> {
+      ensure!(
+        Self::bridge_authorities().contains(authority),
+        Error::<T>::NotBridgeAuthority
+      );
+      ensure!(
+        UncertifiedBurns::<T>::contains_key(chain_id, nonce),
+        Error::<T>::BurnNotCertifiable
+      );
+      let burn = Self::burns(chain_id, nonce).ok_or(Error::<T>::BurnNotCertifiable)?;
+      ensure!(
+        burn.status == BurnStatus::Pending,
+        Error::<T>::BurnNotCertifiable
+      );
+      ensure!(
+        !Self::burn_signatures(chain_id, nonce)
+          .iter()
+          .any(|(signer, _)| signer == authority),
+        Error::<T>::BurnAlreadySigned
+      );
+      let message = Self::burn_certificate_message(chain_id, nonce, &burn)
+        .ok_or(Error::<T>::BurnNotCertifiable)?;
+      // the contracts recover the signers from the message itself, not its blake2 hash
+      let signature = <[u8; 65]>::decode(&mut &signature.encode()[..])
+        .map_err(|_| Error::<T>::InvalidBurnSignature)?;
+      let signer = secp256k1_ecdsa_recover_compressed(&signature, &message)
+        .map_err(|_| Error::<T>::InvalidBurnSignature)?;
+      ensure!(
+        signer == authority.as_ref().0,
+        Error::<T>::InvalidBurnSignature
+      );
+      Ok(())
+    }
+
+    /// Sign the uncertified burns with the local keys of the current bridge authorities and
+    /// submit the signatures as unsigned transactions
+    fn sign_burns() {
+      let authorities = Self::bridge_authorities();
+      let keys = T::BridgeAuthorityId::all()
+        .into_iter()
+        .filter(|key| authorities.contains(key))
+        .collect::<Vec<_>>();
+      if keys.is_empty() {
+        return;
+      }
+
+      let mut signed = 0;
+      for (chain_id, nonce, ()) in UncertifiedBurns::<T>::iter() {
+        let burn = match Self::burns(chain_id, nonce) {
+          Some(burn) if burn.status == BurnStatus::Pending => burn,
+          _ => continue,
+        };
+        let message = match Self::burn_certificate_message(chain_id, nonce, &burn) {
+          Some(message) => message,
+          None => continue,
+        };
+        let signers = Self::burn_signatures(chain_id, nonce);
+        for key in &keys {
+          if signed >= MAX_OFFCHAIN_SIGNATURES {
+            return;
+          }
+          if signers.iter().any(|(signer, _)| signer == key) {
+            continue;
+          }
+          // signed as is, `RuntimeAppPublic::sign` would sign the blake2 hash of the message
+          let signature =
+            match ecdsa_sign_prehashed(T::BridgeAuthorityId::ID, key.as_ref(), &message)
+              .and_then(|signature| Decode::decode(&mut &signature.encode()[..]).ok())
+            {
+              Some(signature) => signature,
+              None => continue,
+            };
+          let call = Call::submit_burn_signature(chain_id, nonce, key.clone(), signature);
+          if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()).is_err() {
+            log::warn!(
+              target: "clover-claims",
+              "failed to submit the signature of burn {} to chain {}",
+              nonce,
+              chain_id
+            );
+          }
+          signed += 1;
+        }
+      }
+    }
+
+    /// The mint fee of bridging `amount` of the currency from the chain
+    pub fn mint_fee_of(
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      amount: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+      Self::mint_fee(chain_id, currency_id).map_or_else(Zero::zero, |schedule| schedule.fee(amount))
+    }
+
+    /// The burn fee of bridging `amount` of the currency to the chain
+    pub fn burn_fee_of(
+      chain_id: ChainId,
+      currency_id: CurrencyId,
+      amount: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+      Self::burn_fee(chain_id, currency_id).map_or_else(Zero::zero, |schedule| schedule.fee(amount))
+    }
+
+    /// Convert the flat fees of `Releases::V2_0_0` to the `FeeSchedule`s of `Releases::V3_0_0`
+    fn migrate_to_fee_schedules() -> Weight {
+      let pallet = match <T::PalletInfo as PalletInfo>::name::<Pallet<T>>() {
+        Some(name) => name.as_bytes(),
+        None => return 0,
+      };
+      let mut count: Weight = 0;
+      for item in [&b"MintFee"[..], &b"BurnFee"[..]].iter() {
+        let fees =
+          StorageKeyIterator::<ChainId, Option<BalanceOf<T>>, Twox64Concat>::new(pallet, item)
+            .drain()
+            .collect::<Vec<_>>();
+        count += fees.len() as Weight;
+        for (chain_id, fee) in fees {
+          if let Some(fee) = fee {
+            put_storage_value(
+              pallet,
+              item,
+              &chain_id.using_encoded(Twox64Concat::hash),
+              FeeSchedule::flat(fee),
+            );
+          }
+        }
+      }
+      T::DbWeight::get().reads_writes(count + 1, 2 * count + 1)
+    }
+
+    /// Convert the claims of `Releases::V4_0_0` to `ClaimRecord`s, with the mint details of
+    /// their `MintInfo`
+    fn migrate_to_claim_records() -> Weight {
+      let mut count: Weight = 0;
+      Claims::<T>::translate::<Option<(EthereumAddress, BalanceOf<T>, bool)>, _>(
+        |chain_id, tx, info| {
+          count += 1;
+          let (who, amount, claimed) = info?;
+          let mint = Self::mints(chain_id, &tx);
+          Some(ClaimRecord {
+            who,
+            amount,
+            mint_block: mint.as_ref().map_or_else(Zero::zero, |mint| mint.block),
+            relayer: mint.as_ref().and_then(|mint| mint.relayers.last().cloned()),
+            fee: mint.map_or_else(Zero::zero, |mint| mint.fee),
+            claimed,
+            claimed_by: None,
+            claim_block: None,
+          })
+        },
+      );
+      T::DbWeight::get().reads_writes(2 * count + 1, count + 1)
+    }
+
+    /// Attestations of `Releases::V5_0_0` attested mints without vesting
+    fn migrate_to_vested_attestations() -> Weight {
+      let mut count: Weight = 0;
+      Attestations::<T>::translate::<
+        Vec<(T::AccountId, CurrencyId, EthereumAddress, BalanceOf<T>)>,
+        _,
+      >(|_, _, votes| {
+        count += 1;
+        Some(
+          votes
+            .into_iter()
+            .map(|(r, c, w, v)| (r, c, w, v, None))
+            .collect(),
+        )
+      });
+      T::DbWeight::get().reads_writes(count + 1, count + 1)
+    }
+
+    /// Total the claims and burns recorded before `Releases::V7_0_0`
+    fn migrate_to_bridged_supplies() -> Weight {
+      let mut count: Weight = 0;
+      for (chain_id, tx, record) in Claims::<T>::iter() {
+        count += 2;
+        BridgedSupplies::<T>::mutate(chain_id, Self::claim_currency(chain_id, &tx), |supply| {
+          supply.minted = supply.minted.saturating_add(record.amount);
+          if record.claimed {
+            supply.claimed = supply.claimed.saturating_add(record.amount);
+          }
+        });
+      }
+      for (chain_id, _, burn) in Burns::<T>::iter() {
+        count += 1;
+        if burn.status != BurnStatus::Refunded {
+          BridgedSupplies::<T>::mutate(chain_id, burn.currency_id, |supply| {
+            supply.burned = supply.burned.saturating_add(burn.amount)
+          });
+        }
+      }
+      T::DbWeight::get().reads_writes(count, count)
+    }
+
+    /// Scope the state of `Releases::V3_0_0` by `T::NativeCurrencyId`
+    fn migrate_to_multi_currency() -> Weight {
+      let pallet = match <T::PalletInfo as PalletInfo>::name::<Pallet<T>>() {
+        Some(name) => name.as_bytes(),
+        None => return 0,
+      };
+      let currency_id = T::NativeCurrencyId::get();
+      let mut count: Weight = 0;
+
+      // collect first, the new entries live under the same prefixes
+      fn take_chain_map<V: Decode>(pallet: &[u8], item: &[u8]) -> Vec<(ChainId, V)> {
+        StorageKeyIterator::<ChainId, V, Twox64Concat>::new(pallet, item)
+          .drain()
+          .collect()
+      }
+      let limits = take_chain_map::<BalanceOf<T>>(pallet, b"ClaimLimit");
+      let windows =
+        take_chain_map::<ClaimWindow<BalanceOf<T>, T::BlockNumber>>(pallet, b"ClaimWindows");
+      let max_amounts = take_chain_map::<BalanceOf<T>>(pallet, b"MaxClaimAmount");
+      let caps = take_chain_map::<BalanceOf<T>>(pallet, b"AddressDailyCap");
+      let fees_collected = take_chain_map::<BalanceOf<T>>(pallet, b"FeesCollected");
+      let mint_fees = take_chain_map::<FeeSchedule<BalanceOf<T>>>(pallet, b"MintFee");
+      let burn_fees = take_chain_map::<FeeSchedule<BalanceOf<T>>>(pallet, b"BurnFee");
+      // keyed by `(ChainId, EthereumAddress)`, hashed with `Twox64Concat` and `Blake2_128Concat`
+      let address_minted =
+        StorageIterator::<(T::BlockNumber, BalanceOf<T>)>::new(pallet, b"AddressMinted")
+          .drain()
+          .filter_map(|(key, minted)| {
+            let chain_id = ChainId::decode(&mut key.get(8..16)?).ok()?;
+            let who = EthereumAddress::decode(&mut key.get(32..)?).ok()?;
+            Some((chain_id, who, minted))
+          })
+          .collect::<Vec<_>>();
+
+      count += (limits.len()
+        + windows.len()
+        + max_amounts.len()
+        + caps.len()
+        + fees_collected.len()
+        + mint_fees.len()
+        + burn_fees.len()
+        + address_minted.len()) as Weight;
+      for (chain_id, limit) in limits {
+        ClaimLimit::<T>::insert(chain_id, currency_id, limit);
+      }
+      for (chain_id, window) in windows {
+        ClaimWindows::<T>::insert(chain_id, currency_id, window);
+      }
+      for (chain_id, amount) in max_amounts {
+        MaxClaimAmount::<T>::insert(chain_id, currency_id, amount);
+      }
+      for (chain_id, cap) in caps {
+        AddressDailyCap::<T>::insert(chain_id, currency_id, cap);
+      }
+      for (chain_id, fees) in fees_collected {
+        FeesCollected::<T>::insert(chain_id, currency_id, fees);
+      }
+      for (chain_id, fee) in mint_fees {
+        MintFee::<T>::insert(chain_id, currency_id, fee);
+      }
+      for (chain_id, fee) in burn_fees {
+        BurnFee::<T>::insert(chain_id, currency_id, fee);
+      }
+      for (chain_id, who, minted) in address_minted {
+        AddressMinted::<T>::insert((chain_id, currency_id), who, minted);
+      }
+
+      let attestations =
+        StorageIterator::<Vec<(T::AccountId, EthereumAddress, BalanceOf<T>)>>::new(
+          pallet,
+          b"Attestations",
+        )
+        .drain()
+        .collect::<Vec<_>>();
+      count += attestations.len() as Weight;
+      // still `Releases::V5_0_0` values, converted by `migrate_to_vested_attestations`
+      for (key, votes) in attestations {
+        let votes = votes
+          .into_iter()
+          .map(|(r, w, v)| (r, currency_id, w, v))
+          .collect::<Vec<_>>();
+        put_storage_value(pallet, b"Attestations", &key, votes);
+      }
+      Mints::<T>::translate::<
+        (
+          T::BlockNumber,
+          T::BlockNumber,
+          Vec<T::AccountId>,
+          BalanceOf<T>,
+        ),
+        _,
+      >(|_, _, (block, expiry, relayers, fee)| {
+        count += 1;
+        Some(MintInfo {
+          currency_id,
+          block,
+          expiry,
+          relayers,
+          fee,
+        })
+      });
+      Burns::<T>::translate::<
+        (
+          T::AccountId,
+          EthereumAddress,
+          BalanceOf<T>,
+          BalanceOf<T>,
+          T::BlockNumber,
+          BurnStatus,
+        ),
+        _,
+      >(|_, _, (who, dest, amount, fee, block, status)| {
+        count += 1;
+        Some(BurnInfo {
+          who,
+          currency_id,
+          dest,
+          amount,
+          fee,
+          block,
+          status,
+        })
+      });
+
+      T::DbWeight::get().reads_writes(count + 1, 2 * count + 1)
+    }
+
+    /// Move the single chain state of `Releases::V1_0_0` to `T::LegacyChainId` and
+    /// `T::NativeCurrencyId`, the bridge account becomes the only relayer of the chain
+    fn migrate_to_multi_chain() -> Weight {
+      let pallet = match <T::PalletInfo as PalletInfo>::name::<Pallet<T>>() {
+        Some(name) => name.as_bytes(),
+        None => return 0,
+      };
+      let chain_id = T::LegacyChainId::get();
+      let mut reads: Weight = 4;
+      let mut writes: Weight = 1;
+
+      let currency_id = T::NativeCurrencyId::get();
+      if let Some(limit) = take_storage_value::<BalanceOf<T>>(pallet, b"ClaimLimit", &[]) {
+        ClaimLimit::<T>::insert(chain_id, currency_id, limit);
+        writes += 2;
+      }
+      if let Some(Some(fee)) = take_storage_value::<Option<BalanceOf<T>>>(pallet, b"MintFee", &[]) {
+        MintFee::<T>::insert(chain_id, currency_id, FeeSchedule::flat(fee));
+        writes += 2;
+      }
+      if let Some(Some(fee)) = take_storage_value::<Option<BalanceOf<T>>>(pallet, b"BurnFee", &[]) {
+        BurnFee::<T>::insert(chain_id, currency_id, FeeSchedule::flat(fee));
+        writes += 2;
+      }
+      if let Some(Some(relayer)) =
+        take_storage_value::<Option<T::AccountId>>(pallet, b"BridgeAccount", &[])
+      {
+        Relayers::<T>::insert(chain_id, vec![relayer]);
+        RelayerThreshold::<T>::insert(chain_id, 1);
+        writes += 3;
+      }
+      // the single chain was bridged without being enabled
+      ChainEnabled::<T>::insert(chain_id, true);
+
+      // collect first, the new entries live under the same prefix
+      let claims = StorageKeyIterator::<
+        EthereumTxHash,
+        Option<(EthereumAddress, BalanceOf<T>, bool)>,
+        Blake2_128Concat,
+      >::new(pallet, b"Claims")
+      .drain()
+      .collect::<Vec<_>>();
+      reads += claims.len() as Weight;
+      writes += 2 * claims.len() as Weight;
+      // still `Releases::V4_0_0` values, converted by `migrate_to_claim_records`
+      for (tx, info) in claims {
+        unhashed::put(&Claims::<T>::hashed_key_for(chain_id, tx), &info);
+      }
+
+      T::DbWeight::get().reads_writes(reads, writes)
     }
 
     // Constructs the message that Ethereum RPC's `personal_sign` and `eth_sign` would sign.
     fn ethereum_signable_message(what: &[u8], extra: &[u8]) -> Vec<u8> {
-      let prefix = T::Prefix::get();
-      let mut l = prefix.len() + what.len() + extra.len();
-      let mut rev = Vec::new();
-      while l > 0 {
-        rev.push(b'0' + (l % 10) as u8);
-        l /= 10;
-      }
-      let mut v = b"\x19Ethereum Signed Message:\n".to_vec();
-      v.extend(rev.into_iter().rev());
-      v.extend_from_slice(&prefix[..]);
-      v.extend_from_slice(what);
-      v.extend_from_slice(extra);
-      v
-    }
-
-    // Attempts to recover the Ethereum address from a message signature signed by using
-    // the Ethereum RPC's `personal_sign` and `eth_sign`.
-    fn eth_recover(s: &EcdsaSignature, what: &[u8], extra: &[u8]) -> Option<EthereumAddress> {
-      let msg = keccak_256(&Self::ethereum_signable_message(what, extra));
-      let mut res = EthereumAddress::default();
-      res
-        .0
-        .copy_from_slice(&keccak_256(&secp256k1_ecdsa_recover(&s.0, &msg).ok()?[..])[12..]);
-      Some(res)
+      personal_message(&[T::Prefix::get(), what, extra].concat())
+    }
+
+    // Attempts to recover the Ethereum address from a message signature signed for
+    // `chain_id` by using the Ethereum RPC's `personal_sign` and `eth_sign`.
+    fn eth_recover(s: &EcdsaSignature, msg: &[u8], chain_id: ChainId) -> Option<EthereumAddress> {
+      recover(
+        &EthereumSignature::Standard(s.0),
+        &keccak_256(msg),
+        chain_id,
+      )
+      .map(EthereumAddress)
+    }
+  }
+}
+
+impl<T: Config> BoundToRuntimeAppPublic for Pallet<T> {
+  type Public = T::BridgeAuthorityId;
+}
+
+impl<T: Config> OneSessionHandler<T::AccountId> for Pallet<T> {
+  type Key = T::BridgeAuthorityId;
+
+  fn on_genesis_session<'a, I: 'a>(validators: I)
+  where
+    I: Iterator<Item = (&'a T::AccountId, T::BridgeAuthorityId)>,
+  {
+    BridgeAuthorities::<T>::put(validators.map(|(_, key)| key).collect::<Vec<_>>());
+  }
+
+  fn on_new_session<'a, I: 'a>(_changed: bool, validators: I, _queued_validators: I)
+  where
+    I: Iterator<Item = (&'a T::AccountId, T::BridgeAuthorityId)>,
+  {
+    let authorities = validators.map(|(_, key)| key).collect::<Vec<_>>();
+    if authorities != Self::bridge_authorities() {
+      let set_id = Self::bridge_authority_set_id().saturating_add(1);
+      BridgeAuthoritySetId::<T>::put(set_id);
+      Self::deposit_event(Event::BridgeAuthoritiesChanged(
+        set_id,
+        authorities.len() as u32,
+      ));
+      BridgeAuthorities::<T>::put(authorities);
     }
   }
+
+  fn on_disabled(_validator_index: usize) {}
 }
@@ -4,13 +4,24 @@
 use super::*;
 use crate as clover_claims;
 
-use frame_support::parameter_types;
+use clover_traits::VerifiedReceipt;
+
+use frame_support::{
+  ord_parameter_types, parameter_types,
+  traits::{GenesisBuild, OnUnbalanced},
+  weights::Weight,
+};
+use frame_system::{EnsureRoot, EnsureSignedBy};
 use hex_literal::hex;
-use sp_core::H256;
+use orml_traits::parameter_type_with_key;
+use pallet_evm::AddressMapping;
+use sp_core::{H160, H256};
 use sp_runtime::{
   testing::Header,
-  traits::{BlakeTwo256, IdentityLookup},
+  traits::{BlakeTwo256, ConvertInto, IdentityLookup},
+  DispatchError,
 };
+use std::cell::RefCell;
 
 parameter_types! {
     pub const BlockHashCount: u32 = 250;
@@ -54,17 +65,174 @@ impl pallet_balances::Config for Test {
   type MaxLocks = ();
 }
 
+parameter_types! {
+    pub const MinVestedTransfer: u64 = 1;
+}
+
+impl pallet_vesting::Config for Test {
+  type Event = ();
+  type Currency = Balances;
+  type BlockNumberToBalance = ConvertInto;
+  type MinVestedTransfer = MinVestedTransfer;
+  type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const MaximumSchedulerWeight: Weight = 1_000_000_000_000;
+    pub const MaxScheduledPerBlock: u32 = 50;
+}
+
+impl pallet_scheduler::Config for Test {
+  type Event = ();
+  type Origin = Origin;
+  type PalletsOrigin = OriginCaller;
+  type Call = Call;
+  type MaximumWeight = MaximumSchedulerWeight;
+  type ScheduleOrigin = EnsureRoot<u64>;
+  type MaxScheduledPerBlock = MaxScheduledPerBlock;
+  type WeightInfo = ();
+}
+
+parameter_type_with_key! {
+  pub ExistentialDeposits: |_currency_id: CurrencyId| -> u64 {
+    Zero::zero()
+  };
+}
+
+impl orml_tokens::Config for Test {
+  type Event = ();
+  type Balance = u64;
+  type Amount = i64;
+  type CurrencyId = CurrencyId;
+  type WeightInfo = ();
+  type ExistentialDeposits = ExistentialDeposits;
+  type OnDust = ();
+}
+
+pub const BSC: ChainId = 56;
+pub const ETH: ChainId = 1;
+pub const TREASURY: u64 = 99;
+pub const PAUSER: u64 = 8;
+pub const CLV: CurrencyId = CurrencyId::CLV;
+pub const CETH: CurrencyId = CurrencyId::CETH;
+
+/// Maps an EVM address to the account of its first 8 bytes
+pub struct EvmAddressMapping;
+impl AddressMapping<u64> for EvmAddressMapping {
+  fn into_account_id(address: H160) -> u64 {
+    let mut data = [0u8; 8];
+    data.copy_from_slice(&address[0..8]);
+    u64::from_le_bytes(data)
+  }
+
+  fn to_evm_address(_account_id: &u64) -> Option<H160> {
+    None
+  }
+}
+
+thread_local! {
+  static RECEIPTS: RefCell<Vec<(ReceiptProof, VerifiedReceipt)>> = RefCell::new(Vec::new());
+  static RELAYER_BOND: RefCell<u64> = RefCell::new(0);
+}
+
+/// Verifies the proofs set by `set_receipt` for BSC
+pub struct MockReceiptVerifier;
+impl VerifyReceipt for MockReceiptVerifier {
+  fn verify_receipt(chain_id: u64, proof: &ReceiptProof) -> Result<VerifiedReceipt, DispatchError> {
+    if chain_id != BSC {
+      return Err(DispatchError::Other("unsupported chain"));
+    }
+    RECEIPTS.with(|receipts| {
+      receipts
+        .borrow()
+        .iter()
+        .find(|(p, _)| p == proof)
+        .map(|(_, receipt)| receipt.clone())
+        .ok_or(DispatchError::Other("invalid proof"))
+    })
+  }
+}
+
+pub fn set_receipt(proof: ReceiptProof, receipt: VerifiedReceipt) {
+  RECEIPTS.with(|receipts| receipts.borrow_mut().push((proof, receipt)));
+}
+
+/// Zero unless set by `set_relayer_bond`, so relayers join committees without bonding
+pub struct RelayerBond;
+impl Get<u64> for RelayerBond {
+  fn get() -> u64 {
+    RELAYER_BOND.with(|bond| *bond.borrow())
+  }
+}
+
+pub fn set_relayer_bond(bond: u64) {
+  RELAYER_BOND.with(|v| *v.borrow_mut() = bond);
+}
+
+pub struct FeeDestination;
+impl OnUnbalanced<pallet_balances::NegativeImbalance<Test>> for FeeDestination {
+  fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Test>) {
+    Balances::resolve_creating(&TREASURY, amount);
+  }
+}
+
 parameter_types! {
     pub Prefix: &'static [u8] = b"Pay CLVs to the TEST account:";
     pub const ClaimsModuleId: ModuleId = ModuleId(*b"clvclaim");
+    pub const LegacyChainId: ChainId = BSC;
+    pub const BurnRefundDelay: u64 = 10;
+    pub const BlocksPerDay: u64 = 20;
+    pub const ClaimExpiry: u64 = 100;
+    pub const UnbondingDelay: u64 = 10;
+    pub const LargeMintDelay: u64 = 5;
+    pub const NativeCurrencyId: CurrencyId = CLV;
+    pub const TokenFeeDestination: u64 = TREASURY;
+    pub const CertificateUnsignedPriority: TransactionPriority = TransactionPriority::max_value();
+}
+ord_parameter_types! {
+    pub const Pauser: u64 = PAUSER;
 }
 impl Config for Test {
   type ModuleId = ClaimsModuleId;
   type Event = ();
   type Currency = Balances;
   type Prefix = Prefix;
+  type LegacyChainId = LegacyChainId;
+  type BurnRefundDelay = BurnRefundDelay;
+  type BlocksPerDay = BlocksPerDay;
+  type ClaimExpiry = ClaimExpiry;
+  type FeeDestination = FeeDestination;
+  type MultiCurrency = Tokens;
+  type NativeCurrencyId = NativeCurrencyId;
+  type TokenFeeDestination = TokenFeeDestination;
+  type AdminOrigin = EnsureRoot<u64>;
+  type PauseOrigin = EnsureSignedBy<Pauser, u64>;
+  type AddressMapping = EvmAddressMapping;
+  type ReceiptVerifier = MockReceiptVerifier;
+  type RelayerBond = RelayerBond;
+  type UnbondingDelay = UnbondingDelay;
+  type SlashOrigin = EnsureRoot<u64>;
+  type Slashed = FeeDestination;
+  type VestingSchedule = Vesting;
+  type LargeMintDelay = LargeMintDelay;
+  type VetoOrigin = EnsureRoot<u64>;
+  type MintCall = Call;
+  type Scheduler = Scheduler;
+  type PalletsOrigin = OriginCaller;
+  type BridgeAuthorityId = ecdsa::AuthorityId;
+  type CertificateUnsignedPriority = CertificateUnsignedPriority;
+  type FreezeOrigin = EnsureRoot<u64>;
 }
-type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+
+impl<C> SendTransactionTypes<C> for Test
+where
+  Call: From<C>,
+{
+  type OverarchingCall = Call;
+  type Extrinsic = UncheckedExtrinsic;
+}
+
+pub type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
 frame_support::construct_runtime!(
@@ -75,7 +243,10 @@ frame_support::construct_runtime!(
   {
     System: frame_system::{Module, Call, Config, Storage, Event<T>},
     Balances: pallet_balances::{Module, Call, Storage, Config<T>, Event<T>},
-    CloverClaims: clover_claims::{Module, Call, Storage, Event<T>, ValidateUnsigned},
+    Tokens: orml_tokens::{Module, Storage, Event<T>, Config<T>},
+    Vesting: pallet_vesting::{Module, Call, Storage, Event<T>, Config<T>},
+    Scheduler: pallet_scheduler::{Module, Call, Storage, Event<T>},
+    CloverClaims: clover_claims::{Module, Call, Storage, Config<T>, Event<T>, ValidateUnsigned},
   }
 );
 
@@ -92,6 +263,19 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
   .assimilate_storage(&mut t)
   .unwrap();
 
+  orml_tokens::GenesisConfig::<Test> {
+    endowed_accounts: vec![(4, CETH, 100)],
+  }
+  .assimilate_storage(&mut t)
+  .unwrap();
+
+  clover_claims::GenesisConfig::<Test> {
+    enabled_chains: vec![BSC],
+    ..Default::default()
+  }
+  .assimilate_storage(&mut t)
+  .unwrap();
+
   t.into()
 }
 
@@ -124,3 +308,31 @@ pub fn get_wrong_msg_eth_sig() -> EcdsaSignature {
   // data: Pay CLVs to the TEST account2:010000000000000
   EcdsaSignature(hex!["7dc3cd6d99fb0dd1f8fbc4fae9aec8399e913496e3dbd33ddd83f723665ecf4569b715c430239750ffb2973d094cfa4fbb808b3f0ec1ef2caff5d5e473b2332a1c"])
 }
+
+pub fn get_typed_claim_eth_addr() -> EthereumAddress {
+  // private key: keccak256("cow")
+  EthereumAddress(hex!["cd2a3d9f938e13cd947ec05abc7fe734df8dd826"])
+}
+
+pub fn get_typed_claim_sig() -> EthereumSignature {
+  // `get_typed_claim_eth_addr`'s EIP-712 sig of `Claim(bytes dest,bytes32 tx)`
+  // domain: Clover Claims, version 1, chain id 56
+  // data: dest 0x0100000000000000, tx 0x4c5adaad6ca9cd2ae9f372b59ff6765fb66082c08caf6e61e6fbc39c35e82bec
+  EthereumSignature::Standard(hex!["79ccc5682017ff300931a8c888bd0cf52f5230efcafc77f48e08680cba6d2e5033d3ec255d0b8500e4759575c0d95ddc4be6cf7366bb1996b127d4d6202ad7ff1b"])
+}
+
+pub fn get_evm_claim_sig() -> EcdsaSignature {
+  // `get_typed_claim_eth_addr`'s sig
+  // data: Pay CLVs to the TEST account:evm:cd2a3d9f938e13cd947ec05abc7fe734df8dd8264c5adaad6ca9cd2ae9f372b59ff6765fb66082c08caf6e61e6fbc39c35e82bec
+  EcdsaSignature(hex!["e90a4f7e67fd721fcb3f7cc9eaa1b1f2e177a30b5a8b01ba9aa2266192fa8eff41c46db965d46a01f756319b5fc6538e86cacad24d9f577ff0ace266a98875a51b"])
+}
+
+pub fn get_evm_burn_sig(nonce: u64) -> EcdsaSignature {
+  // `get_typed_claim_eth_addr`'s sigs of burning 10 CLV to `get_legal_eth_addr` on BSC
+  // data: Pay CLVs to the TEST account:burn:hex(([69u8; 32], 56u64, CLV, dest, 10u64, nonce).encode())
+  match nonce {
+    0 => EcdsaSignature(hex!["96dfa91dbde61b641694703bcc83839d57eb1c7b92d5ae476395da94c390c5bb244b44d88112a1a83c546c8bc1824e607c72572ffd3bc48ecf12dd5f9b238f401c"]),
+    _ => EcdsaSignature(hex!["2de9b5b0d1b29505ccd6507b4b8e7aa444e8876319754a517522f4212a433f3378b27f613351c4fec3e518c15f61846caddc0f7d09223526d342e45c909829b61c"]),
+  }
+}
+}
@@ -3,35 +3,47 @@
 
 use super::*;
 use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok, dispatch::DispatchError};
+use frame_support::{
+  assert_noop, assert_ok, dispatch::DispatchError, traits::OnInitialize, Blake2_128Concat,
+  StorageHasher, Twox64Concat,
+};
 use pallet_balances::Error as BalancesError;
+use sp_runtime::Permill;
+
+/// The claimant, amount and claimed flag of the claim record of the tx
+fn claim_of(chain_id: ChainId, tx: &EthereumTxHash) -> Option<(EthereumAddress, u64, bool)> {
+  CloverClaims::claims(chain_id, tx).map(|record| (record.who, record.amount, record.claimed))
+}
 
 #[test]
 fn mint_and_claim_should_works() {
   new_test_ext().execute_with(|| {
-    // set bridge account to root
-    assert_ok!(CloverClaims::set_bridge_account(Origin::root(), 1));
+    // set relayer
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
 
     // Set claim limit = 100
-    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), 100));
-    assert_eq!(CloverClaims::claim_limit(), 100);
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 100));
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 100);
 
     // Mint a claim
     let tx_hash = get_legal_tx_hash();
     let eth_addr = get_legal_eth_addr();
     assert_ok!(CloverClaims::mint_claim(
       Origin::signed(1),
+      BSC,
+      CLV,
       tx_hash.clone(),
       eth_addr.clone(),
-      100
+      100,
+      None
     ));
 
     // claims and limit should updated
     assert_eq!(
-      CloverClaims::claims(&tx_hash),
+      claim_of(BSC, &tx_hash),
       Some((eth_addr.clone(), 100, false))
     ); // new tx
-    assert_eq!(CloverClaims::claim_limit(), 0);
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 0);
 
     // Claim it with correct msg sig
     // Pay CLVs to the TEST account:0100000000000000
@@ -40,46 +52,120 @@ fn mint_and_claim_should_works() {
     assert_ok!(CloverClaims::claim(
       Origin::none(),
       1,
+      BSC,
       tx_hash.clone(),
       sig.clone()
     ));
 
     // 5. Claim success
     assert_eq!(Balances::free_balance(1), 100);
-    assert_eq!(
-      CloverClaims::claims(&tx_hash),
-      Some((eth_addr.clone(), 100, true))
-    ); // tx has already be claimed
+    assert_eq!(claim_of(BSC, &tx_hash), Some((eth_addr.clone(), 100, true))); // tx has already be claimed
+  });
+}
+
+#[test]
+fn claim_record_should_work() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 2));
+    assert_ok!(CloverClaims::set_relayer_threshold(Origin::root(), BSC, 2));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 100));
+    assert_ok!(CloverClaims::set_mint_fee(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(FeeSchedule::flat(10))
+    ));
+
+    let tx_hash = get_legal_tx_hash();
+    let eth_addr = get_legal_eth_addr();
+    System::set_block_number(5);
+    for relayer in &[1, 2] {
+      assert_ok!(CloverClaims::mint_claim(
+        Origin::signed(*relayer),
+        BSC,
+        CLV,
+        tx_hash,
+        eth_addr,
+        100,
+        None
+      ));
+    }
+    let mut record = ClaimRecord {
+      who: eth_addr,
+      amount: 90,
+      mint_block: 5,
+      relayer: Some(2),
+      fee: 10,
+      claimed: false,
+      claimed_by: None,
+      claim_block: None,
+    };
+    assert_eq!(CloverClaims::claims(BSC, &tx_hash), Some(record.clone()));
+
+    System::set_block_number(8);
+    assert_ok!(CloverClaims::claim(
+      Origin::none(),
+      1,
+      BSC,
+      tx_hash,
+      get_legal_eth_sig()
+    ));
+    record.claimed = true;
+    record.claimed_by = Some(1);
+    record.claim_block = Some(8);
+    assert_eq!(CloverClaims::claims(BSC, &tx_hash), Some(record));
   });
 }
 
 #[test]
 fn mint_fee_should_work() {
   new_test_ext().execute_with(|| {
-    assert_ok!(CloverClaims::set_mint_fee(Origin::root(), 1000));
-    // set bridge account to root
-    assert_ok!(CloverClaims::set_bridge_account(Origin::root(), 1));
-    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), 2000));
+    assert_ok!(CloverClaims::set_mint_fee(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(FeeSchedule::flat(1000))
+    ));
+    // set relayer
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::set_claim_limit(
+      Origin::root(),
+      BSC,
+      CLV,
+      2000
+    ));
 
     // Mint a claim
     let tx_hash = get_legal_tx_hash();
     let eth_addr = get_legal_eth_addr();
     assert_noop!(
-      CloverClaims::mint_claim(Origin::signed(1), tx_hash.clone(), eth_addr.clone(), 100),
+      CloverClaims::mint_claim(
+        Origin::signed(1),
+        BSC,
+        CLV,
+        tx_hash.clone(),
+        eth_addr.clone(),
+        100,
+        None
+      ),
       Error::<Test>::InvalidAmount
     );
 
-    assert_eq!(CloverClaims::claim_limit(), 2000);
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 2000);
 
     assert_ok!(CloverClaims::mint_claim(
       Origin::signed(1),
+      BSC,
+      CLV,
       tx_hash.clone(),
       eth_addr.clone(),
-      1200
+      1200,
+      None
     ));
 
     // claim limit only update with the real amount
-    assert_eq!(CloverClaims::claim_limit(), 1800);
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 1800);
     // Claim it with correct msg sig
     // Pay CLVs to the TEST account:0100000000000000
     let sig = get_legal_eth_sig();
@@ -87,6 +173,7 @@ fn mint_fee_should_work() {
     assert_ok!(CloverClaims::claim(
       Origin::none(),
       1,
+      BSC,
       tx_hash.clone(),
       sig.clone()
     ));
@@ -100,212 +187,4387 @@ fn mint_fee_should_work() {
 }
 
 #[test]
-fn change_bridger_should_work() {
+fn change_relayer_should_work() {
   new_test_ext().execute_with(|| {
     assert_noop!(
-      CloverClaims::set_bridge_account(Origin::signed(1), 1),
+      CloverClaims::add_relayer(Origin::signed(1), BSC, 1),
       DispatchError::BadOrigin
     );
 
-    // set bridger
-    assert_ok!(CloverClaims::set_bridge_account(Origin::root(), 1)); // 1 is bridger
+    // set relayer
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1)); // 1 is relayer
 
     // 1. Mint a claim with 2, no way
     let tx_hash = get_legal_tx_hash();
     let eth_addr = get_legal_eth_addr();
     assert_noop!(
-      CloverClaims::mint_claim(Origin::signed(2), tx_hash.clone(), eth_addr.clone(), 100),
+      CloverClaims::mint_claim(
+        Origin::signed(2),
+        BSC,
+        CLV,
+        tx_hash.clone(),
+        eth_addr.clone(),
+        100,
+        None
+      ),
       Error::<Test>::NoPermission
     );
   });
 }
 
 #[test]
-fn non_exist_tx_should_fail() {
+fn relayer_threshold_should_work() {
   new_test_ext().execute_with(|| {
-    let tx_hash = get_legal_tx_hash();
-    let sig = get_legal_eth_sig();
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 2));
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 3));
     assert_noop!(
-      CloverClaims::claim(Origin::none(), 1, tx_hash, sig),
-      Error::<Test>::TxNotMinted
+      CloverClaims::add_relayer(Origin::root(), BSC, 3),
+      Error::<Test>::AlreadyRelayer
     );
-  });
-}
 
-#[test]
-fn illegal_sig_claim_should_fail() {
-  new_test_ext().execute_with(|| {
-    // only root account can change bridge account
+    // threshold can not exceed the committee size
     assert_noop!(
-      CloverClaims::set_bridge_account(Origin::signed(2), 1),
-      DispatchError::BadOrigin
+      CloverClaims::set_relayer_threshold(Origin::root(), BSC, 4),
+      Error::<Test>::InvalidThreshold
     );
-    assert_ok!(CloverClaims::set_bridge_account(Origin::root(), 1));
-
-    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), 100));
+    assert_noop!(
+      CloverClaims::set_relayer_threshold(Origin::root(), BSC, 0),
+      Error::<Test>::InvalidThreshold
+    );
+    assert_ok!(CloverClaims::set_relayer_threshold(Origin::root(), BSC, 2));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 100));
 
-    //  Mint a claim
     let tx_hash = get_legal_tx_hash();
     let eth_addr = get_legal_eth_addr();
     assert_ok!(CloverClaims::mint_claim(
       Origin::signed(1),
+      BSC,
+      CLV,
       tx_hash.clone(),
       eth_addr.clone(),
-      100
+      100,
+      None
     ));
+    // one attestation is not enough
+    assert_eq!(claim_of(BSC, &tx_hash), None);
+    assert_eq!(CloverClaims::attestations(BSC, &tx_hash).len(), 1);
 
-    // Claim it with illegal sig
-    let sig1 = get_another_account_eth_sig();
+    // the same relayer can not attest twice
     assert_noop!(
-      CloverClaims::claim(Origin::none(), 1, tx_hash.clone(), sig1.clone()),
-      Error::<Test>::SignatureNotMatch
+      CloverClaims::mint_claim(
+        Origin::signed(1),
+        BSC,
+        CLV,
+        tx_hash.clone(),
+        eth_addr.clone(),
+        100,
+        None
+      ),
+      Error::<Test>::AlreadyAttested
     );
 
-    // Sig with wrong message should failed
-    let sig2 = get_wrong_msg_eth_sig();
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(2),
+      BSC,
+      CLV,
+      tx_hash.clone(),
+      eth_addr.clone(),
+      100,
+      None
+    ));
+    assert_eq!(
+      claim_of(BSC, &tx_hash),
+      Some((eth_addr.clone(), 100, false))
+    );
+    assert_eq!(CloverClaims::attestations(BSC, &tx_hash).len(), 0);
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 0);
+
     assert_noop!(
-      CloverClaims::claim(Origin::none(), 1, tx_hash.clone(), sig2.clone()),
-      Error::<Test>::SignatureNotMatch
+      CloverClaims::mint_claim(
+        Origin::signed(3),
+        BSC,
+        CLV,
+        tx_hash.clone(),
+        eth_addr.clone(),
+        100,
+        None
+      ),
+      Error::<Test>::AlreadyMinted
     );
   });
 }
 
 #[test]
-fn mint_twice_should_fail() {
+fn conflicting_attestation_should_be_rejected() {
   new_test_ext().execute_with(|| {
-    // Set miner and superior
-    assert_ok!(CloverClaims::set_bridge_account(Origin::root(), 1));
-
-    // Set limit
-    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), 100));
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 2));
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 3));
+    assert_ok!(CloverClaims::set_relayer_threshold(Origin::root(), BSC, 2));
+    assert_ok!(CloverClaims::set_claim_limit(
+      Origin::root(),
+      BSC,
+      CLV,
+      1000
+    ));
 
-    // Mint a claim
     let tx_hash = get_legal_tx_hash();
     let eth_addr = get_legal_eth_addr();
     assert_ok!(CloverClaims::mint_claim(
       Origin::signed(1),
+      BSC,
+      CLV,
       tx_hash.clone(),
       eth_addr.clone(),
-      100
+      100,
+      None
     ));
+    // relayer 2 reports a different amount, the attestation is rejected
+    assert_noop!(
+      CloverClaims::mint_claim(
+        Origin::signed(2),
+        BSC,
+        CLV,
+        tx_hash.clone(),
+        eth_addr.clone(),
+        900,
+        None
+      ),
+      Error::<Test>::ConflictingAttestation
+    );
+    assert_eq!(claim_of(BSC, &tx_hash), None);
+    assert_eq!(CloverClaims::attestations(BSC, &tx_hash).len(), 1);
 
-    // 3. Mint the same eth again
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(3),
+      BSC,
+      CLV,
+      tx_hash.clone(),
+      eth_addr.clone(),
+      100,
+      None
+    ));
+    assert_eq!(
+      claim_of(BSC, &tx_hash),
+      Some((eth_addr.clone(), 100, false))
+    );
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 900);
+
+    // the attestation of a removed relayer no longer blocks the others
+    let tx_hash = EthereumTxHash([1; 32]);
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx_hash,
+      eth_addr,
+      900,
+      None
+    ));
     assert_noop!(
-      CloverClaims::mint_claim(Origin::signed(1), tx_hash.clone(), eth_addr.clone(), 100),
-      Error::<Test>::AlreadyMinted
+      CloverClaims::mint_claim(Origin::signed(2), BSC, CLV, tx_hash, eth_addr, 100, None),
+      Error::<Test>::ConflictingAttestation
     );
+    assert_ok!(CloverClaims::remove_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(2),
+      BSC,
+      CLV,
+      tx_hash,
+      eth_addr,
+      100,
+      None
+    ));
+    assert_eq!(CloverClaims::attestations(BSC, &tx_hash).len(), 1);
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(3),
+      BSC,
+      CLV,
+      tx_hash,
+      eth_addr,
+      100,
+      None
+    ));
+    assert_eq!(claim_of(BSC, &tx_hash), Some((eth_addr, 100, false)));
   });
 }
 
 #[test]
-fn claim_twice_should_fail() {
+fn vested_claim_should_work() {
   new_test_ext().execute_with(|| {
-    assert_ok!(CloverClaims::set_bridge_account(Origin::root(), 1));
-
-    // Set limit
-    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), 100));
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 300));
 
-    // Mint a claim
     let tx_hash = get_legal_tx_hash();
     let eth_addr = get_legal_eth_addr();
+    let vesting = ClaimVesting {
+      start: 10,
+      per_block: 10,
+    };
+    // only the native currency can vest
+    assert_noop!(
+      CloverClaims::mint_claim(
+        Origin::signed(1),
+        BSC,
+        CETH,
+        tx_hash,
+        eth_addr,
+        100,
+        Some(vesting.clone())
+      ),
+      Error::<Test>::VestingNotSupported
+    );
+    assert_noop!(
+      CloverClaims::mint_claim(
+        Origin::signed(1),
+        BSC,
+        CLV,
+        tx_hash,
+        eth_addr,
+        100,
+        Some(ClaimVesting {
+          start: 10,
+          per_block: 0,
+        })
+      ),
+      Error::<Test>::InvalidVestingSchedule
+    );
+
     assert_ok!(CloverClaims::mint_claim(
       Origin::signed(1),
-      tx_hash.clone(),
-      eth_addr.clone(),
-      100
+      BSC,
+      CLV,
+      tx_hash,
+      eth_addr,
+      100,
+      Some(vesting.clone())
     ));
+    // the unvested amount is minted in full against the limit
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 200);
+    assert_eq!(CloverClaims::vesting(BSC, &tx_hash), Some(vesting.clone()));
+    assert_eq!(
+      CloverClaims::claim_status(BSC, &tx_hash),
+      ClaimStatus::Minted(ClaimDetails {
+        currency_id: CLV,
+        who: eth_addr,
+        amount: 100,
+        expiry: Some(100),
+        vesting: Some(vesting.clone()),
+      })
+    );
 
-    // Claim it
-    // Pay CLVs to the TEST account:0100000000000000
-    let sig = get_legal_eth_sig();
-    assert_eq!(Balances::free_balance(1), 0);
+    let issuance = Balances::total_issuance();
     assert_ok!(CloverClaims::claim(
       Origin::none(),
       1,
-      tx_hash.clone(),
-      sig.clone()
+      BSC,
+      tx_hash,
+      get_legal_eth_sig()
     ));
+    assert_eq!(Balances::total_issuance(), issuance + 100);
     assert_eq!(Balances::free_balance(1), 100);
+    assert_eq!(Balances::usable_balance(&1), 0);
+    assert_eq!(
+      <Test as Config>::VestingSchedule::vesting_balance(&1),
+      Some(100)
+    );
 
-    // failed on the second try
-    assert_noop!(
-      CloverClaims::claim(Origin::none(), 1, tx_hash.clone(), sig.clone()),
-      Error::<Test>::AlreadyClaimed
+    System::set_block_number(15);
+    assert_eq!(
+      <Test as Config>::VestingSchedule::vesting_balance(&1),
+      Some(50)
+    );
+    System::set_block_number(20);
+    assert_eq!(
+      <Test as Config>::VestingSchedule::vesting_balance(&1),
+      Some(0)
     );
-    assert_eq!(Balances::free_balance(1), 100);
   });
 }
 
 #[test]
-fn claim_limit_should_work() {
+fn vested_claim_should_not_replace_vesting_schedule() {
+  use crate::Call as ClaimsCall;
+  use frame_support::unsigned::ValidateUnsigned;
+  use sp_runtime::transaction_validity::{InvalidTransaction, TransactionSource};
+
   new_test_ext().execute_with(|| {
-    assert_ok!(CloverClaims::set_bridge_account(Origin::root(), 2));
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 2));
+    assert_ok!(CloverClaims::set_relayer_threshold(Origin::root(), BSC, 2));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 300));
 
-    // Mint a claim should failed without limitation
     let tx_hash = get_legal_tx_hash();
     let eth_addr = get_legal_eth_addr();
+    let vesting = ClaimVesting {
+      start: 10,
+      per_block: 10,
+    };
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx_hash,
+      eth_addr,
+      100,
+      Some(vesting.clone())
+    ));
+    // attestations without the vesting do not count towards it
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(2),
+      BSC,
+      CLV,
+      tx_hash,
+      eth_addr,
+      100,
+      None
+    ));
+    assert_eq!(claim_of(BSC, &tx_hash), None);
+
+    assert_ok!(CloverClaims::set_relayer_threshold(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 3));
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(3),
+      BSC,
+      CLV,
+      tx_hash,
+      eth_addr,
+      100,
+      Some(vesting.clone())
+    ));
+    assert_eq!(
+      CloverClaims::mints(BSC, &tx_hash).map(|mint| mint.relayers),
+      Some(vec![1, 3])
+    );
+
+    assert_ok!(<Test as Config>::VestingSchedule::add_vesting_schedule(
+      &1, 10, 1, 0
+    ));
     assert_noop!(
-      CloverClaims::mint_claim(Origin::signed(2), tx_hash.clone(), eth_addr.clone(), 10),
-      Error::<Test>::ClaimLimitExceeded
+      CloverClaims::claim(Origin::none(), 1, BSC, tx_hash, get_legal_eth_sig()),
+      Error::<Test>::VestedBalanceExists
+    );
+    assert_eq!(
+      CloverClaims::validate_unsigned(
+        TransactionSource::External,
+        &ClaimsCall::claim(1, BSC, tx_hash, get_legal_eth_sig())
+      ),
+      InvalidTransaction::Custom(ValidityError::VestedBalanceExists.into()).into()
+    );
+  });
+}
+
+#[test]
+fn relayer_bond_should_work() {
+  new_test_ext().execute_with(|| {
+    set_relayer_bond(50);
+    assert_noop!(
+      CloverClaims::add_relayer(Origin::root(), BSC, 4),
+      Error::<Test>::InsufficientBond
+    );
+    assert_noop!(
+      CloverClaims::bond_relayer(Origin::signed(4), 0),
+      Error::<Test>::InvalidAmount
     );
+    assert_ok!(CloverClaims::bond_relayer(Origin::signed(4), 30));
+    assert_noop!(
+      CloverClaims::add_relayer(Origin::root(), BSC, 4),
+      Error::<Test>::InsufficientBond
+    );
+    assert_ok!(CloverClaims::bond_relayer(Origin::signed(4), 40));
+    assert_eq!(Balances::reserved_balance(4), 70);
 
-    // Set limit
-    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), 10));
-    assert_eq!(CloverClaims::claim_limit(), 10);
+    // a relayer keeps the minimum bond active
+    assert_noop!(
+      CloverClaims::unbond_relayer(Origin::signed(4), 30),
+      Error::<Test>::InsufficientBond
+    );
+    assert_noop!(
+      CloverClaims::unbond_relayer(Origin::signed(5), 10),
+      Error::<Test>::NotBonded
+    );
+    assert_ok!(CloverClaims::unbond_relayer(Origin::signed(4), 20));
+    assert_eq!(
+      CloverClaims::bonds(4),
+      Some(BondInfo {
+        active: 50,
+        unbonding: 20,
+        unlock_at: 10,
+      })
+    );
 
-    // Claim amount with limitation should be ok
-    assert_ok!(CloverClaims::mint_claim(
-      Origin::signed(2),
-      tx_hash.clone(),
-      eth_addr.clone(),
+    System::set_block_number(9);
+    assert_noop!(
+      CloverClaims::withdraw_unbonded(Origin::signed(4)),
+      Error::<Test>::BondLocked
+    );
+    System::set_block_number(10);
+    assert_ok!(CloverClaims::withdraw_unbonded(Origin::signed(4)));
+    assert_eq!(Balances::reserved_balance(4), 50);
+    assert_eq!(Balances::free_balance(4), 50);
+    assert_noop!(
+      CloverClaims::withdraw_unbonded(Origin::signed(4)),
+      Error::<Test>::NothingToWithdraw
+    );
+
+    assert_ok!(CloverClaims::remove_relayer(Origin::root(), BSC, 4));
+    assert_ok!(CloverClaims::unbond_relayer(Origin::signed(4), 50));
+    System::set_block_number(20);
+    assert_ok!(CloverClaims::withdraw_unbonded(Origin::signed(4)));
+    assert_eq!(CloverClaims::bonds(4), None);
+    assert_eq!(Balances::free_balance(4), 100);
+  });
+}
+
+#[test]
+fn slash_relayer_should_work() {
+  new_test_ext().execute_with(|| {
+    set_relayer_bond(50);
+    let tx_hash = get_legal_tx_hash();
+    assert_ok!(CloverClaims::bond_relayer(Origin::signed(4), 60));
+    assert_ok!(CloverClaims::bond_relayer(Origin::signed(5), 60));
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 5));
+    assert_ok!(CloverClaims::set_relayer_threshold(Origin::root(), BSC, 2));
+    assert_ok!(CloverClaims::unbond_relayer(Origin::signed(5), 10));
+
+    assert_noop!(
+      CloverClaims::slash_relayer(Origin::signed(4), BSC, tx_hash, 5, 30),
+      DispatchError::BadOrigin
+    );
+    assert_noop!(
+      CloverClaims::slash_relayer(Origin::root(), BSC, tx_hash, 3, 30),
+      Error::<Test>::NotBonded
+    );
+
+    // the remaining bond of 4 is still enough
+    assert_ok!(CloverClaims::slash_relayer(
+      Origin::root(),
+      BSC,
+      tx_hash,
+      4,
       10
     ));
-    assert_eq!(CloverClaims::claim_limit(), 0);
+    assert_eq!(CloverClaims::relayers(BSC), vec![4, 5]);
+
+    // the active bond is slashed first, then the unbonding one
+    assert_ok!(CloverClaims::slash_relayer(
+      Origin::root(),
+      BSC,
+      tx_hash,
+      5,
+      30
+    ));
+    assert_eq!(
+      CloverClaims::bonds(5),
+      Some(BondInfo {
+        active: 20,
+        unbonding: 10,
+        unlock_at: 10,
+      })
+    );
+    assert_eq!(Balances::free_balance(TREASURY), 40);
+    assert_eq!(CloverClaims::relayers(BSC), vec![4]);
+    assert_eq!(CloverClaims::relayer_threshold(BSC), 1);
+
+    // at most the bond is slashed
+    assert_ok!(CloverClaims::slash_relayer(
+      Origin::root(),
+      BSC,
+      tx_hash,
+      5,
+      100
+    ));
+    assert_eq!(CloverClaims::bonds(5), None);
+    assert_eq!(Balances::reserved_balance(5), 0);
+    assert_eq!(Balances::free_balance(5), 40);
+    assert_eq!(Balances::free_balance(TREASURY), 70);
   });
 }
 
 #[test]
-fn burn_should_work() {
+fn large_mint_should_be_delayed() {
   new_test_ext().execute_with(|| {
-    let eth_addr = get_legal_eth_addr();
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 200));
+    assert_ok!(CloverClaims::set_mint_fee(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(FeeSchedule::flat(1))
+    ));
+    assert_noop!(
+      CloverClaims::set_large_mint_threshold(Origin::signed(1), BSC, CLV, Some(50)),
+      DispatchError::BadOrigin
+    );
+    assert_ok!(CloverClaims::set_large_mint_threshold(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(50)
+    ));
+    assert_eq!(CloverClaims::large_mint_threshold(BSC, CLV), Some(50));
 
-    // should burn balance from account 4
-    assert_ok!(CloverClaims::burn(Origin::signed(4), eth_addr, 40));
-    assert_eq!(Balances::free_balance(4), 60);
+    // mints up to the threshold are instant
+    let small = EthereumTxHash([1; 32]);
+    let eth_addr = get_legal_eth_addr();
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      small,
+      eth_addr,
+      50,
+      None
+    ));
+    assert_eq!(claim_of(BSC, &small), Some((eth_addr, 49, false)));
 
-    assert_ok!(CloverClaims::burn(Origin::signed(4), eth_addr, 40));
-    assert_eq!(Balances::free_balance(4), 20);
+    let tx_hash = get_legal_tx_hash();
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx_hash,
+      eth_addr,
+      60,
+      None
+    ));
+    assert_eq!(claim_of(BSC, &tx_hash), None);
+    assert_eq!(
+      CloverClaims::pending_mints(BSC, &tx_hash),
+      Some(PendingMint {
+        currency_id: CLV,
+        who: eth_addr,
+        amount: 59,
+        fee: 1,
+        vesting: None,
+        relayer: 1,
+        relayers: vec![1],
+        finalize_at: 5,
+      })
+    );
+    assert_eq!(
+      CloverClaims::claim_status(BSC, &tx_hash),
+      ClaimStatus::Delayed(
+        ClaimDetails {
+          currency_id: CLV,
+          who: eth_addr,
+          amount: 59,
+          expiry: None,
+          vesting: None,
+        },
+        5
+      )
+    );
+    // the limit is taken while pending, the fee is collected once finalized
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 200 - 49 - 59);
+    assert_eq!(CloverClaims::fees_collected(BSC, CLV), 1);
+    assert_noop!(
+      CloverClaims::mint_claim(Origin::signed(1), BSC, CLV, tx_hash, eth_addr, 60, None),
+      Error::<Test>::AlreadyMinted
+    );
+    assert_noop!(
+      CloverClaims::claim(Origin::none(), 1, BSC, tx_hash, get_legal_eth_sig()),
+      Error::<Test>::TxNotMinted
+    );
+    assert_noop!(
+      CloverClaims::finalize_mint(Origin::signed(2), BSC, tx_hash),
+      Error::<Test>::MintDelayNotPassed
+    );
 
-    // should failed if burn all the balances from an account
+    // finalized by the scheduler
+    System::set_block_number(5);
+    Scheduler::on_initialize(5);
+    assert_eq!(CloverClaims::pending_mints(BSC, &tx_hash), None);
+    assert_eq!(claim_of(BSC, &tx_hash), Some((eth_addr, 59, false)));
+    assert_eq!(CloverClaims::mints(BSC, &tx_hash).map(|m| m.block), Some(5));
+    assert_eq!(CloverClaims::fees_collected(BSC, CLV), 2);
     assert_noop!(
-      CloverClaims::burn(Origin::signed(5), eth_addr, 100),
-      BalancesError::<Test, _>::KeepAlive
+      CloverClaims::finalize_mint(Origin::signed(2), BSC, tx_hash),
+      Error::<Test>::MintNotPending
     );
-    assert_eq!(Balances::free_balance(5), 100);
+
+    assert_ok!(CloverClaims::claim(
+      Origin::none(),
+      1,
+      BSC,
+      tx_hash,
+      get_legal_eth_sig()
+    ));
+    assert_eq!(Balances::free_balance(1), 59);
+
+    // without a threshold any value is instant
+    assert_ok!(CloverClaims::set_large_mint_threshold(
+      Origin::root(),
+      BSC,
+      CLV,
+      None
+    ));
+    let large = EthereumTxHash([2; 32]);
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      large,
+      eth_addr,
+      80,
+      None
+    ));
+    assert_eq!(claim_of(BSC, &large), Some((eth_addr, 79, false)));
   });
 }
 
 #[test]
-fn burn_fee_should_work() {
+fn veto_mint_should_work() {
   new_test_ext().execute_with(|| {
-    assert_ok!(CloverClaims::set_burn_fee(Origin::root(), 30));
-    // set bridge account to root
-    assert_ok!(CloverClaims::set_bridge_account(Origin::root(), 1));
-    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), 2000));
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 100));
+    assert_ok!(CloverClaims::set_address_daily_cap(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(100)
+    ));
+    assert_ok!(CloverClaims::set_large_mint_threshold(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(10)
+    ));
 
+    let tx_hash = get_legal_tx_hash();
     let eth_addr = get_legal_eth_addr();
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx_hash,
+      eth_addr,
+      100,
+      None
+    ));
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 0);
 
-    // burn should failed if there is not enough balance to pay fee
     assert_noop!(
-      CloverClaims::burn(Origin::signed(4), eth_addr, 20),
-      Error::<Test>::InvalidAmount
+      CloverClaims::veto_mint(Origin::signed(1), BSC, tx_hash),
+      DispatchError::BadOrigin
     );
-    assert_eq!(Balances::free_balance(4), 100);
+    assert_noop!(
+      CloverClaims::veto_mint(Origin::root(), BSC, EthereumTxHash([1; 32])),
+      Error::<Test>::MintNotPending
+    );
+    assert_ok!(CloverClaims::veto_mint(Origin::root(), BSC, tx_hash));
+    assert_eq!(CloverClaims::pending_mints(BSC, &tx_hash), None);
+    assert_eq!(
+      CloverClaims::claim_status(BSC, &tx_hash),
+      ClaimStatus::Revoked
+    );
+    // the limits are given back
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 100);
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      EthereumTxHash([1; 32]),
+      eth_addr,
+      100,
+      None
+    ));
 
-    assert_ok!(CloverClaims::burn(Origin::signed(5), eth_addr, 40));
-    assert_eq!(Balances::free_balance(5), 60);
+    // the vetoed tx can not be minted again and is not finalized
+    assert_noop!(
+      CloverClaims::mint_claim(Origin::signed(1), BSC, CLV, tx_hash, eth_addr, 100, None),
+      Error::<Test>::AlreadyRevoked
+    );
+    System::set_block_number(5);
+    Scheduler::on_initialize(5);
+    assert_eq!(claim_of(BSC, &tx_hash), None);
+    assert_eq!(
+      CloverClaims::pending_mints(BSC, &EthereumTxHash([1; 32])),
+      None
+    );
+    assert_eq!(
+      claim_of(BSC, &EthereumTxHash([1; 32])),
+      Some((eth_addr, 100, false))
+    );
+  });
+}
 
-    // module has the fee deposited
-    assert_eq!(Balances::free_balance(CloverClaims::account_id()), 30);
+#[test]
+fn bridged_supply_should_be_tracked() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::set_claim_limit(
+      Origin::root(),
+      BSC,
+      CLV,
+      1000
+    ));
+    assert_ok!(CloverClaims::set_mint_fee(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(FeeSchedule::flat(1))
+    ));
+
+    let tx_hash = get_legal_tx_hash();
+    let eth_addr = get_legal_eth_addr();
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx_hash,
+      eth_addr,
+      100,
+      None
+    ));
+    let voided = EthereumTxHash([1; 32]);
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      voided,
+      eth_addr,
+      50,
+      None
+    ));
+    assert_eq!(
+      CloverClaims::bridged_supply(BSC, CLV),
+      BridgedSupply {
+        minted: 148,
+        claimed: 0,
+        burned: 0,
+      }
+    );
+
+    // voided mints are deducted
+    assert_ok!(CloverClaims::void_mint(Origin::signed(1), BSC, voided));
+    assert_eq!(CloverClaims::bridged_supply(BSC, CLV).minted, 99);
+
+    assert_ok!(CloverClaims::claim(
+      Origin::none(),
+      1,
+      BSC,
+      tx_hash,
+      get_legal_eth_sig()
+    ));
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      eth_addr,
+      40
+    ));
+    assert_eq!(
+      CloverClaims::bridged_supply(BSC, CLV),
+      BridgedSupply {
+        minted: 99,
+        claimed: 99,
+        burned: 40,
+      }
+    );
+    assert_eq!(CloverClaims::bridged_supply(BSC, CLV).outstanding(), 59);
+
+    // refunded burns are deducted
+    System::set_block_number(10);
+    assert_ok!(CloverClaims::refund_burn(Origin::signed(1), BSC, 0));
+    assert_eq!(CloverClaims::bridged_supply(BSC, CLV).burned, 0);
+    assert_eq!(
+      CloverClaims::supply_audit(BSC, CLV),
+      SupplyAudit {
+        minted: 99,
+        claimed: 99,
+        burned: 0,
+        fees: 1,
+        lock_report: None,
+      }
+    );
+    assert_eq!(CloverClaims::bridged_supply(ETH, CLV), Default::default());
+  });
+}
+
+#[test]
+fn supply_invariant_violation_should_pause() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::set_claim_limit(
+      Origin::root(),
+      BSC,
+      CLV,
+      1000
+    ));
+
+    let tx_hash = get_legal_tx_hash();
+    let eth_addr = get_legal_eth_addr();
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx_hash,
+      eth_addr,
+      100,
+      None
+    ));
+    assert_ok!(CloverClaims::claim(
+      Origin::none(),
+      1,
+      BSC,
+      tx_hash,
+      get_legal_eth_sig()
+    ));
+
+    // only relayers of the chain report
+    assert_noop!(
+      CloverClaims::report_locked(Origin::signed(2), BSC, CLV, 100),
+      Error::<Test>::NoPermission
+    );
+    System::set_block_number(3);
+    assert_ok!(CloverClaims::report_locked(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      100
+    ));
+    assert_eq!(
+      CloverClaims::lock_report(BSC, CLV),
+      Some(LockReport {
+        relayer: 1,
+        locked: 100,
+        block: 3,
+      })
+    );
+    assert!(!CloverClaims::paused(BridgeOperation::Mint));
+
+    // less locked than claimed pauses minting and claiming
+    assert_ok!(CloverClaims::report_locked(Origin::signed(1), BSC, CLV, 99));
+    assert_eq!(
+      CloverClaims::lock_report(BSC, CLV).map(|r| r.locked),
+      Some(99)
+    );
+    assert!(CloverClaims::paused(BridgeOperation::Mint));
+    assert!(CloverClaims::paused(BridgeOperation::Claim));
+    assert!(!CloverClaims::paused(BridgeOperation::Burn));
+    assert_noop!(
+      CloverClaims::mint_claim(
+        Origin::signed(1),
+        BSC,
+        CLV,
+        EthereumTxHash([1; 32]),
+        eth_addr,
+        10,
+        None
+      ),
+      Error::<Test>::MintPaused
+    );
+
+    // the burned value is no longer required to be locked
+    assert_ok!(CloverClaims::unpause(Origin::root(), BridgeOperation::Mint));
+    assert_ok!(CloverClaims::unpause(
+      Origin::root(),
+      BridgeOperation::Claim
+    ));
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      eth_addr,
+      10
+    ));
+    assert_ok!(CloverClaims::report_locked(Origin::signed(1), BSC, CLV, 90));
+    assert!(!CloverClaims::paused(BridgeOperation::Mint));
+    assert!(!CloverClaims::paused(BridgeOperation::Claim));
+  });
+}
+
+#[test]
+fn supply_invariant_violation_should_need_relayer_threshold() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::set_claim_limit(
+      Origin::root(),
+      BSC,
+      CLV,
+      1000
+    ));
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      get_legal_tx_hash(),
+      get_legal_eth_addr(),
+      100,
+      None
+    ));
+    assert_ok!(CloverClaims::claim(
+      Origin::none(),
+      1,
+      BSC,
+      get_legal_tx_hash(),
+      get_legal_eth_sig()
+    ));
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 2));
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 3));
+    assert_ok!(CloverClaims::set_relayer_threshold(Origin::root(), BSC, 2));
+
+    // a single relayer can not pause the bridge
+    assert_ok!(CloverClaims::report_locked(Origin::signed(1), BSC, CLV, 0));
+    assert!(!CloverClaims::paused(BridgeOperation::Mint));
+    assert_eq!(CloverClaims::shortfall_reports(BSC, CLV), vec![(1, 0)]);
+
+    // a report of enough locked value does not count
+    assert_ok!(CloverClaims::report_locked(
+      Origin::signed(2),
+      BSC,
+      CLV,
+      100
+    ));
+    assert!(!CloverClaims::paused(BridgeOperation::Mint));
+
+    // a later report replaces the previous one of the relayer
+    assert_ok!(CloverClaims::report_locked(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      100
+    ));
+    assert_eq!(CloverClaims::shortfall_reports(BSC, CLV), vec![]);
+    assert_ok!(CloverClaims::report_locked(Origin::signed(1), BSC, CLV, 90));
+
+    // the reports of removed relayers do not count
+    assert_ok!(CloverClaims::remove_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::report_locked(Origin::signed(3), BSC, CLV, 95));
+    assert!(!CloverClaims::paused(BridgeOperation::Mint));
+    assert_eq!(CloverClaims::shortfall_reports(BSC, CLV), vec![(3, 95)]);
+
+    // the threshold of relayers reporting a shortfall pauses minting and claiming
+    assert_ok!(CloverClaims::report_locked(Origin::signed(2), BSC, CLV, 80));
+    assert!(CloverClaims::paused(BridgeOperation::Mint));
+    assert!(CloverClaims::paused(BridgeOperation::Claim));
+    assert_eq!(CloverClaims::shortfall_reports(BSC, CLV), vec![]);
+  });
+}
+
+#[test]
+fn migrate_to_bridged_supplies_should_work() {
+  new_test_ext().execute_with(|| {
+    use frame_support::traits::OnRuntimeUpgrade;
+
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::set_claim_limit(
+      Origin::root(),
+      BSC,
+      CLV,
+      1000
+    ));
+    let tx_hash = get_legal_tx_hash();
+    let eth_addr = get_legal_eth_addr();
+    for (tx, value) in vec![(tx_hash, 100), (EthereumTxHash([1; 32]), 50)] {
+      assert_ok!(CloverClaims::mint_claim(
+        Origin::signed(1),
+        BSC,
+        CLV,
+        tx,
+        eth_addr,
+        value,
+        None
+      ));
+    }
+    assert_ok!(CloverClaims::claim(
+      Origin::none(),
+      1,
+      BSC,
+      tx_hash,
+      get_legal_eth_sig()
+    ));
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      eth_addr,
+      30
+    ));
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      eth_addr,
+      20
+    ));
+    System::set_block_number(10);
+    assert_ok!(CloverClaims::refund_burn(Origin::signed(1), BSC, 1));
+    // state recorded before the supplies were tracked
+    BridgedSupplies::<Test>::remove(BSC, CLV);
+    StorageVersion::<Test>::put(Releases::V6_0_0);
+
+    CloverClaims::on_runtime_upgrade();
+
+    assert_eq!(StorageVersion::<Test>::get(), Releases::V8_0_0);
+    assert_eq!(
+      CloverClaims::bridged_supply(BSC, CLV),
+      BridgedSupply {
+        minted: 150,
+        claimed: 100,
+        burned: 30,
+      }
+    );
+
+    // the migration should only run once
+    assert_eq!(CloverClaims::on_runtime_upgrade(), 0);
+  });
+}
+
+#[test]
+fn migrate_to_session_bridge_keys_should_work() {
+  new_test_ext().execute_with(|| {
+    assert_eq!(CloverClaims::storage_version(), Releases::V8_0_0);
+    StorageVersion::<Test>::put(Releases::V7_0_0);
+
+    // the runtime adds the bridge keys while the version is below V8
+    assert!(CloverClaims::on_runtime_upgrade() > 0);
+    assert_eq!(CloverClaims::storage_version(), Releases::V8_0_0);
+    assert_eq!(CloverClaims::on_runtime_upgrade(), 0);
+
+    // the placeholder keys of the validators are distinct
+    assert_eq!(ecdsa::placeholder(&1u64), ecdsa::placeholder(&1u64));
+    assert_ne!(ecdsa::placeholder(&1u64), ecdsa::placeholder(&2u64));
+    assert_ne!(ecdsa::placeholder(&1u64), ecdsa::AuthorityId::default());
+  });
+}
+
+#[test]
+fn remove_relayer_should_work() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 2));
+    assert_ok!(CloverClaims::set_relayer_threshold(Origin::root(), BSC, 2));
+
+    // removing a relayer should not make the threshold unreachable
+    assert_noop!(
+      CloverClaims::remove_relayer(Origin::root(), BSC, 2),
+      Error::<Test>::InvalidThreshold
+    );
+    assert_noop!(
+      CloverClaims::remove_relayer(Origin::root(), BSC, 3),
+      Error::<Test>::NotRelayer
+    );
+
+    assert_ok!(CloverClaims::set_relayer_threshold(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::remove_relayer(Origin::root(), BSC, 2));
+    assert_eq!(CloverClaims::relayers(BSC), vec![1]);
+
+    assert_noop!(
+      CloverClaims::mint_claim(
+        Origin::signed(2),
+        BSC,
+        CLV,
+        get_legal_tx_hash(),
+        get_legal_eth_addr(),
+        100,
+        None
+      ),
+      Error::<Test>::NoPermission
+    );
+  });
+}
+
+#[test]
+fn claims_should_be_scoped_by_chain() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(CloverClaims::set_chain_enabled(Origin::root(), ETH, true));
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), ETH, 2));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 100));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), ETH, CLV, 300));
+    assert_ok!(CloverClaims::set_mint_fee(
+      Origin::root(),
+      ETH,
+      CLV,
+      Some(FeeSchedule::flat(10))
+    ));
+
+    let tx_hash = get_legal_tx_hash();
+    let eth_addr = get_legal_eth_addr();
+    // relayers can only mint claims of their own chain
+    assert_noop!(
+      CloverClaims::mint_claim(
+        Origin::signed(1),
+        ETH,
+        CLV,
+        tx_hash.clone(),
+        eth_addr.clone(),
+        100,
+        None
+      ),
+      Error::<Test>::NoPermission
+    );
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx_hash.clone(),
+      eth_addr.clone(),
+      100,
+      None
+    ));
+    // the same tx hash on another chain is a different claim
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(2),
+      ETH,
+      CLV,
+      tx_hash.clone(),
+      eth_addr.clone(),
+      200,
+      None
+    ));
+
+    assert_eq!(
+      claim_of(BSC, &tx_hash),
+      Some((eth_addr.clone(), 100, false))
+    );
+    assert_eq!(
+      claim_of(ETH, &tx_hash),
+      Some((eth_addr.clone(), 190, false))
+    );
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 0);
+    assert_eq!(CloverClaims::claim_limit(ETH, CLV), 110);
+
+    let sig = get_legal_eth_sig();
+    assert_ok!(CloverClaims::claim(
+      Origin::none(),
+      1,
+      ETH,
+      tx_hash.clone(),
+      sig.clone()
+    ));
+    assert_eq!(Balances::free_balance(1), 190);
+    assert_eq!(
+      claim_of(BSC, &tx_hash),
+      Some((eth_addr.clone(), 100, false))
+    );
+  });
+}
+
+#[test]
+fn disabled_chain_should_fail() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), ETH, 1));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), ETH, CLV, 100));
+
+    let tx_hash = get_legal_tx_hash();
+    let eth_addr = get_legal_eth_addr();
+    assert_noop!(
+      CloverClaims::mint_claim(
+        Origin::signed(1),
+        ETH,
+        CLV,
+        tx_hash.clone(),
+        eth_addr.clone(),
+        100,
+        None
+      ),
+      Error::<Test>::ChainDisabled
+    );
+    assert_noop!(
+      CloverClaims::burn(Origin::signed(4), ETH, CLV, eth_addr, 40),
+      Error::<Test>::ChainDisabled
+    );
+
+    assert_ok!(CloverClaims::set_chain_enabled(Origin::root(), BSC, false));
+    assert_noop!(
+      CloverClaims::burn(Origin::signed(4), BSC, CLV, eth_addr, 40),
+      Error::<Test>::ChainDisabled
+    );
+  });
+}
+
+#[test]
+fn migrate_to_multi_chain_should_work() {
+  new_test_ext().execute_with(|| {
+    use frame_support::{
+      storage::migration::{get_storage_value, put_storage_value},
+      traits::OnRuntimeUpgrade,
+    };
+    use sp_io::hashing::twox_128;
+
+    let tx_hash = get_legal_tx_hash();
+    let eth_addr = get_legal_eth_addr();
+    // the storage of the single chain module, before any version was recorded
+    frame_support::storage::unhashed::kill_prefix(&twox_128(b"CloverClaims"));
+    put_storage_value(b"CloverClaims", b"BridgeAccount", &[], Some(1u64));
+    put_storage_value::<u64>(b"CloverClaims", b"ClaimLimit", &[], 500);
+    put_storage_value(b"CloverClaims", b"MintFee", &[], Some(10u64));
+    put_storage_value(b"CloverClaims", b"BurnFee", &[], None::<u64>);
+    put_storage_value(
+      b"CloverClaims",
+      b"Claims",
+      &tx_hash.using_encoded(Blake2_128Concat::hash),
+      Some((eth_addr.clone(), 100u64, false)),
+    );
+    assert_eq!(StorageVersion::<Test>::get(), Releases::V1_0_0);
+
+    CloverClaims::on_runtime_upgrade();
+
+    assert_eq!(StorageVersion::<Test>::get(), Releases::V8_0_0);
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 500);
+    assert_eq!(
+      CloverClaims::mint_fee(BSC, CLV),
+      Some(FeeSchedule::flat(10))
+    );
+    assert_eq!(CloverClaims::burn_fee(BSC, CLV), None);
+    assert_eq!(
+      claim_of(BSC, &tx_hash),
+      Some((eth_addr.clone(), 100, false))
+    );
+    // the bridge account relays the chain alone
+    assert!(get_storage_value::<Option<u64>>(b"CloverClaims", b"BridgeAccount", &[]).is_none());
+    assert_eq!(CloverClaims::relayers(BSC), vec![1]);
+    assert_eq!(CloverClaims::relayer_threshold(BSC), 1);
+    assert!(CloverClaims::chain_enabled(BSC));
+    let tx = EthereumTxHash([2; 32]);
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx,
+      eth_addr,
+      100,
+      None
+    ));
+    assert_eq!(claim_of(BSC, &tx), Some((eth_addr.clone(), 90, false)));
+
+    // the migration should only run once
+    assert_eq!(CloverClaims::on_runtime_upgrade(), 0);
+    assert_eq!(
+      claim_of(BSC, &tx_hash),
+      Some((eth_addr.clone(), 100, false))
+    );
+  });
+}
+
+#[test]
+fn migrate_to_fee_schedules_should_work() {
+  new_test_ext().execute_with(|| {
+    use frame_support::{storage::migration::put_storage_value, traits::OnRuntimeUpgrade};
+
+    // flat fees
+    put_storage_value(
+      b"CloverClaims",
+      b"MintFee",
+      &BSC.using_encoded(Twox64Concat::hash),
+      Some(10u64),
+    );
+    put_storage_value(
+      b"CloverClaims",
+      b"BurnFee",
+      &ETH.using_encoded(Twox64Concat::hash),
+      Some(5u64),
+    );
+    put_storage_value(
+      b"CloverClaims",
+      b"BurnFee",
+      &BSC.using_encoded(Twox64Concat::hash),
+      None::<u64>,
+    );
+    StorageVersion::<Test>::put(Releases::V2_0_0);
+
+    CloverClaims::on_runtime_upgrade();
+
+    assert_eq!(StorageVersion::<Test>::get(), Releases::V8_0_0);
+    assert_eq!(
+      CloverClaims::mint_fee(BSC, CLV),
+      Some(FeeSchedule::flat(10))
+    );
+    assert_eq!(CloverClaims::mint_fee(ETH, CLV), None);
+    assert_eq!(CloverClaims::burn_fee(ETH, CLV), Some(FeeSchedule::flat(5)));
+    assert_eq!(CloverClaims::burn_fee(BSC, CLV), None);
+    assert_eq!(CloverClaims::mint_fee_of(BSC, CLV, 100), 10);
+
+    // the migration should only run once
+    assert_eq!(CloverClaims::on_runtime_upgrade(), 0);
+    assert_eq!(
+      CloverClaims::mint_fee(BSC, CLV),
+      Some(FeeSchedule::flat(10))
+    );
+  });
+}
+
+#[test]
+fn migrate_to_multi_currency_should_work() {
+  new_test_ext().execute_with(|| {
+    use frame_support::{storage::migration::put_storage_value, traits::OnRuntimeUpgrade};
+
+    let tx_hash = get_legal_tx_hash();
+    let eth_addr = get_legal_eth_addr();
+    let chain_key = BSC.using_encoded(Twox64Concat::hash);
+    // chain scoped layout
+    put_storage_value(b"CloverClaims", b"ClaimLimit", &chain_key, 500u64);
+    put_storage_value(
+      b"CloverClaims",
+      b"MintFee",
+      &chain_key,
+      FeeSchedule::flat(10u64),
+    );
+    put_storage_value(
+      b"CloverClaims",
+      b"AddressMinted",
+      &[
+        chain_key.clone(),
+        eth_addr.using_encoded(Blake2_128Concat::hash),
+      ]
+      .concat(),
+      (0u64, 30u64),
+    );
+    let tx_key = [
+      chain_key.clone(),
+      tx_hash.using_encoded(Blake2_128Concat::hash),
+    ]
+    .concat();
+    put_storage_value(
+      b"CloverClaims",
+      b"Mints",
+      &tx_key,
+      (1u64, 101u64, vec![1u64], 10u64),
+    );
+    put_storage_value(
+      b"CloverClaims",
+      b"Attestations",
+      &tx_key,
+      vec![(2u64, eth_addr.clone(), 100u64)],
+    );
+    put_storage_value(
+      b"CloverClaims",
+      b"Burns",
+      &[chain_key.clone(), 0u64.using_encoded(Twox64Concat::hash)].concat(),
+      (
+        4u64,
+        eth_addr.clone(),
+        40u64,
+        0u64,
+        1u64,
+        BurnStatus::Pending,
+      ),
+    );
+    StorageVersion::<Test>::put(Releases::V3_0_0);
+
+    CloverClaims::on_runtime_upgrade();
+
+    assert_eq!(StorageVersion::<Test>::get(), Releases::V8_0_0);
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 500);
+    assert_eq!(CloverClaims::claim_limit(BSC, CETH), 0);
+    assert_eq!(
+      CloverClaims::mint_fee(BSC, CLV),
+      Some(FeeSchedule::flat(10))
+    );
+    assert_eq!(CloverClaims::mint_fee(BSC, CETH), None);
+    assert_eq!(AddressMinted::<Test>::get((BSC, CLV), &eth_addr), (0, 30));
+    assert_eq!(
+      CloverClaims::mints(BSC, &tx_hash),
+      Some(MintInfo {
+        currency_id: CLV,
+        block: 1,
+        expiry: 101,
+        relayers: vec![1],
+        fee: 10,
+      })
+    );
+    assert_eq!(
+      CloverClaims::attestations(BSC, &tx_hash),
+      vec![(2, CLV, eth_addr.clone(), 100, None)]
+    );
+    assert_eq!(
+      CloverClaims::burns(BSC, 0),
+      Some(BurnInfo {
+        who: 4,
+        currency_id: CLV,
+        dest: eth_addr,
+        amount: 40,
+        fee: 0,
+        block: 1,
+        status: BurnStatus::Pending,
+      })
+    );
+
+    // the migration should only run once
+    assert_eq!(CloverClaims::on_runtime_upgrade(), 0);
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 500);
+  });
+}
+
+#[test]
+fn migrate_to_claim_records_should_work() {
+  new_test_ext().execute_with(|| {
+    use frame_support::{storage::migration::put_storage_value, traits::OnRuntimeUpgrade};
+
+    let eth_addr = get_legal_eth_addr();
+    let tx_key = |tx: &EthereumTxHash| {
+      [
+        BSC.using_encoded(Twox64Concat::hash),
+        tx.using_encoded(Blake2_128Concat::hash),
+      ]
+      .concat()
+    };
+    let minted = EthereumTxHash([1; 32]);
+    let claimed = EthereumTxHash([2; 32]);
+    // claims as tuples, the second one minted before `MintInfo`
+    put_storage_value(
+      b"CloverClaims",
+      b"Claims",
+      &tx_key(&minted),
+      Some((eth_addr.clone(), 90u64, false)),
+    );
+    put_storage_value(
+      b"CloverClaims",
+      b"Claims",
+      &tx_key(&claimed),
+      Some((eth_addr.clone(), 100u64, true)),
+    );
+    Mints::<Test>::insert(
+      BSC,
+      minted,
+      MintInfo {
+        currency_id: CETH,
+        block: 3,
+        expiry: 103,
+        relayers: vec![1, 2],
+        fee: 10,
+      },
+    );
+    StorageVersion::<Test>::put(Releases::V4_0_0);
+
+    CloverClaims::on_runtime_upgrade();
+
+    assert_eq!(StorageVersion::<Test>::get(), Releases::V8_0_0);
+    assert_eq!(
+      CloverClaims::claims(BSC, &minted),
+      Some(ClaimRecord {
+        who: eth_addr.clone(),
+        amount: 90,
+        mint_block: 3,
+        relayer: Some(2),
+        fee: 10,
+        claimed: false,
+        claimed_by: None,
+        claim_block: None,
+      })
+    );
+    assert_eq!(
+      CloverClaims::claims(BSC, &claimed),
+      Some(ClaimRecord {
+        who: eth_addr.clone(),
+        amount: 100,
+        mint_block: 0,
+        relayer: None,
+        fee: 0,
+        claimed: true,
+        claimed_by: None,
+        claim_block: None,
+      })
+    );
+    assert_eq!(
+      CloverClaims::pending_claims(BSC, &eth_addr)
+        .into_iter()
+        .map(|(tx, details)| (tx, details.currency_id))
+        .collect::<Vec<_>>(),
+      vec![(minted, CETH)]
+    );
+
+    // the migration should only run once
+    assert_eq!(CloverClaims::on_runtime_upgrade(), 0);
+    assert_eq!(claim_of(BSC, &claimed), Some((eth_addr, 100, true)));
+  });
+}
+
+#[test]
+fn migrate_to_vested_attestations_should_work() {
+  new_test_ext().execute_with(|| {
+    use frame_support::{storage::migration::put_storage_value, traits::OnRuntimeUpgrade};
+
+    let tx_hash = get_legal_tx_hash();
+    let eth_addr = get_legal_eth_addr();
+    // attestations without vesting
+    put_storage_value(
+      b"CloverClaims",
+      b"Attestations",
+      &[
+        BSC.using_encoded(Twox64Concat::hash),
+        tx_hash.using_encoded(Blake2_128Concat::hash),
+      ]
+      .concat(),
+      vec![(2u64, CETH, eth_addr.clone(), 100u64)],
+    );
+    StorageVersion::<Test>::put(Releases::V5_0_0);
+
+    CloverClaims::on_runtime_upgrade();
+
+    assert_eq!(StorageVersion::<Test>::get(), Releases::V8_0_0);
+    assert_eq!(
+      CloverClaims::attestations(BSC, &tx_hash),
+      vec![(2, CETH, eth_addr, 100, None)]
+    );
+
+    // the migration should only run once
+    assert_eq!(CloverClaims::on_runtime_upgrade(), 0);
+  });
+}
+
+#[test]
+fn non_exist_tx_should_fail() {
+  new_test_ext().execute_with(|| {
+    let tx_hash = get_legal_tx_hash();
+    let sig = get_legal_eth_sig();
+    assert_noop!(
+      CloverClaims::claim(Origin::none(), 1, BSC, tx_hash, sig),
+      Error::<Test>::TxNotMinted
+    );
+  });
+}
+
+#[test]
+fn claim_with_recovery_id_forms_should_work() {
+  use crate::Call as ClaimsCall;
+  use frame_support::unsigned::ValidateUnsigned;
+  use sp_runtime::transaction_validity::TransactionSource;
+
+  new_test_ext().execute_with(|| {
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 100));
+    let tx_hash = get_legal_tx_hash();
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx_hash,
+      get_legal_eth_addr(),
+      100,
+      None
+    ));
+
+    // v = 0 is accepted as well as v = 27
+    let mut sig = get_legal_eth_sig();
+    sig.0[64] = 0;
+    assert!(CloverClaims::validate_unsigned(
+      TransactionSource::External,
+      &ClaimsCall::claim(1, BSC, tx_hash, sig.clone())
+    )
+    .is_ok());
+
+    // EIP-155 v of another chain is rejected
+    sig.0[64] = 2 + 35;
+    assert_noop!(
+      CloverClaims::claim(Origin::none(), 1, BSC, tx_hash, sig.clone()),
+      Error::<Test>::InvalidEthereumSignature
+    );
+
+    // EIP-155 v of the source chain
+    sig.0[64] = (BSC * 2 + 35) as u8;
+    assert_ok!(CloverClaims::claim(Origin::none(), 1, BSC, tx_hash, sig));
+    assert_eq!(Balances::free_balance(1), 100);
+  });
+}
+
+#[test]
+fn claim_typed_should_work() {
+  use crate::Call as ClaimsCall;
+  use frame_support::unsigned::ValidateUnsigned;
+  use sp_runtime::transaction_validity::TransactionSource;
+
+  new_test_ext().execute_with(|| {
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 100));
+    let tx_hash = get_legal_tx_hash();
+    let sig = get_typed_claim_sig();
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx_hash,
+      get_typed_claim_eth_addr(),
+      100,
+      None
+    ));
+
+    // signed for another dest
+    assert_noop!(
+      CloverClaims::claim_typed(Origin::none(), 2, BSC, tx_hash, sig.clone()),
+      Error::<Test>::SignatureNotMatch
+    );
+    // the personal_sign form is not interchangeable
+    assert_noop!(
+      CloverClaims::claim(Origin::none(), 1, BSC, tx_hash, get_legal_eth_sig()),
+      Error::<Test>::SignatureNotMatch
+    );
+
+    // EIP-2098 compact form of the same signature
+    let mut compact = [0u8; 64];
+    compact.copy_from_slice(&sig.to_standard(BSC).unwrap()[..64]);
+    let compact = EthereumSignature::Compact(compact);
+    assert!(CloverClaims::validate_unsigned(
+      TransactionSource::External,
+      &ClaimsCall::claim_typed(1, BSC, tx_hash, compact)
+    )
+    .is_ok());
+
+    assert_ok!(CloverClaims::claim_typed(
+      Origin::none(),
+      1,
+      BSC,
+      tx_hash,
+      sig.clone()
+    ));
+    assert_eq!(Balances::free_balance(1), 100);
+    assert_eq!(
+      claim_of(BSC, &tx_hash),
+      Some((get_typed_claim_eth_addr(), 100, true))
+    );
+    assert!(CloverClaims::validate_unsigned(
+      TransactionSource::External,
+      &ClaimsCall::claim_typed(1, BSC, tx_hash, sig)
+    )
+    .is_err());
+  });
+}
+
+#[test]
+fn illegal_sig_claim_should_fail() {
+  new_test_ext().execute_with(|| {
+    // only root account can add relayers
+    assert_noop!(
+      CloverClaims::add_relayer(Origin::signed(2), BSC, 1),
+      DispatchError::BadOrigin
+    );
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 100));
+
+    //  Mint a claim
+    let tx_hash = get_legal_tx_hash();
+    let eth_addr = get_legal_eth_addr();
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx_hash.clone(),
+      eth_addr.clone(),
+      100,
+      None
+    ));
+
+    // Claim it with illegal sig
+    let sig1 = get_another_account_eth_sig();
+    assert_noop!(
+      CloverClaims::claim(Origin::none(), 1, BSC, tx_hash.clone(), sig1.clone()),
+      Error::<Test>::SignatureNotMatch
+    );
+
+    // Sig with wrong message should failed
+    let sig2 = get_wrong_msg_eth_sig();
+    assert_noop!(
+      CloverClaims::claim(Origin::none(), 1, BSC, tx_hash.clone(), sig2.clone()),
+      Error::<Test>::SignatureNotMatch
+    );
+  });
+}
+
+#[test]
+fn mint_twice_should_fail() {
+  new_test_ext().execute_with(|| {
+    // Set miner and superior
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+
+    // Set limit
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 100));
+
+    // Mint a claim
+    let tx_hash = get_legal_tx_hash();
+    let eth_addr = get_legal_eth_addr();
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx_hash.clone(),
+      eth_addr.clone(),
+      100,
+      None
+    ));
+
+    // 3. Mint the same eth again
+    assert_noop!(
+      CloverClaims::mint_claim(
+        Origin::signed(1),
+        BSC,
+        CLV,
+        tx_hash.clone(),
+        eth_addr.clone(),
+        100,
+        None
+      ),
+      Error::<Test>::AlreadyMinted
+    );
+  });
+}
+
+#[test]
+fn claim_twice_should_fail() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+
+    // Set limit
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 100));
+
+    // Mint a claim
+    let tx_hash = get_legal_tx_hash();
+    let eth_addr = get_legal_eth_addr();
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx_hash.clone(),
+      eth_addr.clone(),
+      100,
+      None
+    ));
+
+    // Claim it
+    // Pay CLVs to the TEST account:0100000000000000
+    let sig = get_legal_eth_sig();
+    assert_eq!(Balances::free_balance(1), 0);
+    assert_ok!(CloverClaims::claim(
+      Origin::none(),
+      1,
+      BSC,
+      tx_hash.clone(),
+      sig.clone()
+    ));
+    assert_eq!(Balances::free_balance(1), 100);
+
+    // failed on the second try
+    assert_noop!(
+      CloverClaims::claim(Origin::none(), 1, BSC, tx_hash.clone(), sig.clone()),
+      Error::<Test>::AlreadyClaimed
+    );
+    assert_eq!(Balances::free_balance(1), 100);
+  });
+}
+
+#[test]
+fn claims_should_have_weight() {
+  use crate::Call as ClaimsCall;
+  use frame_support::weights::GetDispatchInfo;
+
+  let tx_hash = get_legal_tx_hash();
+  for call in vec![
+    ClaimsCall::<Test>::claim(1, BSC, tx_hash, get_legal_eth_sig()),
+    ClaimsCall::<Test>::claim_typed(1, BSC, tx_hash, EthereumSignature::Compact([0; 64])),
+    ClaimsCall::<Test>::claim_to_evm(H160::default(), BSC, tx_hash, get_legal_eth_sig()),
+  ] {
+    assert!(call.get_dispatch_info().weight >= CLAIM_SIGNATURE_WEIGHT);
+  }
+}
+
+#[test]
+fn claim_limit_should_work() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 2));
+
+    // Mint a claim should failed without limitation
+    let tx_hash = get_legal_tx_hash();
+    let eth_addr = get_legal_eth_addr();
+    assert_noop!(
+      CloverClaims::mint_claim(
+        Origin::signed(2),
+        BSC,
+        CLV,
+        tx_hash.clone(),
+        eth_addr.clone(),
+        10,
+        None
+      ),
+      Error::<Test>::ClaimLimitExceeded
+    );
+
+    // Set limit
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 10));
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 10);
+
+    // Claim amount with limitation should be ok
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(2),
+      BSC,
+      CLV,
+      tx_hash.clone(),
+      eth_addr.clone(),
+      10,
+      None
+    ));
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 0);
+  });
+}
+
+#[test]
+fn claim_window_should_refill_limit() {
+  new_test_ext().execute_with(|| {
+    System::set_block_number(1);
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_noop!(
+      CloverClaims::set_claim_window(Origin::root(), BSC, CLV, Some((100, 0))),
+      Error::<Test>::InvalidPeriod
+    );
+    assert_ok!(CloverClaims::set_claim_window(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some((100, 10))
+    ));
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 100);
+
+    let eth_addr = get_legal_eth_addr();
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      EthereumTxHash([1; 32]),
+      eth_addr,
+      80,
+      None
+    ));
+    assert_noop!(
+      CloverClaims::mint_claim(
+        Origin::signed(1),
+        BSC,
+        CLV,
+        EthereumTxHash([2; 32]),
+        eth_addr,
+        30,
+        None
+      ),
+      Error::<Test>::ClaimLimitExceeded
+    );
+
+    // not refilled before the window passed
+    CloverClaims::on_initialize(10);
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 20);
+    CloverClaims::on_initialize(11);
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 100);
+    assert_eq!(CloverClaims::claim_window(BSC, CLV).unwrap().start, 11);
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      EthereumTxHash([2; 32]),
+      eth_addr,
+      30,
+      None
+    ));
+
+    // stop refilling
+    assert_ok!(CloverClaims::set_claim_window(
+      Origin::root(),
+      BSC,
+      CLV,
+      None
+    ));
+    CloverClaims::on_initialize(30);
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 70);
+  });
+}
+
+#[test]
+fn claim_caps_should_work() {
+  new_test_ext().execute_with(|| {
+    System::set_block_number(1);
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::set_claim_limit(
+      Origin::root(),
+      BSC,
+      CLV,
+      1000
+    ));
+    assert_ok!(CloverClaims::set_max_claim_amount(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(50)
+    ));
+    assert_ok!(CloverClaims::set_address_daily_cap(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(80)
+    ));
+    let eth_addr = get_legal_eth_addr();
+    let other_addr = EthereumAddress([9; 20]);
+
+    assert_noop!(
+      CloverClaims::mint_claim(
+        Origin::signed(1),
+        BSC,
+        CLV,
+        EthereumTxHash([1; 32]),
+        eth_addr,
+        60,
+        None
+      ),
+      Error::<Test>::ClaimAmountTooLarge
+    );
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      EthereumTxHash([1; 32]),
+      eth_addr,
+      50,
+      None
+    ));
+    assert_eq!(
+      CloverClaims::remaining_claim_limit(BSC, CLV, Some(eth_addr)),
+      30
+    );
+    assert_eq!(
+      CloverClaims::remaining_claim_limit(BSC, CLV, Some(other_addr)),
+      80
+    );
+    assert_eq!(CloverClaims::remaining_claim_limit(BSC, CLV, None), 950);
+    assert_noop!(
+      CloverClaims::mint_claim(
+        Origin::signed(1),
+        BSC,
+        CLV,
+        EthereumTxHash([2; 32]),
+        eth_addr,
+        40,
+        None
+      ),
+      Error::<Test>::AddressCapExceeded
+    );
+    // other addresses are not affected
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      EthereumTxHash([2; 32]),
+      other_addr,
+      40,
+      None
+    ));
+
+    // the cap resets the next day
+    System::set_block_number(20);
+    assert_eq!(
+      CloverClaims::remaining_claim_limit(BSC, CLV, Some(eth_addr)),
+      80
+    );
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      EthereumTxHash([3; 32]),
+      eth_addr,
+      40,
+      None
+    ));
+
+    // caps can be removed
+    assert_ok!(CloverClaims::set_max_claim_amount(
+      Origin::root(),
+      BSC,
+      CLV,
+      None
+    ));
+    assert_ok!(CloverClaims::set_address_daily_cap(
+      Origin::root(),
+      BSC,
+      CLV,
+      None
+    ));
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      EthereumTxHash([4; 32]),
+      eth_addr,
+      200,
+      None
+    ));
+  });
+}
+
+#[test]
+fn revoke_claim_should_work() {
+  new_test_ext().execute_with(|| {
+    System::set_block_number(1);
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 100));
+    assert_ok!(CloverClaims::set_mint_fee(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(FeeSchedule::flat(10))
+    ));
+    let tx_hash = get_legal_tx_hash();
+    let eth_addr = get_legal_eth_addr();
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx_hash,
+      eth_addr,
+      60,
+      None
+    ));
+    assert_eq!(
+      CloverClaims::mints(BSC, &tx_hash),
+      Some(MintInfo {
+        currency_id: CLV,
+        block: 1,
+        expiry: 101,
+        relayers: vec![1],
+        fee: 10,
+      })
+    );
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 50);
+
+    assert_noop!(
+      CloverClaims::revoke_claim(Origin::signed(1), BSC, tx_hash, true),
+      DispatchError::BadOrigin
+    );
+    assert_noop!(
+      CloverClaims::revoke_claim(Origin::root(), BSC, tx_hash, true),
+      Error::<Test>::ClaimNotExpired
+    );
+
+    System::set_block_number(101);
+    assert_ok!(CloverClaims::revoke_claim(
+      Origin::root(),
+      BSC,
+      tx_hash,
+      true
+    ));
+    assert_eq!(claim_of(BSC, &tx_hash), None);
+    assert_eq!(CloverClaims::mints(BSC, &tx_hash), None);
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 100);
+    // the depositor is refunded on the source chain
+    let depositor = EvmAddressMapping::into_account_id(H160(eth_addr.0));
+    assert_eq!(
+      CloverClaims::burns(BSC, 0),
+      Some(BurnInfo {
+        who: depositor,
+        currency_id: CLV,
+        dest: eth_addr,
+        amount: 50,
+        fee: 0,
+        block: 101,
+        status: BurnStatus::Pending,
+      })
+    );
+    // or on Clover if the release fails, the fees are left alone
+    System::set_block_number(111);
+    assert_ok!(CloverClaims::refund_burn(Origin::root(), BSC, 0));
+    assert_eq!(Balances::free_balance(depositor), 50);
+    assert_eq!(Balances::free_balance(CloverClaims::account_id()), 10);
+
+    // can neither be claimed nor minted again
+    assert_noop!(
+      CloverClaims::claim(Origin::none(), 1, BSC, tx_hash, get_legal_eth_sig()),
+      Error::<Test>::TxNotMinted
+    );
+    assert_noop!(
+      CloverClaims::mint_claim(Origin::signed(1), BSC, CLV, tx_hash, eth_addr, 60, None),
+      Error::<Test>::AlreadyRevoked
+    );
+
+    // claimed mints can not be revoked
+    let tx_hash = EthereumTxHash([1; 32]);
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx_hash,
+      eth_addr,
+      60,
+      None
+    ));
+    assert_noop!(
+      CloverClaims::revoke_claim(Origin::root(), BSC, tx_hash, false),
+      Error::<Test>::ClaimNotExpired
+    );
+    Claims::<Test>::mutate(BSC, tx_hash, |record| {
+      record.as_mut().unwrap().claimed = true
+    });
+    System::set_block_number(201);
+    assert_noop!(
+      CloverClaims::revoke_claim(Origin::root(), BSC, tx_hash, false),
+      Error::<Test>::AlreadyClaimed
+    );
+  });
+}
+
+#[test]
+fn revoke_claim_with_refund_should_keep_outstanding() {
+  new_test_ext().execute_with(|| {
+    System::set_block_number(1);
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::set_claim_limit(
+      Origin::root(),
+      BSC,
+      CLV,
+      1000
+    ));
+    let eth_addr = get_legal_eth_addr();
+    let revoked = EthereumTxHash([1; 32]);
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      revoked,
+      eth_addr,
+      100,
+      None
+    ));
+
+    System::set_block_number(101);
+    assert_ok!(CloverClaims::revoke_claim(
+      Origin::root(),
+      BSC,
+      revoked,
+      true
+    ));
+    // the refund burn releases the deposit, nothing is left locked for it
+    assert_eq!(
+      CloverClaims::bridged_supply(BSC, CLV),
+      BridgedSupply {
+        minted: 100,
+        claimed: 100,
+        burned: 100,
+      }
+    );
+    assert_eq!(CloverClaims::bridged_supply(BSC, CLV).outstanding(), 0);
+
+    // so later claims are outstanding in full
+    let tx_hash = get_legal_tx_hash();
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx_hash,
+      eth_addr,
+      60,
+      None
+    ));
+    assert_ok!(CloverClaims::claim(
+      Origin::none(),
+      1,
+      BSC,
+      tx_hash,
+      get_legal_eth_sig()
+    ));
+    assert_eq!(CloverClaims::bridged_supply(BSC, CLV).outstanding(), 60);
+
+    // revoked without refund, the deposit stays locked and is not counted
+    let kept = EthereumTxHash([2; 32]);
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      kept,
+      eth_addr,
+      30,
+      None
+    ));
+    System::set_block_number(201);
+    assert_ok!(CloverClaims::revoke_claim(Origin::root(), BSC, kept, false));
+    assert_eq!(
+      CloverClaims::bridged_supply(BSC, CLV),
+      BridgedSupply {
+        minted: 160,
+        claimed: 160,
+        burned: 100,
+      }
+    );
+  });
+}
+
+#[test]
+fn void_mint_should_work() {
+  new_test_ext().execute_with(|| {
+    for relayer in 1..=3 {
+      assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, relayer));
+    }
+    assert_ok!(CloverClaims::set_relayer_threshold(Origin::root(), BSC, 2));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 100));
+    assert_ok!(CloverClaims::set_mint_fee(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(FeeSchedule::flat(10))
+    ));
+    let eth_addr = get_legal_eth_addr();
+    let mint = |tx: EthereumTxHash| {
+      for relayer in 1..=2 {
+        assert_ok!(CloverClaims::mint_claim(
+          Origin::signed(relayer),
+          BSC,
+          CLV,
+          tx,
+          eth_addr,
+          60,
+          None
+        ));
+      }
+    };
+    let tx_hash = EthereumTxHash([1; 32]);
+    mint(tx_hash);
+    assert_eq!(Balances::free_balance(CloverClaims::account_id()), 10);
+
+    // only relayers of the chain can vote
+    assert_noop!(
+      CloverClaims::void_mint(Origin::signed(4), BSC, tx_hash),
+      Error::<Test>::NoPermission
+    );
+    assert_noop!(
+      CloverClaims::void_mint(Origin::signed(1), BSC, EthereumTxHash([9; 32])),
+      Error::<Test>::TxNotMinted
+    );
+
+    // one vote is not enough
+    assert_ok!(CloverClaims::void_mint(Origin::signed(1), BSC, tx_hash));
+    assert_eq!(CloverClaims::void_votes(BSC, &tx_hash), vec![1]);
+    assert_eq!(claim_of(BSC, &tx_hash), Some((eth_addr, 50, false)));
+    assert_noop!(
+      CloverClaims::void_mint(Origin::signed(1), BSC, tx_hash),
+      Error::<Test>::AlreadyVoted
+    );
+
+    assert_ok!(CloverClaims::void_mint(Origin::signed(3), BSC, tx_hash));
+    assert_eq!(claim_of(BSC, &tx_hash), None);
+    assert!(CloverClaims::void_votes(BSC, &tx_hash).is_empty());
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 100);
+    assert_eq!(Balances::free_balance(CloverClaims::account_id()), 0);
+
+    // the voided tx can not be minted again
+    assert_eq!(CloverClaims::revoked_claims(BSC, &tx_hash), Some(()));
+    assert_noop!(
+      CloverClaims::mint_claim(Origin::signed(1), BSC, CLV, tx_hash, eth_addr, 50, None),
+      Error::<Test>::AlreadyRevoked
+    );
+
+    // the admin origin voids at once
+    let tx_hash = EthereumTxHash([2; 32]);
+    mint(tx_hash);
+    assert_ok!(CloverClaims::void_mint(Origin::root(), BSC, tx_hash));
+    assert_eq!(claim_of(BSC, &tx_hash), None);
+    assert_eq!(CloverClaims::revoked_claims(BSC, &tx_hash), Some(()));
+
+    // claimed mints can not be voided
+    let tx_hash = get_legal_tx_hash();
+    mint(tx_hash);
+    assert_ok!(CloverClaims::claim(
+      Origin::none(),
+      1,
+      BSC,
+      tx_hash,
+      get_legal_eth_sig()
+    ));
+    assert_noop!(
+      CloverClaims::void_mint(Origin::signed(2), BSC, tx_hash),
+      Error::<Test>::AlreadyClaimed
+    );
+    assert_noop!(
+      CloverClaims::void_mint(Origin::root(), BSC, tx_hash),
+      Error::<Test>::AlreadyClaimed
+    );
+  });
+}
+
+#[test]
+fn void_mint_after_sweep_should_keep_rewards() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 2));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 200));
+    assert_ok!(CloverClaims::set_mint_fee(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(FeeSchedule::flat(10))
+    ));
+    assert_ok!(CloverClaims::set_relayer_reward_share(
+      Origin::root(),
+      BSC,
+      Permill::from_percent(50)
+    ));
+    let eth_addr = get_legal_eth_addr();
+    let mint = |tx: EthereumTxHash| {
+      assert_ok!(CloverClaims::mint_claim(
+        Origin::signed(2),
+        BSC,
+        CLV,
+        tx,
+        eth_addr,
+        60,
+        None
+      ));
+    };
+    let tx_hash = get_legal_tx_hash();
+    mint(tx_hash);
+    assert_ok!(CloverClaims::claim(
+      Origin::none(),
+      1,
+      BSC,
+      tx_hash,
+      get_legal_eth_sig()
+    ));
+    assert_eq!(CloverClaims::unclaimed_rewards(CLV), 5);
+
+    // the fee of the voided mint was swept, the rewards of the relayer are left alone
+    let swept = EthereumTxHash([1; 32]);
+    mint(swept);
+    assert_eq!(Balances::free_balance(CloverClaims::account_id()), 20);
+    assert_ok!(CloverClaims::sweep_fees(Origin::root(), CLV));
+    assert_eq!(Balances::free_balance(CloverClaims::account_id()), 5);
+    assert_ok!(CloverClaims::void_mint(Origin::root(), BSC, swept));
+    assert_eq!(Balances::free_balance(CloverClaims::account_id()), 5);
+    assert_eq!(CloverClaims::fees_collected(BSC, CLV), 20);
+
+    // the fee which is still held is reversed
+    let voided = EthereumTxHash([2; 32]);
+    mint(voided);
+    assert_eq!(Balances::free_balance(CloverClaims::account_id()), 15);
+    assert_ok!(CloverClaims::void_mint(Origin::root(), BSC, voided));
+    assert_eq!(Balances::free_balance(CloverClaims::account_id()), 5);
+    assert_eq!(CloverClaims::fees_collected(BSC, CLV), 20);
+
+    assert_ok!(CloverClaims::claim_relayer_rewards(Origin::signed(2), CLV));
+    assert_eq!(Balances::free_balance(2), 5);
+    assert_eq!(CloverClaims::unclaimed_rewards(CLV), 0);
+  });
+}
+
+#[test]
+fn burn_should_work() {
+  new_test_ext().execute_with(|| {
+    let eth_addr = get_legal_eth_addr();
+
+    // should burn balance from account 4
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(4),
+      BSC,
+      CLV,
+      eth_addr,
+      40
+    ));
+    assert_eq!(Balances::free_balance(4), 60);
+
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(4),
+      BSC,
+      CLV,
+      eth_addr,
+      40
+    ));
+    assert_eq!(Balances::free_balance(4), 20);
+
+    // should failed if burn all the balances from an account
+    assert_noop!(
+      CloverClaims::burn(Origin::signed(5), BSC, CLV, eth_addr, 100),
+      BalancesError::<Test, _>::KeepAlive
+    );
+    assert_eq!(Balances::free_balance(5), 100);
+  });
+}
+
+#[test]
+fn burn_fee_should_work() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(CloverClaims::set_burn_fee(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(FeeSchedule::flat(30))
+    ));
+    // set relayer
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::set_claim_limit(
+      Origin::root(),
+      BSC,
+      CLV,
+      2000
+    ));
+
+    let eth_addr = get_legal_eth_addr();
+
+    // burn should failed if there is not enough balance to pay fee
+    assert_noop!(
+      CloverClaims::burn(Origin::signed(4), BSC, CLV, eth_addr, 20),
+      Error::<Test>::InvalidAmount
+    );
+    assert_eq!(Balances::free_balance(4), 100);
+
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(5),
+      BSC,
+      CLV,
+      eth_addr,
+      40
+    ));
+    assert_eq!(Balances::free_balance(5), 60);
+
+    // module has the fee deposited
+    assert_eq!(Balances::free_balance(CloverClaims::account_id()), 30);
+  });
+}
+
+#[test]
+fn sweep_fees_should_work() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 100));
+    assert_ok!(CloverClaims::set_mint_fee(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(FeeSchedule::flat(10))
+    ));
+    assert_ok!(CloverClaims::set_burn_fee(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(FeeSchedule::flat(5))
+    ));
+    let eth_addr = get_legal_eth_addr();
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      get_legal_tx_hash(),
+      eth_addr,
+      60,
+      None
+    ));
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(4),
+      BSC,
+      CLV,
+      eth_addr,
+      20
+    ));
+    assert_eq!(CloverClaims::fees_collected(BSC, CLV), 15);
+    assert_eq!(CloverClaims::fees_collected(ETH, CLV), 0);
+    assert_eq!(Balances::free_balance(CloverClaims::account_id()), 15);
+
+    assert_noop!(
+      CloverClaims::sweep_fees(Origin::signed(1), CLV),
+      DispatchError::BadOrigin
+    );
+    assert_ok!(CloverClaims::sweep_fees(Origin::root(), CLV));
+    assert_eq!(Balances::free_balance(CloverClaims::account_id()), 0);
+    assert_eq!(Balances::free_balance(TREASURY), 15);
+    // the collected total is kept
+    assert_eq!(CloverClaims::fees_collected(BSC, CLV), 15);
+
+    // nothing to sweep
+    assert_ok!(CloverClaims::sweep_fees(Origin::root(), CLV));
+    assert_eq!(Balances::free_balance(TREASURY), 15);
+  });
+}
+
+#[test]
+fn relayer_rewards_should_work() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 2));
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 3));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 100));
+    assert_ok!(CloverClaims::set_mint_fee(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(FeeSchedule::flat(10))
+    ));
+    assert_ok!(CloverClaims::set_burn_fee(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(FeeSchedule::flat(10))
+    ));
+    assert_noop!(
+      CloverClaims::set_relayer_reward_share(Origin::signed(2), BSC, Permill::from_percent(50)),
+      DispatchError::BadOrigin
+    );
+    assert_ok!(CloverClaims::set_relayer_reward_share(
+      Origin::root(),
+      BSC,
+      Permill::from_percent(50)
+    ));
+    assert_eq!(
+      CloverClaims::relayer_reward_share(BSC),
+      Permill::from_percent(50)
+    );
+
+    let tx_hash = get_legal_tx_hash();
+    let eth_addr = get_legal_eth_addr();
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(3),
+      BSC,
+      CLV,
+      tx_hash,
+      eth_addr,
+      60,
+      None
+    ));
+    // rewarded once claimed
+    assert_eq!(
+      CloverClaims::relayer_rewards(3, CLV),
+      RewardLedger::default()
+    );
+    assert_ok!(CloverClaims::claim(
+      Origin::none(),
+      1,
+      BSC,
+      tx_hash,
+      get_legal_eth_sig()
+    ));
+    assert_eq!(
+      CloverClaims::relayer_rewards(3, CLV),
+      RewardLedger {
+        earned: 5,
+        claimed: 0,
+      }
+    );
+
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(4),
+      BSC,
+      CLV,
+      eth_addr,
+      20
+    ));
+    assert_ok!(CloverClaims::confirm_burn(
+      Origin::signed(2),
+      BSC,
+      0,
+      EthereumTxHash([1; 32])
+    ));
+    assert_eq!(
+      CloverClaims::relayer_ledger(&2),
+      vec![(
+        CLV,
+        RewardLedger {
+          earned: 5,
+          claimed: 0,
+        }
+      )]
+    );
+    assert_eq!(CloverClaims::unclaimed_rewards(CLV), 10);
+
+    // the rewards are not swept
+    assert_eq!(Balances::free_balance(CloverClaims::account_id()), 20);
+    assert_ok!(CloverClaims::sweep_fees(Origin::root(), CLV));
+    assert_eq!(Balances::free_balance(TREASURY), 10);
+    assert_eq!(Balances::free_balance(CloverClaims::account_id()), 10);
+
+    assert_ok!(CloverClaims::claim_relayer_rewards(Origin::signed(3), CLV));
+    assert_eq!(Balances::free_balance(3), 5);
+    assert_eq!(
+      CloverClaims::relayer_rewards(3, CLV),
+      RewardLedger {
+        earned: 5,
+        claimed: 5,
+      }
+    );
+    assert_noop!(
+      CloverClaims::claim_relayer_rewards(Origin::signed(3), CLV),
+      Error::<Test>::NoRewards
+    );
+    assert_noop!(
+      CloverClaims::claim_relayer_rewards(Origin::signed(2), CETH),
+      Error::<Test>::NoRewards
+    );
+
+    assert_ok!(CloverClaims::claim_relayer_rewards(Origin::signed(2), CLV));
+    assert_eq!(Balances::free_balance(2), 5);
+    assert_eq!(CloverClaims::unclaimed_rewards(CLV), 0);
+    assert_eq!(Balances::free_balance(CloverClaims::account_id()), 0);
+  });
+}
+
+#[test]
+fn fee_schedule_should_work() {
+  new_test_ext().execute_with(|| {
+    let schedule = FeeSchedule {
+      rate: Permill::from_percent(1),
+      min: 2,
+      max: Some(50),
+      tiers: vec![
+        (1000, Permill::from_percent(2)),
+        (4000, Permill::from_percent(1)),
+      ],
+    };
+    // minimum fee
+    assert_eq!(schedule.fee(60), 2);
+    assert_eq!(schedule.fee(500), 5);
+    // tiers
+    assert_eq!(schedule.fee(1000), 20);
+    assert_eq!(schedule.fee(2000), 40);
+    assert_eq!(schedule.fee(4000), 40);
+    // maximum fee
+    assert_eq!(schedule.fee(3000), 50);
+    assert_eq!(schedule.fee(10000), 50);
+    assert_eq!(FeeSchedule::flat(10u64).fee(1), 10);
+    assert_eq!(FeeSchedule::flat(10u64).fee(10000), 10);
+
+    // invalid schedules
+    assert_noop!(
+      CloverClaims::set_mint_fee(
+        Origin::root(),
+        BSC,
+        CLV,
+        Some(FeeSchedule {
+          min: 60,
+          ..schedule.clone()
+        })
+      ),
+      Error::<Test>::InvalidFeeSchedule
+    );
+    assert_noop!(
+      CloverClaims::set_burn_fee(
+        Origin::root(),
+        BSC,
+        CLV,
+        Some(FeeSchedule {
+          tiers: vec![
+            (1000, Permill::from_percent(2)),
+            (1000, Permill::from_percent(1))
+          ],
+          ..schedule.clone()
+        })
+      ),
+      Error::<Test>::InvalidFeeSchedule
+    );
+    assert_noop!(
+      CloverClaims::set_mint_fee(Origin::signed(1), BSC, CLV, Some(schedule.clone())),
+      DispatchError::BadOrigin
+    );
+
+    assert_eq!(CloverClaims::mint_fee_of(BSC, CLV, 2000), 0);
+    assert_ok!(CloverClaims::set_mint_fee(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(schedule.clone())
+    ));
+    assert_ok!(CloverClaims::set_burn_fee(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(FeeSchedule {
+        rate: Permill::from_percent(10),
+        min: 0,
+        max: None,
+        tiers: vec![],
+      })
+    ));
+    assert_eq!(CloverClaims::mint_fee(BSC, CLV), Some(schedule.clone()));
+    assert_eq!(CloverClaims::mint_fee_of(BSC, CLV, 2000), 40);
+    assert_eq!(CloverClaims::burn_fee_of(BSC, CLV, 50), 5);
+    assert_eq!(CloverClaims::mint_fee_of(ETH, CLV, 2000), 0);
+
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::set_claim_limit(
+      Origin::root(),
+      BSC,
+      CLV,
+      10000
+    ));
+    let eth_addr = get_legal_eth_addr();
+    let tx_hash = get_legal_tx_hash();
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx_hash,
+      eth_addr,
+      2000,
+      None
+    ));
+    assert_eq!(
+      claim_of(BSC, &tx_hash),
+      Some((eth_addr.clone(), 1960, false))
+    );
+    // the fee is not less than the amount
+    assert_noop!(
+      CloverClaims::mint_claim(
+        Origin::signed(1),
+        BSC,
+        CLV,
+        EthereumTxHash([1; 32]),
+        eth_addr,
+        2,
+        None
+      ),
+      Error::<Test>::InvalidAmount
+    );
+
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(4),
+      BSC,
+      CLV,
+      eth_addr,
+      50
+    ));
+    assert_eq!(Balances::free_balance(4), 50);
+    assert_eq!(CloverClaims::fees_collected(BSC, CLV), 45);
+
+    // remove the fee
+    assert_ok!(CloverClaims::set_mint_fee(Origin::root(), BSC, CLV, None));
+    assert_eq!(CloverClaims::mint_fee(BSC, CLV), None);
+    assert_eq!(CloverClaims::mint_fee_of(BSC, CLV, 2000), 0);
+  });
+}
+
+#[test]
+fn multi_currency_should_work() {
+  new_test_ext().execute_with(|| {
+    System::set_block_number(1);
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::set_claim_limit(
+      Origin::root(),
+      BSC,
+      CETH,
+      100
+    ));
+    assert_ok!(CloverClaims::set_mint_fee(
+      Origin::root(),
+      BSC,
+      CETH,
+      Some(FeeSchedule::flat(10))
+    ));
+    assert_ok!(CloverClaims::set_burn_fee(
+      Origin::root(),
+      BSC,
+      CETH,
+      Some(FeeSchedule::flat(5))
+    ));
+    let eth_addr = get_legal_eth_addr();
+    let tx_hash = get_legal_tx_hash();
+
+    // the limits of currencies are not shared
+    assert_eq!(CloverClaims::remaining_claim_limit(BSC, CETH, None), 100);
+    assert_eq!(CloverClaims::remaining_claim_limit(BSC, CLV, None), 0);
+    assert_noop!(
+      CloverClaims::mint_claim(Origin::signed(1), BSC, CLV, tx_hash, eth_addr, 60, None),
+      Error::<Test>::ClaimLimitExceeded
+    );
+
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CETH,
+      tx_hash,
+      eth_addr,
+      60,
+      None
+    ));
+    assert_eq!(CloverClaims::claim_limit(BSC, CETH), 50);
+    assert_eq!(
+      CloverClaims::mints(BSC, &tx_hash).map(|mint| mint.currency_id),
+      Some(CETH)
+    );
+    assert_eq!(CloverClaims::fees_collected(BSC, CETH), 10);
+    assert_eq!(CloverClaims::fees_collected(BSC, CLV), 0);
+    assert_eq!(Tokens::free_balance(CETH, &CloverClaims::account_id()), 10);
+
+    // claimed in the minted currency
+    assert_ok!(CloverClaims::claim(
+      Origin::none(),
+      1,
+      BSC,
+      tx_hash,
+      get_legal_eth_sig()
+    ));
+    assert_eq!(Tokens::free_balance(CETH, &1), 50);
+    assert_eq!(Balances::free_balance(1), 0);
+
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(4),
+      BSC,
+      CETH,
+      eth_addr,
+      40
+    ));
+    assert_eq!(Tokens::free_balance(CETH, &4), 60);
+    assert_eq!(Balances::free_balance(4), 100);
+    assert_eq!(
+      CloverClaims::burns(BSC, 0).map(|burn| (burn.currency_id, burn.amount, burn.fee)),
+      Some((CETH, 35, 5))
+    );
+    assert_eq!(CloverClaims::fees_collected(BSC, CETH), 15);
+    assert_noop!(
+      CloverClaims::burn(Origin::signed(4), BSC, CETH, eth_addr, 100),
+      orml_tokens::Error::<Test>::BalanceTooLow
+    );
+
+    // refunded in the burned currency
+    System::set_block_number(11);
+    assert_ok!(CloverClaims::refund_burn(Origin::signed(4), BSC, 0));
+    assert_eq!(Tokens::free_balance(CETH, &4), 95);
+
+    // token fees are swept to the token fee destination
+    assert_ok!(CloverClaims::sweep_fees(Origin::root(), CETH));
+    assert_eq!(Tokens::free_balance(CETH, &CloverClaims::account_id()), 0);
+    assert_eq!(Tokens::free_balance(CETH, &TREASURY), 15);
+    assert_eq!(Balances::free_balance(TREASURY), 0);
+
+    // attestations of another currency are not counted
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 2));
+    assert_ok!(CloverClaims::set_relayer_threshold(Origin::root(), BSC, 2));
+    let tx_hash = EthereumTxHash([1; 32]);
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CETH,
+      tx_hash,
+      eth_addr,
+      20,
+      None
+    ));
+    assert_noop!(
+      CloverClaims::mint_claim(Origin::signed(2), BSC, CLV, tx_hash, eth_addr, 20, None),
+      Error::<Test>::ConflictingAttestation
+    );
+    assert_eq!(claim_of(BSC, &tx_hash), None);
+    assert_eq!(CloverClaims::attestations(BSC, &tx_hash).len(), 1);
+  });
+}
+
+#[test]
+fn burn_queue_should_work() {
+  new_test_ext().execute_with(|| {
+    System::set_block_number(1);
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    let eth_addr = get_legal_eth_addr();
+    let tx_hash = get_legal_tx_hash();
+
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(4),
+      BSC,
+      CLV,
+      eth_addr,
+      10
+    ));
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(5),
+      BSC,
+      CLV,
+      eth_addr,
+      20
+    ));
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(4),
+      BSC,
+      CLV,
+      eth_addr,
+      30
+    ));
+    assert_eq!(CloverClaims::next_burn_nonce(BSC), 3);
+    assert_eq!(
+      CloverClaims::burns(BSC, 1),
+      Some(BurnInfo {
+        who: 5,
+        currency_id: CLV,
+        dest: eth_addr,
+        amount: 20,
+        fee: 0,
+        block: 1,
+        status: BurnStatus::Pending,
+      })
+    );
+
+    // only relayers of the chain can confirm
+    assert_noop!(
+      CloverClaims::confirm_burn(Origin::signed(2), BSC, 1, tx_hash),
+      Error::<Test>::NoPermission
+    );
+    assert_noop!(
+      CloverClaims::confirm_burn(Origin::signed(1), BSC, 3, tx_hash),
+      Error::<Test>::BurnNotFound
+    );
+    assert_ok!(CloverClaims::confirm_burn(
+      Origin::signed(1),
+      BSC,
+      1,
+      tx_hash
+    ));
+    assert_eq!(
+      CloverClaims::burns(BSC, 1).unwrap().status,
+      BurnStatus::Relayed(tx_hash)
+    );
+    assert_noop!(
+      CloverClaims::confirm_burn(Origin::signed(1), BSC, 1, tx_hash),
+      Error::<Test>::BurnNotPending
+    );
+
+    let pending = CloverClaims::pending_burns(BSC, 0, 10);
+    assert_eq!(
+      pending.iter().map(|(n, _)| *n).collect::<Vec<_>>(),
+      vec![0, 2]
+    );
+    assert_eq!(CloverClaims::pending_burns(BSC, 1, 1)[0].0, 2);
+    assert_eq!(CloverClaims::pending_burns(ETH, 0, 10), vec![]);
+
+    // the cursor moves past the burns which are no longer pending
+    assert_eq!(CloverClaims::lowest_pending_burn(BSC), 0);
+    assert_ok!(CloverClaims::confirm_burn(
+      Origin::signed(1),
+      BSC,
+      0,
+      EthereumTxHash([2; 32])
+    ));
+    assert_eq!(CloverClaims::lowest_pending_burn(BSC), 2);
+    assert_eq!(
+      CloverClaims::pending_burns(BSC, 0, 10)
+        .iter()
+        .map(|(n, _)| *n)
+        .collect::<Vec<_>>(),
+      vec![2]
+    );
+
+    // a cursor left behind catches up
+    LowestPendingBurn::<Test>::remove(BSC);
+    assert_ok!(CloverClaims::confirm_burns(
+      Origin::signed(1),
+      BSC,
+      vec![(2, EthereumTxHash([3; 32]))]
+    ));
+    assert_eq!(CloverClaims::lowest_pending_burn(BSC), 3);
+    assert_eq!(CloverClaims::pending_burns(BSC, 0, 10), vec![]);
+  });
+}
+
+#[test]
+fn refund_burn_should_work() {
+  new_test_ext().execute_with(|| {
+    System::set_block_number(1);
+    let eth_addr = get_legal_eth_addr();
+    assert_ok!(CloverClaims::set_burn_fee(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(FeeSchedule::flat(5))
+    ));
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(4),
+      BSC,
+      CLV,
+      eth_addr,
+      30
+    ));
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(4),
+      BSC,
+      CLV,
+      eth_addr,
+      20
+    ));
+    assert_eq!(Balances::free_balance(4), 50);
+
+    // refund is not available before the delay
+    assert_noop!(
+      CloverClaims::refund_burn(Origin::signed(4), BSC, 0),
+      Error::<Test>::RefundNotAvailable
+    );
+    assert_noop!(
+      CloverClaims::refund_burn(Origin::root(), BSC, 0),
+      Error::<Test>::RefundNotAvailable
+    );
+
+    System::set_block_number(11);
+    // only the burner or root can refund
+    assert_noop!(
+      CloverClaims::refund_burn(Origin::signed(5), BSC, 0),
+      Error::<Test>::NoPermission
+    );
+    assert_ok!(CloverClaims::refund_burn(Origin::signed(4), BSC, 0));
+    // the fee is not refunded
+    assert_eq!(Balances::free_balance(4), 75);
+    assert_eq!(
+      CloverClaims::burns(BSC, 0).unwrap().status,
+      BurnStatus::Refunded
+    );
+    assert_eq!(CloverClaims::lowest_pending_burn(BSC), 1);
+    assert_noop!(
+      CloverClaims::refund_burn(Origin::signed(4), BSC, 0),
+      Error::<Test>::BurnNotPending
+    );
+
+    assert_ok!(CloverClaims::refund_burn(Origin::root(), BSC, 1));
+    assert_eq!(Balances::free_balance(4), 90);
+    assert_eq!(CloverClaims::lowest_pending_burn(BSC), 2);
+    assert_eq!(CloverClaims::pending_burns(BSC, 0, 10), vec![]);
+  });
+}
+
+/// The contract of CLV on BSC, which verifies the burn certificates
+const BSC_CLV_TOKEN: H160 = H160([0xc1; 20]);
+
+/// The test externalities with a keystore holding the bridge keys of the seeds
+fn bridge_test_ext(seeds: &[&str]) -> (sp_io::TestExternalities, Vec<ecdsa::AuthorityId>) {
+  use sp_keystore::{testing::KeyStore, KeystoreExt, SyncCryptoStore};
+  use std::sync::Arc;
+
+  let keystore = KeyStore::new();
+  let keys = seeds
+    .iter()
+    .map(|seed| {
+      SyncCryptoStore::ecdsa_generate_new(&keystore, BRIDGE_KEY_TYPE, Some(&format!("//{}", seed)))
+        .unwrap()
+        .into()
+    })
+    .collect();
+  let mut ext = new_test_ext();
+  ext.register_extension(KeystoreExt(Arc::new(keystore)));
+  ext.execute_with(|| {
+    assert_ok!(CloverClaims::set_deposit_contract(
+      Origin::root(),
+      BSC,
+      BSC_CLV_TOKEN,
+      Some(CLV)
+    ));
+  });
+  (ext, keys)
+}
+
+/// Start a session whose validators have the bridge keys
+fn new_bridge_session(keys: &[ecdsa::AuthorityId]) {
+  let validators = keys
+    .iter()
+    .enumerate()
+    .map(|(i, key)| (i as u64, key.clone()))
+    .collect::<Vec<_>>();
+  let session = || validators.iter().map(|(v, key)| (v, key.clone()));
+  <CloverClaims as OneSessionHandler<u64>>::on_new_session(true, session(), session());
+}
+
+/// Signature of the key over the burn certificate message of the burn
+fn sign_burn(
+  key: &ecdsa::AuthorityId,
+  chain_id: ChainId,
+  nonce: BurnNonce,
+) -> ecdsa::AuthoritySignature {
+  let burn = CloverClaims::burns(chain_id, nonce).unwrap();
+  let message = CloverClaims::burn_certificate_message(chain_id, nonce, &burn).unwrap();
+  sp_io::crypto::ecdsa_sign_prehashed(BRIDGE_KEY_TYPE, key.as_ref(), &message)
+    .unwrap()
+    .into()
+}
+
+/// The ethereum address of the bridge key
+fn bridge_key_address(key: &ecdsa::AuthorityId) -> EthereumAddress {
+  let public = secp256k1::PublicKey::parse_compressed(&key.as_ref().0).unwrap();
+  let mut address = [0u8; 20];
+  address.copy_from_slice(&keccak_256(&public.serialize()[1..])[12..]);
+  EthereumAddress(address)
+}
+
+#[test]
+fn burn_certificate_should_work() {
+  use crate::Call as ClaimsCall;
+  use frame_support::unsigned::ValidateUnsigned;
+  use sp_runtime::transaction_validity::{InvalidTransaction, TransactionSource};
+
+  let (mut ext, keys) = bridge_test_ext(&["Alice", "Bob", "Charlie", "Dave", "Eve"]);
+  ext.execute_with(|| {
+    System::set_block_number(1);
+    new_bridge_session(&keys[..4]);
+    assert_eq!(CloverClaims::bridge_authority_set_id(), 1);
+    assert_eq!(CloverClaims::certificate_threshold(4), 3);
+
+    let eth_addr = get_legal_eth_addr();
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(4),
+      BSC,
+      CLV,
+      eth_addr,
+      30
+    ));
+
+    // only the authorities of the session with a signature over the burn
+    assert_noop!(
+      CloverClaims::submit_burn_signature(
+        Origin::none(),
+        BSC,
+        0,
+        keys[4].clone(),
+        sign_burn(&keys[4], BSC, 0)
+      ),
+      Error::<Test>::NotBridgeAuthority
+    );
+    assert_noop!(
+      CloverClaims::submit_burn_signature(
+        Origin::none(),
+        BSC,
+        0,
+        keys[0].clone(),
+        sign_burn(&keys[1], BSC, 0)
+      ),
+      Error::<Test>::InvalidBurnSignature
+    );
+    assert_noop!(
+      CloverClaims::submit_burn_signature(
+        Origin::none(),
+        BSC,
+        1,
+        keys[0].clone(),
+        sign_burn(&keys[0], BSC, 0)
+      ),
+      Error::<Test>::BurnNotCertifiable
+    );
+
+    for key in &keys[..2] {
+      assert_ok!(CloverClaims::submit_burn_signature(
+        Origin::none(),
+        BSC,
+        0,
+        key.clone(),
+        sign_burn(key, BSC, 0)
+      ));
+    }
+    assert_noop!(
+      CloverClaims::submit_burn_signature(
+        Origin::none(),
+        BSC,
+        0,
+        keys[0].clone(),
+        sign_burn(&keys[0], BSC, 0)
+      ),
+      Error::<Test>::BurnAlreadySigned
+    );
+    assert_eq!(CloverClaims::burn_signatures(BSC, 0).len(), 2);
+    assert_eq!(CloverClaims::burn_certificate(BSC, 0), None);
+
+    // the third of four authorities certifies the burn
+    assert_ok!(CloverClaims::submit_burn_signature(
+      Origin::none(),
+      BSC,
+      0,
+      keys[2].clone(),
+      sign_burn(&keys[2], BSC, 0)
+    ));
+    assert!(CloverClaims::burn_signatures(BSC, 0).is_empty());
+    let certificate = CloverClaims::burn_certificate(BSC, 0).unwrap();
+    assert_eq!(certificate.dest, eth_addr);
+    assert_eq!(certificate.amount, 30);
+    assert_eq!(certificate.set_id, 1);
+    assert_eq!(
+      certificate.message,
+      CloverClaims::burn_certificate_message(BSC, 0, &CloverClaims::burns(BSC, 0).unwrap())
+        .unwrap()
+    );
+    assert_eq!(
+      certificate
+        .signatures
+        .iter()
+        .map(|(signer, _)| *signer)
+        .collect::<Vec<_>>(),
+      keys[..3].iter().map(bridge_key_address).collect::<Vec<_>>()
+    );
+
+    // the certificate is final
+    assert_eq!(
+      CloverClaims::validate_unsigned(
+        TransactionSource::External,
+        &ClaimsCall::submit_burn_signature(BSC, 0, keys[3].clone(), sign_burn(&keys[3], BSC, 0)),
+      ),
+      InvalidTransaction::Custom(ValidityError::BurnNotCertifiable.into()).into()
+    );
+    System::set_block_number(20);
+    assert_noop!(
+      CloverClaims::refund_burn(Origin::signed(4), BSC, 0),
+      Error::<Test>::BurnCertified
+    );
+  });
+}
+
+#[test]
+fn burn_certificate_should_be_verified_by_contracts() {
+  let (mut ext, keys) = bridge_test_ext(&["Alice", "Bob"]);
+  ext.execute_with(|| {
+    System::set_block_number(1);
+    new_bridge_session(&keys);
+    let eth_addr = get_legal_eth_addr();
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(4),
+      BSC,
+      CLV,
+      eth_addr,
+      30
+    ));
+    for key in &keys {
+      assert_ok!(CloverClaims::submit_burn_signature(
+        Origin::none(),
+        BSC,
+        0,
+        key.clone(),
+        sign_burn(key, BSC, 0)
+      ));
+    }
+    let certificate = CloverClaims::burn_certificate(BSC, 0).unwrap();
+    assert_eq!(certificate.token, BSC_CLV_TOKEN);
+
+    // keccak256(abi.encode(address(this), dest, amount, nonce, block.chainid))
+    let uint256 = |value: u64| {
+      let mut word = [0u8; 32];
+      word[24..].copy_from_slice(&value.to_be_bytes());
+      word
+    };
+    let mut encoded = Vec::new();
+    encoded.extend_from_slice(&[0u8; 12]);
+    encoded.extend_from_slice(&BSC_CLV_TOKEN.0);
+    encoded.extend_from_slice(&[0u8; 12]);
+    encoded.extend_from_slice(&eth_addr.0);
+    encoded.extend_from_slice(&uint256(30));
+    encoded.extend_from_slice(&uint256(0));
+    encoded.extend_from_slice(&uint256(56));
+    let digest = keccak_256(&encoded);
+    assert_eq!(certificate.message, digest);
+
+    // ecrecover(digest, v, r, s) with `v` of 27 or 28 yields the signers
+    let signers = certificate
+      .signatures
+      .iter()
+      .map(|(_, signature)| {
+        let mut signature = signature.to_standard(BSC).unwrap();
+        signature[64] += 27;
+        let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature, &digest).unwrap();
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&keccak_256(&public)[12..]);
+        EthereumAddress(address)
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(
+      signers,
+      keys.iter().map(bridge_key_address).collect::<Vec<_>>()
+    );
+    assert_eq!(
+      certificate
+        .signatures
+        .iter()
+        .map(|(signer, _)| *signer)
+        .collect::<Vec<_>>(),
+      signers
+    );
+
+    // burns of currencies without a contract on the chain are not certified
+    assert_ok!(CloverClaims::set_deposit_contract(
+      Origin::root(),
+      BSC,
+      BSC_CLV_TOKEN,
+      None
+    ));
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(4),
+      BSC,
+      CLV,
+      eth_addr,
+      30
+    ));
+    assert_eq!(
+      CloverClaims::burn_certificate_message(BSC, 1, &CloverClaims::burns(BSC, 1).unwrap()),
+      None
+    );
+    assert_noop!(
+      CloverClaims::submit_burn_signature(
+        Origin::none(),
+        BSC,
+        1,
+        keys[0].clone(),
+        Default::default()
+      ),
+      Error::<Test>::BurnNotCertifiable
+    );
+  });
+}
+
+#[test]
+fn burn_signatures_should_follow_the_authority_set() {
+  let (mut ext, keys) = bridge_test_ext(&["Alice", "Bob", "Charlie", "Dave", "Eve"]);
+  ext.execute_with(|| {
+    System::set_block_number(1);
+    new_bridge_session(&keys[..4]);
+    let eth_addr = get_legal_eth_addr();
+    for _ in 0..2 {
+      assert_ok!(CloverClaims::burn(
+        Origin::signed(4),
+        BSC,
+        CLV,
+        eth_addr,
+        30
+      ));
+    }
+    for key in &keys[..2] {
+      assert_ok!(CloverClaims::submit_burn_signature(
+        Origin::none(),
+        BSC,
+        0,
+        key.clone(),
+        sign_burn(key, BSC, 0)
+      ));
+    }
+
+    // the same keys do not change the set
+    new_bridge_session(&keys[..4]);
+    assert_eq!(CloverClaims::bridge_authority_set_id(), 1);
+
+    // the signature of Alice no longer counts once she leaves the set
+    new_bridge_session(&keys[1..]);
+    assert_eq!(CloverClaims::bridge_authority_set_id(), 2);
+    assert_noop!(
+      CloverClaims::submit_burn_signature(
+        Origin::none(),
+        BSC,
+        0,
+        keys[0].clone(),
+        sign_burn(&keys[0], BSC, 0)
+      ),
+      Error::<Test>::NotBridgeAuthority
+    );
+    assert_ok!(CloverClaims::submit_burn_signature(
+      Origin::none(),
+      BSC,
+      0,
+      keys[2].clone(),
+      sign_burn(&keys[2], BSC, 0)
+    ));
+    assert_eq!(CloverClaims::burn_certificates(BSC, 0), None);
+    assert_ok!(CloverClaims::submit_burn_signature(
+      Origin::none(),
+      BSC,
+      0,
+      keys[4].clone(),
+      sign_burn(&keys[4], BSC, 0)
+    ));
+    let certificate = CloverClaims::burn_certificates(BSC, 0).unwrap();
+    assert_eq!(certificate.set_id, 2);
+    assert_eq!(
+      certificate
+        .signatures
+        .into_iter()
+        .map(|(signer, _)| signer)
+        .collect::<Vec<_>>(),
+      vec![keys[1].clone(), keys[2].clone(), keys[4].clone()]
+    );
+
+    // burns released by a relayer are no longer signed
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::confirm_burn(
+      Origin::signed(1),
+      BSC,
+      1,
+      EthereumTxHash([1; 32])
+    ));
+    assert_noop!(
+      CloverClaims::submit_burn_signature(
+        Origin::none(),
+        BSC,
+        1,
+        keys[1].clone(),
+        sign_burn(&keys[1], BSC, 1)
+      ),
+      Error::<Test>::BurnNotCertifiable
+    );
+  });
+}
+
+#[test]
+fn offchain_worker_should_sign_burns() {
+  use crate::Call as ClaimsCall;
+  use frame_support::traits::OffchainWorker;
+  use sp_core::offchain::{
+    testing::{TestOffchainExt, TestTransactionPoolExt},
+    OffchainExt, TransactionPoolExt,
+  };
+
+  let (mut ext, keys) = bridge_test_ext(&["Alice", "Bob"]);
+  let (offchain, _) = TestOffchainExt::new();
+  let (pool, pool_state) = TestTransactionPoolExt::new();
+  ext.register_extension(OffchainExt::new(offchain));
+  ext.register_extension(TransactionPoolExt::new(pool));
+  ext.execute_with(|| {
+    System::set_block_number(1);
+    // Charlie is an authority without a local key
+    let charlie = ecdsa::AuthorityId::default();
+    new_bridge_session(&[keys[0].clone(), keys[1].clone(), charlie]);
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(4),
+      BSC,
+      CLV,
+      get_legal_eth_addr(),
+      30
+    ));
+
+    CloverClaims::offchain_worker(1);
+    let txs = std::mem::take(&mut pool_state.write().transactions);
+    assert_eq!(txs.len(), 2);
+    for tx in txs {
+      let tx = UncheckedExtrinsic::decode(&mut &tx[..]).unwrap();
+      assert_eq!(tx.signature, None);
+      match tx.function {
+        mock::Call::CloverClaims(ClaimsCall::submit_burn_signature(chain_id, nonce, key, sig)) => {
+          assert_ok!(CloverClaims::submit_burn_signature(
+            Origin::none(),
+            chain_id,
+            nonce,
+            key,
+            sig
+          ));
+        }
+        call => panic!("unexpected call {:?}", call),
+      }
+    }
+    assert_eq!(CloverClaims::burn_signatures(BSC, 0).len(), 2);
+
+    // the local keys already signed
+    CloverClaims::offchain_worker(2);
+    assert!(pool_state.read().transactions.is_empty());
+  });
+}
+
+#[test]
+fn pause_should_work() {
+  use crate::Call as ClaimsCall;
+  use frame_support::unsigned::ValidateUnsigned;
+  use sp_runtime::transaction_validity::{InvalidTransaction, TransactionSource};
+
+  new_test_ext().execute_with(|| {
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 100));
+    let tx_hash = get_legal_tx_hash();
+    let eth_addr = get_legal_eth_addr();
+
+    // only the pause origin can pause, only the admin origin can unpause
+    assert_noop!(
+      CloverClaims::pause(Origin::signed(1), BridgeOperation::Mint),
+      DispatchError::BadOrigin
+    );
+    assert_noop!(
+      CloverClaims::pause(Origin::root(), BridgeOperation::Mint),
+      DispatchError::BadOrigin
+    );
+    assert_ok!(CloverClaims::pause(
+      Origin::signed(PAUSER),
+      BridgeOperation::Mint
+    ));
+    assert!(CloverClaims::paused(BridgeOperation::Mint));
+    assert!(!CloverClaims::paused(BridgeOperation::Claim));
+    assert_noop!(
+      CloverClaims::unpause(Origin::signed(PAUSER), BridgeOperation::Mint),
+      DispatchError::BadOrigin
+    );
+
+    assert_noop!(
+      CloverClaims::mint_claim(Origin::signed(1), BSC, CLV, tx_hash, eth_addr, 100, None),
+      Error::<Test>::MintPaused
+    );
+    assert_ok!(CloverClaims::unpause(Origin::root(), BridgeOperation::Mint));
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx_hash,
+      eth_addr,
+      100,
+      None
+    ));
+
+    // claims are paused independently, also rejected from the pool
+    assert_ok!(CloverClaims::pause(
+      Origin::signed(PAUSER),
+      BridgeOperation::Claim
+    ));
+    assert_noop!(
+      CloverClaims::claim(Origin::none(), 1, BSC, tx_hash, get_legal_eth_sig()),
+      Error::<Test>::ClaimPaused
+    );
+    assert_eq!(
+      CloverClaims::validate_unsigned(
+        TransactionSource::External,
+        &ClaimsCall::claim(1, BSC, tx_hash, get_legal_eth_sig())
+      ),
+      InvalidTransaction::Custom(ValidityError::ClaimPaused.into()).into()
+    );
+    assert_ok!(CloverClaims::unpause(
+      Origin::root(),
+      BridgeOperation::Claim
+    ));
+    assert_ok!(CloverClaims::claim(
+      Origin::none(),
+      1,
+      BSC,
+      tx_hash,
+      get_legal_eth_sig()
+    ));
+
+    assert_ok!(CloverClaims::pause(
+      Origin::signed(PAUSER),
+      BridgeOperation::Burn
+    ));
+    assert_noop!(
+      CloverClaims::burn(Origin::signed(1), BSC, CLV, eth_addr, 10),
+      Error::<Test>::BurnPaused
+    );
+    assert_ok!(CloverClaims::unpause(Origin::root(), BridgeOperation::Burn));
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      eth_addr,
+      10
+    ));
+  });
+}
+
+#[test]
+fn freeze_should_work() {
+  use crate::Call as ClaimsCall;
+  use frame_support::unsigned::ValidateUnsigned;
+  use sp_runtime::transaction_validity::{InvalidTransaction, TransactionSource};
+
+  new_test_ext().execute_with(|| {
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 100));
+    let tx_hash = get_legal_tx_hash();
+    let eth_addr = get_legal_eth_addr();
+    let claim = ClaimsCall::claim(1, BSC, tx_hash, get_legal_eth_sig());
+
+    // only the freeze origin can freeze, an address is frozen once
+    assert_noop!(
+      CloverClaims::freeze_address(Origin::signed(1), eth_addr),
+      DispatchError::BadOrigin
+    );
+    assert_ok!(CloverClaims::freeze_address(Origin::root(), eth_addr));
+    assert!(CloverClaims::frozen_address(&eth_addr));
+    assert_noop!(
+      CloverClaims::freeze_address(Origin::root(), eth_addr),
+      Error::<Test>::AlreadyFrozen
+    );
+
+    // deposits to the frozen address are not minted
+    assert_noop!(
+      CloverClaims::mint_claim(Origin::signed(1), BSC, CLV, tx_hash, eth_addr, 100, None),
+      Error::<Test>::AddressFrozen
+    );
+    assert_ok!(CloverClaims::unfreeze_address(Origin::root(), eth_addr));
+    assert!(!CloverClaims::frozen_address(&eth_addr));
+    assert_noop!(
+      CloverClaims::unfreeze_address(Origin::root(), eth_addr),
+      Error::<Test>::NotFrozen
+    );
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx_hash,
+      eth_addr,
+      100,
+      None
+    ));
+
+    // claims of the frozen address are rejected, also from the pool
+    assert_ok!(CloverClaims::freeze_address(Origin::root(), eth_addr));
+    assert_noop!(
+      CloverClaims::claim(Origin::none(), 1, BSC, tx_hash, get_legal_eth_sig()),
+      Error::<Test>::AddressFrozen
+    );
+    assert_eq!(
+      CloverClaims::validate_unsigned(TransactionSource::External, &claim),
+      InvalidTransaction::Custom(ValidityError::Frozen.into()).into()
+    );
+    assert_ok!(CloverClaims::unfreeze_address(Origin::root(), eth_addr));
+
+    // claims to the frozen account are rejected, also from the pool
+    assert_noop!(
+      CloverClaims::freeze_account(Origin::signed(1), 1),
+      DispatchError::BadOrigin
+    );
+    assert_ok!(CloverClaims::freeze_account(Origin::root(), 1));
+    assert!(CloverClaims::frozen_account(&1));
+    assert_noop!(
+      CloverClaims::freeze_account(Origin::root(), 1),
+      Error::<Test>::AlreadyFrozen
+    );
+    assert_noop!(
+      CloverClaims::claim(Origin::none(), 1, BSC, tx_hash, get_legal_eth_sig()),
+      Error::<Test>::AccountFrozen
+    );
+    assert_eq!(
+      CloverClaims::validate_unsigned(TransactionSource::External, &claim),
+      InvalidTransaction::Custom(ValidityError::Frozen.into()).into()
+    );
+    assert_ok!(CloverClaims::unfreeze_account(Origin::root(), 1));
+    assert!(!CloverClaims::frozen_account(&1));
+    assert_noop!(
+      CloverClaims::unfreeze_account(Origin::root(), 1),
+      Error::<Test>::NotFrozen
+    );
+    assert!(CloverClaims::validate_unsigned(TransactionSource::External, &claim).is_ok());
+    assert_ok!(CloverClaims::claim(
+      Origin::none(),
+      1,
+      BSC,
+      tx_hash,
+      get_legal_eth_sig()
+    ));
+    assert_eq!(Balances::free_balance(1), 100);
+
+    // frozen accounts can not burn, nor can anyone burn to frozen addresses
+    assert_ok!(CloverClaims::freeze_account(Origin::root(), 1));
+    assert_noop!(
+      CloverClaims::burn(Origin::signed(1), BSC, CLV, eth_addr, 10),
+      Error::<Test>::AccountFrozen
+    );
+    assert_ok!(CloverClaims::unfreeze_account(Origin::root(), 1));
+    assert_ok!(CloverClaims::freeze_address(Origin::root(), eth_addr));
+    assert_noop!(
+      CloverClaims::burn(Origin::signed(1), BSC, CLV, eth_addr, 10),
+      Error::<Test>::AddressFrozen
+    );
+    assert_ok!(CloverClaims::unfreeze_address(Origin::root(), eth_addr));
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      eth_addr,
+      10
+    ));
+    assert_eq!(Balances::free_balance(1), 90);
+  });
+}
+
+#[test]
+fn claims_api_should_work() {
+  new_test_ext().execute_with(|| {
+    System::set_block_number(1);
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 2));
+    assert_ok!(CloverClaims::set_relayer_threshold(Origin::root(), BSC, 2));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 100));
+    assert_ok!(CloverClaims::set_address_daily_cap(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(80)
+    ));
+    let tx_hash = get_legal_tx_hash();
+    let eth_addr = get_legal_eth_addr();
+
+    // the bytes signed with `personal_sign` for `claim`
+    assert_eq!(
+      CloverClaims::claim_message(&1, &tx_hash),
+      b"\x19Ethereum Signed Message:\n109Pay CLVs to the TEST account:0100000000000000\
+        4c5adaad6ca9cd2ae9f372b59ff6765fb66082c08caf6e61e6fbc39c35e82bec"
+        .to_vec()
+    );
+
+    assert_eq!(
+      CloverClaims::claim_limits(BSC, CLV),
+      ClaimLimits {
+        claim_limit: 100,
+        claim_window: None,
+        max_claim_amount: None,
+        address_daily_cap: Some(80),
+      }
+    );
+
+    assert_eq!(
+      CloverClaims::claim_status(BSC, &tx_hash),
+      ClaimStatus::Unknown
+    );
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx_hash,
+      eth_addr,
+      60,
+      None
+    ));
+    assert_eq!(
+      CloverClaims::claim_status(BSC, &tx_hash),
+      ClaimStatus::Attesting(1)
+    );
+    assert_eq!(CloverClaims::pending_claims(BSC, &eth_addr), vec![]);
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(2),
+      BSC,
+      CLV,
+      tx_hash,
+      eth_addr,
+      60,
+      None
+    ));
+
+    let details = ClaimDetails {
+      currency_id: CLV,
+      who: eth_addr,
+      amount: 60,
+      expiry: Some(101),
+      vesting: None,
+    };
+    assert_eq!(
+      CloverClaims::claim_status(BSC, &tx_hash),
+      ClaimStatus::Minted(details.clone())
+    );
+    assert_eq!(
+      CloverClaims::pending_claims(BSC, &eth_addr),
+      vec![(tx_hash, details.clone())]
+    );
+    assert_eq!(
+      CloverClaims::pending_claims(BSC, &get_typed_claim_eth_addr()),
+      vec![]
+    );
+    assert_eq!(CloverClaims::pending_claims(ETH, &eth_addr), vec![]);
+    assert_eq!(CloverClaims::claim_limits(BSC, CLV).claim_limit, 40);
+
+    assert_ok!(CloverClaims::claim(
+      Origin::none(),
+      1,
+      BSC,
+      tx_hash,
+      get_legal_eth_sig()
+    ));
+    assert_eq!(
+      CloverClaims::claim_status(BSC, &tx_hash),
+      ClaimStatus::Claimed(details)
+    );
+    assert_eq!(CloverClaims::pending_claims(BSC, &eth_addr), vec![]);
+
+    // revoked txs
+    let tx_hash2 = EthereumTxHash([1; 32]);
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx_hash2,
+      eth_addr,
+      10,
+      None
+    ));
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(2),
+      BSC,
+      CLV,
+      tx_hash2,
+      eth_addr,
+      10,
+      None
+    ));
+    System::set_block_number(101);
+    assert_ok!(CloverClaims::revoke_claim(
+      Origin::root(),
+      BSC,
+      tx_hash2,
+      false
+    ));
+    assert_eq!(
+      CloverClaims::claim_status(BSC, &tx_hash2),
+      ClaimStatus::Revoked
+    );
+  });
+}
+
+#[test]
+fn evm_claim_and_burn_should_work() {
+  use crate::Call as ClaimsCall;
+  use frame_support::unsigned::ValidateUnsigned;
+  use pallet_evm::AddressMapping;
+  use sp_runtime::transaction_validity::{InvalidTransaction, TransactionSource};
+
+  new_test_ext().execute_with(|| {
+    System::set_block_number(1);
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 100));
+    let tx_hash = get_legal_tx_hash();
+    let evm_addr = H160(get_typed_claim_eth_addr().0);
+    let evm_account = EvmAddressMapping::into_account_id(evm_addr);
+    assert_ok!(CloverClaims::mint_claim(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      tx_hash,
+      get_typed_claim_eth_addr(),
+      100,
+      None
+    ));
+
+    // the dest is signed
+    assert_noop!(
+      CloverClaims::claim_to_evm(
+        Origin::none(),
+        H160(get_legal_eth_addr().0),
+        BSC,
+        tx_hash,
+        get_evm_claim_sig()
+      ),
+      Error::<Test>::SignatureNotMatch
+    );
+    // not interchangeable with `claim`
+    assert_noop!(
+      CloverClaims::claim(
+        Origin::none(),
+        evm_account,
+        BSC,
+        tx_hash,
+        get_evm_claim_sig()
+      ),
+      Error::<Test>::SignatureNotMatch
+    );
+    assert!(CloverClaims::validate_unsigned(
+      TransactionSource::External,
+      &ClaimsCall::claim_to_evm(evm_addr, BSC, tx_hash, get_evm_claim_sig())
+    )
+    .is_ok());
+    assert_ok!(CloverClaims::claim_to_evm(
+      Origin::none(),
+      evm_addr,
+      BSC,
+      tx_hash,
+      get_evm_claim_sig()
+    ));
+    assert_eq!(Balances::free_balance(evm_account), 100);
+
+    // burn the funds of the evm address signed by it
+    let dest = get_legal_eth_addr();
+    let burn_call =
+      |nonce| ClaimsCall::burn_from_evm(evm_addr, BSC, CLV, dest, 10, get_evm_burn_sig(nonce));
+    assert_noop!(
+      CloverClaims::burn_from_evm(
+        Origin::none(),
+        evm_addr,
+        BSC,
+        CLV,
+        dest,
+        20,
+        get_evm_burn_sig(0)
+      ),
+      Error::<Test>::SignatureNotMatch
+    );
+    assert_eq!(
+      CloverClaims::validate_unsigned(TransactionSource::External, &burn_call(1)),
+      InvalidTransaction::Custom(ValidityError::SignatureNotMatch.into()).into()
+    );
+    assert!(CloverClaims::validate_unsigned(TransactionSource::External, &burn_call(0)).is_ok());
+    assert_ok!(CloverClaims::burn_from_evm(
+      Origin::none(),
+      evm_addr,
+      BSC,
+      CLV,
+      dest,
+      10,
+      get_evm_burn_sig(0)
+    ));
+    assert_eq!(Balances::free_balance(evm_account), 90);
+    assert_eq!(CloverClaims::evm_burn_nonce(&evm_addr), 1);
+    assert_eq!(
+      CloverClaims::burns(BSC, 0),
+      Some(BurnInfo {
+        who: evm_account,
+        currency_id: CLV,
+        dest,
+        amount: 10,
+        fee: 0,
+        block: 1,
+        status: BurnStatus::Pending,
+      })
+    );
+
+    // signatures can not be replayed
+    assert_noop!(
+      CloverClaims::burn_from_evm(
+        Origin::none(),
+        evm_addr,
+        BSC,
+        CLV,
+        dest,
+        10,
+        get_evm_burn_sig(0)
+      ),
+      Error::<Test>::SignatureNotMatch
+    );
+    assert!(CloverClaims::validate_unsigned(TransactionSource::External, &burn_call(0)).is_err());
+
+    assert_ok!(CloverClaims::pause(
+      Origin::signed(PAUSER),
+      BridgeOperation::Burn
+    ));
+    assert_eq!(
+      CloverClaims::validate_unsigned(TransactionSource::External, &burn_call(1)),
+      InvalidTransaction::Custom(ValidityError::BurnPaused.into()).into()
+    );
+    assert_ok!(CloverClaims::unpause(Origin::root(), BridgeOperation::Burn));
+    assert_ok!(CloverClaims::burn_from_evm(
+      Origin::none(),
+      evm_addr,
+      BSC,
+      CLV,
+      dest,
+      10,
+      get_evm_burn_sig(1)
+    ));
+    assert_eq!(Balances::free_balance(evm_account), 80);
+  });
+}
+
+#[test]
+fn evm_burn_should_be_checked_before_the_pool() {
+  use crate::Call as ClaimsCall;
+  use frame_support::unsigned::ValidateUnsigned;
+  use pallet_evm::AddressMapping;
+  use sp_core::H256;
+  use sp_runtime::transaction_validity::{InvalidTransaction, TransactionSource};
+
+  new_test_ext().execute_with(|| {
+    System::set_block_number(1);
+    let evm_addr = H160(get_typed_claim_eth_addr().0);
+    let evm_account = EvmAddressMapping::into_account_id(evm_addr);
+    assert_ok!(Balances::transfer(Origin::signed(4), evm_account, 50));
+    let dest = get_legal_eth_addr();
+    let validate = |chain_id| {
+      CloverClaims::validate_unsigned(
+        TransactionSource::External,
+        &ClaimsCall::burn_from_evm(evm_addr, chain_id, CLV, dest, 10, get_evm_burn_sig(0)),
+      )
+    };
+    assert_eq!(validate(BSC).unwrap().longevity, EVM_BURN_LONGEVITY);
+
+    // burns bound to fail in the dispatch are rejected
+    assert_eq!(
+      validate(ETH),
+      InvalidTransaction::Custom(ValidityError::ChainDisabled.into()).into()
+    );
+    assert_ok!(CloverClaims::set_burn_fee(
+      Origin::root(),
+      BSC,
+      CLV,
+      Some(FeeSchedule::flat(10))
+    ));
+    assert_eq!(
+      validate(BSC),
+      InvalidTransaction::Custom(ValidityError::InvalidAmount.into()).into()
+    );
+    assert_ok!(CloverClaims::set_burn_fee(Origin::root(), BSC, CLV, None));
+    assert_ok!(CloverClaims::freeze_address(Origin::root(), dest));
+    assert_eq!(
+      validate(BSC),
+      InvalidTransaction::Custom(ValidityError::Frozen.into()).into()
+    );
+    assert_ok!(CloverClaims::unfreeze_address(Origin::root(), dest));
+
+    // the signature is bound to the genesis of the network
+    let genesis = frame_system::BlockHash::<Test>::get(0);
+    frame_system::BlockHash::<Test>::insert(0, H256::repeat_byte(1));
+    assert_eq!(
+      validate(BSC),
+      InvalidTransaction::Custom(ValidityError::SignatureNotMatch.into()).into()
+    );
+    assert_noop!(
+      CloverClaims::burn_from_evm(
+        Origin::none(),
+        evm_addr,
+        BSC,
+        CLV,
+        dest,
+        10,
+        get_evm_burn_sig(0)
+      ),
+      Error::<Test>::SignatureNotMatch
+    );
+    frame_system::BlockHash::<Test>::insert(0, genesis);
+    assert!(validate(BSC).is_ok());
+  });
+}
+
+#[test]
+fn mint_with_proof_should_work() {
+  use clover_traits::{EvmLog, ReceiptProof, VerifiedReceipt};
+  use sp_core::H256;
+  use sp_runtime::traits::BadOrigin;
+
+  let deposit_contract = H160::repeat_byte(0x11);
+  let deposit_log = |who: EthereumAddress, data: Vec<u8>| {
+    let mut topic = H256::zero();
+    topic.0[12..].copy_from_slice(&who.0);
+    EvmLog {
+      address: deposit_contract,
+      topics: vec![H256(keccak_256(DEPOSIT_EVENT)), topic],
+      data,
+    }
+  };
+  let amount = |value: u128| {
+    let mut data = [0u8; 32];
+    data[16..].copy_from_slice(&value.to_be_bytes());
+    data.to_vec()
+  };
+  let proof = |index: u64| ReceiptProof {
+    block_hash: H256::repeat_byte(9),
+    tx_index: index,
+    tx_proof: vec![vec![index as u8]],
+    receipt_proof: vec![vec![index as u8]],
+  };
+
+  new_test_ext().execute_with(|| {
+    System::set_block_number(1);
+    assert_ok!(CloverClaims::set_claim_limit(
+      Origin::root(),
+      BSC,
+      CLV,
+      1000
+    ));
+    let tx_hash = get_legal_tx_hash();
+    let other_log = EvmLog {
+      address: H160::repeat_byte(0x22),
+      topics: vec![H256(keccak_256(b"Other()"))],
+      data: vec![],
+    };
+    set_receipt(
+      proof(1),
+      VerifiedReceipt {
+        tx_hash: H256(tx_hash.0),
+        block_number: 9,
+        success: true,
+        logs: vec![other_log, deposit_log(get_legal_eth_addr(), amount(100))],
+      },
+    );
+
+    assert_noop!(
+      CloverClaims::mint_with_proof(Origin::signed(5), BSC, proof(1), 1),
+      Error::<Test>::DepositNotFound
+    );
+    assert_noop!(
+      CloverClaims::set_deposit_contract(Origin::signed(5), BSC, deposit_contract, Some(CLV)),
+      BadOrigin
+    );
+    assert_ok!(CloverClaims::set_deposit_contract(
+      Origin::root(),
+      BSC,
+      deposit_contract,
+      Some(CLV)
+    ));
+    assert_eq!(
+      CloverClaims::deposit_contracts(BSC, deposit_contract),
+      Some(CLV)
+    );
+
+    // the proof is checked by the verifier
+    assert_noop!(
+      CloverClaims::mint_with_proof(Origin::signed(5), BSC, proof(2), 1),
+      DispatchError::Other("invalid proof")
+    );
+    assert_noop!(
+      CloverClaims::mint_with_proof(Origin::signed(5), ETH, proof(1), 1),
+      Error::<Test>::ChainDisabled
+    );
+    // only logs of the deposit contract are minted
+    assert_noop!(
+      CloverClaims::mint_with_proof(Origin::signed(5), BSC, proof(1), 0),
+      Error::<Test>::DepositNotFound
+    );
+    assert_noop!(
+      CloverClaims::mint_with_proof(Origin::signed(5), BSC, proof(1), 2),
+      Error::<Test>::DepositNotFound
+    );
+    assert_ok!(CloverClaims::pause(
+      Origin::signed(PAUSER),
+      BridgeOperation::Mint
+    ));
+    assert_noop!(
+      CloverClaims::mint_with_proof(Origin::signed(5), BSC, proof(1), 1),
+      Error::<Test>::MintPaused
+    );
+    assert_ok!(CloverClaims::unpause(Origin::root(), BridgeOperation::Mint));
+
+    // anyone can mint with the proof, no relayer is recorded
+    assert_ok!(CloverClaims::mint_with_proof(
+      Origin::signed(5),
+      BSC,
+      proof(1),
+      1
+    ));
+    assert_eq!(
+      claim_of(BSC, &tx_hash),
+      Some((get_legal_eth_addr(), 100, false))
+    );
+    assert_eq!(
+      CloverClaims::mints(BSC, &tx_hash).map(|mint| mint.relayers),
+      Some(vec![])
+    );
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 900);
+    assert_noop!(
+      CloverClaims::mint_with_proof(Origin::signed(5), BSC, proof(1), 1),
+      Error::<Test>::AlreadyMinted
+    );
+
+    assert_ok!(CloverClaims::claim(
+      Origin::none(),
+      1,
+      BSC,
+      tx_hash,
+      get_legal_eth_sig()
+    ));
+    assert_eq!(Balances::free_balance(1), 100);
+  });
+}
+
+#[test]
+fn invalid_deposit_proof_should_fail() {
+  use clover_traits::{EvmLog, ReceiptProof, VerifiedReceipt};
+  use sp_core::H256;
+
+  let deposit_contract = H160::repeat_byte(0x11);
+  let proof = |index: u64| ReceiptProof {
+    block_hash: H256::repeat_byte(9),
+    tx_index: index,
+    tx_proof: vec![],
+    receipt_proof: vec![],
+  };
+  let receipt = |index: u8, success: bool, data: Vec<u8>| VerifiedReceipt {
+    tx_hash: H256::repeat_byte(index),
+    block_number: 9,
+    success,
+    logs: vec![EvmLog {
+      address: deposit_contract,
+      topics: vec![
+        H256(keccak_256(DEPOSIT_EVENT)),
+        H256::from(H160(get_legal_eth_addr().0)),
+      ],
+      data,
+    }],
+  };
+
+  new_test_ext().execute_with(|| {
+    assert_ok!(CloverClaims::set_claim_limit(
+      Origin::root(),
+      BSC,
+      CLV,
+      1000
+    ));
+    assert_ok!(CloverClaims::set_deposit_contract(
+      Origin::root(),
+      BSC,
+      deposit_contract,
+      Some(CLV)
+    ));
+    let mut amount = [0u8; 32];
+    amount[31] = 100;
+    // the amount overflows the balance
+    let mut too_large = [0u8; 32];
+    too_large[16] = 1;
+    set_receipt(proof(0), receipt(0, false, amount.to_vec()));
+    set_receipt(proof(1), receipt(1, true, amount[1..].to_vec()));
+    set_receipt(proof(2), receipt(2, true, too_large.to_vec()));
+    set_receipt(proof(3), receipt(3, true, amount.to_vec()));
+
+    assert_noop!(
+      CloverClaims::mint_with_proof(Origin::signed(5), BSC, proof(0), 0),
+      Error::<Test>::TxFailed
+    );
+    assert_noop!(
+      CloverClaims::mint_with_proof(Origin::signed(5), BSC, proof(1), 0),
+      Error::<Test>::InvalidDeposit
+    );
+    assert_noop!(
+      CloverClaims::mint_with_proof(Origin::signed(5), BSC, proof(2), 0),
+      Error::<Test>::InvalidDeposit
+    );
+
+    // removed deposit contracts are not minted from
+    assert_ok!(CloverClaims::set_deposit_contract(
+      Origin::root(),
+      BSC,
+      deposit_contract,
+      None
+    ));
+    assert_noop!(
+      CloverClaims::mint_with_proof(Origin::signed(5), BSC, proof(3), 0),
+      Error::<Test>::DepositNotFound
+    );
+  });
+}
+
+#[test]
+fn genesis_config_should_work() {
+  use frame_support::traits::GenesisBuild;
+
+  let mut t = frame_system::GenesisConfig::default()
+    .build_storage::<Test>()
+    .unwrap();
+  crate::GenesisConfig::<Test> {
+    enabled_chains: vec![BSC],
+    relayers: vec![(BSC, 1), (BSC, 2)],
+    relayer_thresholds: vec![(BSC, 2)],
+    claim_limits: vec![(BSC, CLV, 1000)],
+    mint_fees: vec![(BSC, CLV, FeeSchedule::flat(1))],
+    burn_fees: vec![(BSC, CETH, FeeSchedule::flat(2))],
+    claims: vec![(BSC, CLV, get_legal_tx_hash(), get_legal_eth_addr(), 100)],
+  }
+  .assimilate_storage(&mut t)
+  .unwrap();
+
+  sp_io::TestExternalities::from(t).execute_with(|| {
+    assert!(CloverClaims::chain_enabled(BSC));
+    assert!(!CloverClaims::chain_enabled(ETH));
+    assert_eq!(CloverClaims::relayers(BSC), vec![1, 2]);
+    assert_eq!(CloverClaims::relayer_threshold(BSC), 2);
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 1000);
+    assert_eq!(CloverClaims::mint_fee(BSC, CLV), Some(FeeSchedule::flat(1)));
+    assert_eq!(
+      CloverClaims::burn_fee(BSC, CETH),
+      Some(FeeSchedule::flat(2))
+    );
+    assert_eq!(CloverClaims::mint_fee(BSC, CETH), None);
+
+    // the seeded claim is claimable
+    assert_eq!(
+      claim_of(BSC, &get_legal_tx_hash()),
+      Some((get_legal_eth_addr(), 100, false))
+    );
+    assert_ok!(CloverClaims::claim(
+      Origin::none(),
+      1,
+      BSC,
+      get_legal_tx_hash(),
+      get_legal_eth_sig()
+    ));
+    assert_eq!(Balances::free_balance(1), 100);
+  });
+}
+
+#[test]
+fn mint_claims_batch_should_work() {
+  new_test_ext().execute_with(|| {
+    System::set_block_number(1);
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 2));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 150));
+    let eth_addr = get_legal_eth_addr();
+    let tx = |n: u8| EthereumTxHash([n; 32]);
+
+    assert_noop!(
+      CloverClaims::mint_claims_batch(Origin::signed(3), BSC, CLV, vec![(tx(1), eth_addr, 100)]),
+      Error::<Test>::NoPermission
+    );
+    assert_noop!(
+      CloverClaims::mint_claims_batch(
+        Origin::signed(1),
+        BSC,
+        CLV,
+        vec![(tx(1), eth_addr, 1); MAX_BATCH_SIZE + 1]
+      ),
+      Error::<Test>::BatchTooLarge
+    );
+    assert_noop!(
+      CloverClaims::mint_claims_batch(Origin::signed(1), ETH, CLV, vec![(tx(1), eth_addr, 100)]),
+      Error::<Test>::ChainDisabled
+    );
+
+    assert_ok!(CloverClaims::mint_claims_batch(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      vec![
+        (tx(1), eth_addr, 100),
+        // already minted by the first item
+        (tx(1), eth_addr, 100),
+        // over the claim limit
+        (tx(2), eth_addr, 100),
+        (tx(3), eth_addr, 50),
+      ]
+    ));
+    assert_eq!(claim_of(BSC, &tx(1)), Some((eth_addr, 100, false)));
+    assert_eq!(claim_of(BSC, &tx(2)), None);
+    assert_eq!(claim_of(BSC, &tx(3)), Some((eth_addr, 50, false)));
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 0);
+
+    // attestations are recorded until the threshold is reached
+    assert_ok!(CloverClaims::set_relayer_threshold(Origin::root(), BSC, 2));
+    assert_ok!(CloverClaims::set_claim_limit(Origin::root(), BSC, CLV, 100));
+    assert_ok!(CloverClaims::mint_claims_batch(
+      Origin::signed(1),
+      BSC,
+      CLV,
+      vec![(tx(4), eth_addr, 60), (tx(5), eth_addr, 60)]
+    ));
+    assert_eq!(CloverClaims::attestations(BSC, &tx(4)).len(), 1);
+    assert_ok!(CloverClaims::mint_claims_batch(
+      Origin::signed(2),
+      BSC,
+      CLV,
+      vec![(tx(4), eth_addr, 60), (tx(5), eth_addr, 60)]
+    ));
+    assert_eq!(claim_of(BSC, &tx(4)), Some((eth_addr, 60, false)));
+    // the skipped attestation is rolled back
+    assert_eq!(claim_of(BSC, &tx(5)), None);
+    assert_eq!(CloverClaims::attestations(BSC, &tx(5)).len(), 1);
+    assert_eq!(CloverClaims::claim_limit(BSC, CLV), 40);
+
+    assert_ok!(CloverClaims::pause(
+      Origin::signed(PAUSER),
+      BridgeOperation::Mint
+    ));
+    assert_noop!(
+      CloverClaims::mint_claims_batch(Origin::signed(1), BSC, CLV, vec![(tx(6), eth_addr, 10)]),
+      Error::<Test>::MintPaused
+    );
+  });
+}
+
+#[test]
+fn confirm_burns_should_work() {
+  new_test_ext().execute_with(|| {
+    System::set_block_number(1);
+    assert_ok!(CloverClaims::add_relayer(Origin::root(), BSC, 1));
+    let eth_addr = get_legal_eth_addr();
+    let tx = |n: u8| EthereumTxHash([n; 32]);
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(4),
+      BSC,
+      CLV,
+      eth_addr,
+      10
+    ));
+    assert_ok!(CloverClaims::burn(
+      Origin::signed(5),
+      BSC,
+      CLV,
+      eth_addr,
+      20
+    ));
+
+    assert_noop!(
+      CloverClaims::confirm_burns(Origin::signed(2), BSC, vec![(0, tx(1))]),
+      Error::<Test>::NoPermission
+    );
+    assert_noop!(
+      CloverClaims::confirm_burns(Origin::signed(1), BSC, vec![(0, tx(1)); MAX_BATCH_SIZE + 1]),
+      Error::<Test>::BatchTooLarge
+    );
+
+    assert_ok!(CloverClaims::confirm_burns(
+      Origin::signed(1),
+      BSC,
+      vec![
+        (0, tx(1)),
+        // not pending any more
+        (0, tx(2)),
+        // not found
+        (5, tx(3)),
+        (1, tx(4)),
+      ]
+    ));
+    assert_eq!(
+      CloverClaims::burns(BSC, 0).map(|burn| burn.status),
+      Some(BurnStatus::Relayed(tx(1)))
+    );
+    assert_eq!(
+      CloverClaims::burns(BSC, 1).map(|burn| burn.status),
+      Some(BurnStatus::Relayed(tx(4)))
+    );
+    assert_eq!(CloverClaims::burns(BSC, 5), None);
   });
 }
@@ -0,0 +1,74 @@
+// Copyright (C) 2021 Clover Network
+// This file is part of Clover.
+
+//! EIP-712 typed structured data hashing.
+
+use super::Address;
+use sp_io::hashing::keccak_256;
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+
+/// The EIP-712 domain, `salt` is not supported.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Domain<'a> {
+  pub name: &'a [u8],
+  pub version: &'a [u8],
+  pub chain_id: u64,
+  pub verifying_contract: Option<Address>,
+}
+
+impl<'a> Domain<'a> {
+  /// The `domainSeparator` of the domain.
+  pub fn separator(&self) -> [u8; 32] {
+    let mut type_string = b"EIP712Domain(string name,string version,uint256 chainId".to_vec();
+    let mut fields = Vec::with_capacity(4);
+    fields.push(encode_bytes(self.name));
+    fields.push(encode_bytes(self.version));
+    fields.push(encode_uint(self.chain_id.into()));
+    if let Some(ref contract) = self.verifying_contract {
+      type_string.extend_from_slice(b",address verifyingContract");
+      fields.push(encode_address(contract));
+    }
+    type_string.push(b')');
+    hash_struct(&type_string, &fields)
+  }
+}
+
+/// `hashStruct` of a struct whose encoded type is `type_string`, with the fields encoded by
+/// the `encode_*` functions or `hash_struct` for nested structs.
+pub fn hash_struct(type_string: &[u8], fields: &[[u8; 32]]) -> [u8; 32] {
+  let mut data = Vec::with_capacity(32 * (fields.len() + 1));
+  data.extend_from_slice(&keccak_256(type_string));
+  for field in fields {
+    data.extend_from_slice(&field[..]);
+  }
+  keccak_256(&data)
+}
+
+/// The hash to sign for the typed data `struct_hash` in `domain`.
+pub fn typed_data_hash(domain: &Domain, struct_hash: &[u8; 32]) -> [u8; 32] {
+  let mut data = Vec::with_capacity(66);
+  data.extend_from_slice(b"\x19\x01");
+  data.extend_from_slice(&domain.separator());
+  data.extend_from_slice(&struct_hash[..]);
+  keccak_256(&data)
+}
+
+/// Encodes a `uintN` field.
+pub fn encode_uint(value: u128) -> [u8; 32] {
+  let mut r = [0u8; 32];
+  r[16..].copy_from_slice(&value.to_be_bytes());
+  r
+}
+
+/// Encodes an `address` field.
+pub fn encode_address(address: &Address) -> [u8; 32] {
+  let mut r = [0u8; 32];
+  r[12..].copy_from_slice(&address[..]);
+  r
+}
+
+/// Encodes a `bytes` or `string` field.
+pub fn encode_bytes(data: &[u8]) -> [u8; 32] {
+  keccak_256(data)
+}
@@ -0,0 +1,137 @@
+// Copyright (C) 2021 Clover Network
+// This file is part of Clover.
+
+//! Ethereum message signing shared by the modules which verify signatures of ethereum accounts.
+//!
+//! Supports `personal_sign` messages, EIP-712 typed data, EIP-2098 compact signatures and
+//! the recovery id forms produced by common wallets and hardware signers.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+
+pub mod eip712;
+
+#[cfg(test)]
+mod tests;
+
+/// A 20 bytes ethereum address.
+pub type Address = [u8; 20];
+
+/// Half of the secp256k1 curve order, the highest `s` of a canonical signature (EIP-2).
+pub const HALF_CURVE_ORDER: [u8; 32] = [
+  0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+  0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
+/// An ethereum signature.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum EthereumSignature {
+  /// `r || s || v` signature, `v` in any form accepted by `normalize_v`
+  Standard([u8; 65]),
+  /// EIP-2098 `r || yParityAndS` compact signature
+  Compact([u8; 64]),
+}
+
+impl EthereumSignature {
+  /// The `r || s || v` form with `v` of 0 or 1, `None` if `v` is not recognized for
+  /// `chain_id` or `s` is above `HALF_CURVE_ORDER`.
+  pub fn to_standard(&self, chain_id: u64) -> Option<[u8; 65]> {
+    let mut sig = [0u8; 65];
+    match self {
+      EthereumSignature::Standard(s) => {
+        sig.copy_from_slice(&s[..]);
+        sig[64] = normalize_v(s[64], chain_id)?;
+      }
+      EthereumSignature::Compact(s) => {
+        sig[..64].copy_from_slice(&s[..]);
+        sig[64] = s[32] >> 7;
+        sig[32] &= 0x7f;
+      }
+    }
+    if sig[32..64] > HALF_CURVE_ORDER[..] {
+      return None;
+    }
+    Some(sig)
+  }
+}
+
+/// Normalize the recovery id `v` of a signature to 0 or 1.
+///
+/// Accepts 0/1, 27/28, 31/32 (27/28 plus the compressed key offset reported by some
+/// Ledger firmwares) and EIP-155 `chain_id * 2 + 35/36` of the given `chain_id` only, which
+/// Ledger truncates to a byte. A `v` which means another recovery id once truncated is
+/// rejected.
+pub fn normalize_v(v: u8, chain_id: u64) -> Option<u8> {
+  let legacy = match v {
+    0 | 1 => Some(v),
+    27 | 28 => Some(v - 27),
+    31 | 32 => Some(v - 31),
+    _ => None,
+  };
+  let eip155 = chain_id
+    .checked_mul(2)
+    .and_then(|x| x.checked_add(35))
+    .map(|base| (u64::from(v) + 256 - base % 256) % 256)
+    .filter(|id| *id < 2)
+    .map(|id| id as u8);
+  match (legacy, eip155) {
+    (Some(a), Some(b)) if a != b => None,
+    (legacy, eip155) => legacy.or(eip155),
+  }
+}
+
+/// Constructs the message that Ethereum RPC's `personal_sign` and `eth_sign` would sign.
+pub fn personal_message(message: &[u8]) -> Vec<u8> {
+  let mut l = message.len();
+  let mut rev = Vec::new();
+  while l > 0 {
+    rev.push(b'0' + (l % 10) as u8);
+    l /= 10;
+  }
+  let mut v = b"\x19Ethereum Signed Message:\n".to_vec();
+  v.extend(rev.into_iter().rev());
+  v.extend_from_slice(message);
+  v
+}
+
+/// Recovers the address which signed the 32 bytes `hash` for `chain_id`.
+pub fn recover(sig: &EthereumSignature, hash: &[u8; 32], chain_id: u64) -> Option<Address> {
+  let sig = sig.to_standard(chain_id)?;
+  let public = secp256k1_ecdsa_recover(&sig, hash).ok()?;
+  let mut address = Address::default();
+  address.copy_from_slice(&keccak_256(&public[..])[12..]);
+  Some(address)
+}
+
+/// Recovers the address which signed `message` for `chain_id` by using the Ethereum RPC's
+/// `personal_sign` and `eth_sign`.
+pub fn recover_personal(
+  sig: &EthereumSignature,
+  message: &[u8],
+  chain_id: u64,
+) -> Option<Address> {
+  recover(sig, &keccak_256(&personal_message(message)), chain_id)
+}
+
+/// Recovers the address which signed the EIP-712 typed data `struct_hash` in `domain`.
+pub fn recover_typed_data(
+  sig: &EthereumSignature,
+  domain: &eip712::Domain,
+  struct_hash: &[u8; 32],
+) -> Option<Address> {
+  recover(sig, &eip712::typed_data_hash(domain, struct_hash), domain.chain_id)
+}
+
+/// Converts the given binary data into ASCII-encoded hex. It will be twice the length.
+pub fn to_ascii_hex(data: &[u8]) -> Vec<u8> {
+  let mut r = Vec::with_capacity(data.len() * 2);
+  let mut push_nibble = |n| r.push(if n < 10 { b'0' + n } else { b'a' - 10 + n });
+  for &b in data.iter() {
+    push_nibble(b / 16);
+    push_nibble(b % 16);
+  }
+  r
+}
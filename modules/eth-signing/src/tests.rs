@@ -0,0 +1,182 @@
+// Copyright (C) 2021 Clover Network
+// This file is part of Clover.
+
+use super::*;
+use hex_literal::hex;
+
+// the `Mail` example of EIP-712, signed by `cow`
+const COW: Address = hex!["cd2a3d9f938e13cd947ec05abc7fe734df8dd826"];
+const MAIL_R_S: [u8; 64] = hex!["4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b91562"];
+
+fn mail_domain() -> eip712::Domain<'static> {
+  eip712::Domain {
+    name: b"Ether Mail",
+    version: b"1",
+    chain_id: 1,
+    verifying_contract: Some(hex!["cccccccccccccccccccccccccccccccccccccccc"]),
+  }
+}
+
+fn mail_hash() -> [u8; 32] {
+  let person = b"Person(string name,address wallet)";
+  let from = eip712::hash_struct(
+    person,
+    &[eip712::encode_bytes(b"Cow"), eip712::encode_address(&COW)],
+  );
+  let to = eip712::hash_struct(
+    person,
+    &[
+      eip712::encode_bytes(b"Bob"),
+      eip712::encode_address(&hex!["bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb"]),
+    ],
+  );
+  eip712::hash_struct(
+    b"Mail(Person from,Person to,string contents)Person(string name,address wallet)",
+    &[from, to, eip712::encode_bytes(b"Hello, Bob!")],
+  )
+}
+
+fn standard(v: u8) -> EthereumSignature {
+  let mut sig = [0u8; 65];
+  sig[..64].copy_from_slice(&MAIL_R_S[..]);
+  sig[64] = v;
+  EthereumSignature::Standard(sig)
+}
+
+#[test]
+fn eip712_should_work() {
+  assert_eq!(
+    mail_domain().separator(),
+    hex!["f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"]
+  );
+  assert_eq!(
+    eip712::typed_data_hash(&mail_domain(), &mail_hash()),
+    hex!["be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"]
+  );
+  assert_eq!(
+    recover_typed_data(&standard(28), &mail_domain(), &mail_hash()),
+    Some(COW)
+  );
+
+  // signed in another domain
+  let mut domain = mail_domain();
+  domain.chain_id = 56;
+  assert_ne!(
+    recover_typed_data(&standard(28), &domain, &mail_hash()),
+    Some(COW)
+  );
+}
+
+#[test]
+fn recovery_id_forms_should_work() {
+  let hash = eip712::typed_data_hash(&mail_domain(), &mail_hash());
+  // 0/1, 27/28, ledger offset and EIP-155 of bsc
+  for v in [1u8, 28, 32, 56 * 2 + 36].iter() {
+    assert_eq!(recover(&standard(*v), &hash, 56), Some(COW));
+  }
+  for v in [0u8, 27, 31, 56 * 2 + 35].iter() {
+    assert_ne!(recover(&standard(*v), &hash, 56), Some(COW));
+  }
+  for v in [2u8, 26, 29, 33, 34].iter() {
+    assert_eq!(standard(*v).to_standard(56), None);
+    assert_eq!(recover(&standard(*v), &hash, 56), None);
+  }
+  // EIP-155 of clover, truncated to a byte by ledger
+  let clover = 1024u64;
+  assert_eq!(normalize_v((clover * 2 + 36) as u8, clover), Some(1));
+  assert_eq!(normalize_v((clover * 2 + 35) as u8, clover), Some(0));
+}
+
+#[test]
+fn recovery_id_of_another_chain_should_fail() {
+  let hash = eip712::typed_data_hash(&mail_domain(), &mail_hash());
+  // EIP-155 of bsc is not accepted for ethereum and clover
+  for chain_id in [1u64, 1024].iter() {
+    assert_eq!(normalize_v(56 * 2 + 36, *chain_id), None);
+    assert_eq!(recover(&standard(56 * 2 + 36), &hash, *chain_id), None);
+  }
+  // EIP-155 of ethereum is not accepted for bsc
+  assert_eq!(normalize_v(37, 56), None);
+}
+
+#[test]
+fn ambiguous_recovery_id_should_fail() {
+  // 111 * 2 + 35 = 257 is truncated to 1, which is also the legacy recovery id 1
+  assert_eq!(normalize_v(1, 111), None);
+  assert_eq!(normalize_v(2, 111), Some(1));
+  assert_eq!(normalize_v(0, 111), Some(0));
+  // 110 * 2 + 36 = 256 is truncated to 0, which is also the legacy recovery id 0
+  assert_eq!(normalize_v(0, 110), None);
+  assert_eq!(normalize_v(255, 110), Some(0));
+  assert_eq!(normalize_v(1, 110), Some(1));
+  // 124 * 2 + 35 = 283 and 126 * 2 + 35 = 287, truncated to 27 and 31, agree with the legacy
+  // recovery ids
+  assert_eq!(normalize_v(28, 124), Some(1));
+  assert_eq!(normalize_v(31, 126), Some(0));
+  // no EIP-155 form once `chain_id * 2 + 35` overflows
+  assert_eq!(normalize_v(27, u64::max_value()), Some(0));
+  assert_eq!(normalize_v(33, u64::max_value()), None);
+}
+
+#[test]
+fn high_s_should_fail() {
+  let hash = eip712::typed_data_hash(&mail_domain(), &mail_hash());
+  // n - s with the other y parity recovers the same key
+  let n = hex!["fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"];
+  let mut high = MAIL_R_S;
+  let mut borrow = 0i16;
+  for i in (0..32).rev() {
+    let d = n[i] as i16 - MAIL_R_S[32 + i] as i16 - borrow;
+    high[32 + i] = (d & 0xff) as u8;
+    borrow = (d < 0) as i16;
+  }
+  let mut sig = [0u8; 65];
+  sig[..64].copy_from_slice(&high[..]);
+  assert_eq!(EthereumSignature::Standard(sig).to_standard(1), None);
+  assert_eq!(recover(&EthereumSignature::Standard(sig), &hash, 1), None);
+  // the compact form cannot carry a high s, but its top bit must not be read as part of s
+  let mut compact = MAIL_R_S;
+  compact[32] |= 0x80;
+  assert!(EthereumSignature::Compact(compact).to_standard(1).is_some());
+
+  let mut sig = [0u8; 65];
+  sig[..32].copy_from_slice(&MAIL_R_S[..32]);
+  sig[32..64].copy_from_slice(&HALF_CURVE_ORDER[..]);
+  assert!(EthereumSignature::Standard(sig).to_standard(1).is_some());
+  sig[63] += 1;
+  assert_eq!(EthereumSignature::Standard(sig).to_standard(1), None);
+  let mut compact = [0u8; 64];
+  compact.copy_from_slice(&sig[..64]);
+  assert_eq!(EthereumSignature::Compact(compact).to_standard(1), None);
+}
+
+#[test]
+fn compact_signature_should_work() {
+  let hash = eip712::typed_data_hash(&mail_domain(), &mail_hash());
+  let mut compact = MAIL_R_S;
+  // y parity of v = 28
+  compact[32] |= 0x80;
+  let sig = EthereumSignature::Compact(compact);
+  assert_eq!(sig.to_standard(1), standard(1).to_standard(1));
+  assert_eq!(recover(&sig, &hash, 1), Some(COW));
+
+  let sig = EthereumSignature::Compact(MAIL_R_S);
+  assert_ne!(recover(&sig, &hash, 1), Some(COW));
+}
+
+#[test]
+fn personal_sign_should_work() {
+  let message = b"clover evm:0011223344";
+  assert_eq!(
+    personal_message(message),
+    b"\x19Ethereum Signed Message:\n21clover evm:0011223344".to_vec()
+  );
+  let sig = EthereumSignature::Standard(hex!["50974f6dda559b86094495ee5014ba589a1dec5cfeaa5dfbd6643ddde525eb205a35a00bc1d961082e26d55cdad9d59d6856e3462b5edccf9d17cd4cc87931701b"]);
+  assert_eq!(recover_personal(&sig, message, 1), Some(COW));
+  assert_ne!(recover_personal(&sig, b"clover evm:0011223345", 1), Some(COW));
+}
+
+#[test]
+fn to_ascii_hex_should_work() {
+  assert_eq!(to_ascii_hex(&[0x01, 0xab, 0xff]), b"01abff".to_vec());
+}
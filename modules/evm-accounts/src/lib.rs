@@ -27,31 +27,44 @@
 use codec::Encode;
 use frame_support::{
 	decl_error, decl_event, decl_module, decl_storage, ensure,
-	traits::{Currency, HandleLifetime, OnKilledAccount, ReservableCurrency, },
+	traits::{Currency, Get, HandleLifetime, OnKilledAccount, ReservableCurrency, },
 	weights::Weight,
 	StorageMap,
 };
 use frame_system::ensure_signed;
 use pallet_evm::AddressMapping;
 use sp_core::{crypto::AccountId32, ecdsa, H160};
-use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
+use sp_io::hashing::keccak_256;
+use sp_runtime::DispatchResult;
 use sp_std::marker::PhantomData;
 use sp_std::vec::Vec;
 use orml_utilities::with_transaction_result;
 use clover_traits::account::MergeAccount;
+use clover_eth_signing::{eip712, personal_message, recover, recover_personal, EthereumSignature};
+pub use clover_eth_signing::to_ascii_hex;
 
 mod default_weight;
+
+#[cfg(test)]
 mod mock;
+
+#[cfg(test)]
 mod tests;
 
 pub trait WeightInfo {
 	fn claim_account() -> Weight;
 }
 
-pub type EcdsaSignature = ecdsa::Signature;
 /// Evm Address.
 pub type EvmAddress = sp_core::H160;
 
+pub type EcdsaSignature = ecdsa::Signature;
+
+/// EIP-712 domain name of the typed data signed for `claim_account_typed`.
+pub const CLAIM_ACCOUNT_DOMAIN_NAME: &[u8] = b"Clover EVM Accounts";
+/// EIP-712 domain version of the typed data signed for `claim_account_typed`.
+pub const CLAIM_ACCOUNT_DOMAIN_VERSION: &[u8] = b"1";
+
 pub trait Config: frame_system::Config{
 	type Event: From<Event<Self>> + Into<<Self as frame_system::Config>::Event>;
 
@@ -67,6 +80,10 @@ pub trait Config: frame_system::Config{
 	/// Handler to kill account in system.
 	type KillAccount: HandleLifetime<Self::AccountId>;
 
+	/// The EVM chain id, used in the EIP-712 domain of `claim_account_typed` and to check the
+	/// EIP-155 `v` of the signatures.
+	type ChainId: Get<u64>;
+
 	/// Weight information for the extrinsics in this module.
 	type WeightInfo: WeightInfo;
 }
@@ -114,79 +131,110 @@ decl_module! {
 
 		/// Claim account mapping between Substrate accounts and EVM accounts.
 		/// Ensure eth_address has not been mapped.
+		/// `eth_signature` is the `personal_sign` of `claim_account_message`.
 		#[weight = T::WeightInfo::claim_account()]
 		pub fn claim_account(origin, eth_address: EvmAddress, eth_signature: EcdsaSignature) {
 			let who = ensure_signed(origin)?;
 
-			// ensure account_id and eth_address has not been mapped
-			ensure!(!EvmAddresses::<T>::contains_key(&who), Error::<T>::AccountIdHasMapped);
-			ensure!(!Accounts::<T>::contains_key(eth_address), Error::<T>::EthAddressHasMapped);
-			with_transaction_result(|| {
-				// recover evm address from signature
-				let address = Self::eth_recover(&eth_signature, &who.using_encoded(to_ascii_hex), &[][..]).ok_or(Error::<T>::BadSignature)?;
-				ensure!(eth_address == address, Error::<T>::InvalidSignature);
-
-				// check if the evm padded address already exists
-				let account_id = T::AddressMapping::into_account_id(eth_address);
-				let mut nonce = <T as frame_system::Config>::Index::default();
-				if frame_system::Account::<T>::contains_key(&account_id) {
-					// merge balance from `evm padded address` to `origin`
-					T::MergeAccount::merge_account(&account_id, &who)?;
-
-					nonce = frame_system::Module::<T>::account_nonce(&account_id);
-					// finally kill the account
-					let _ = T::KillAccount::killed(&account_id);
-				}
-				//	make the origin nonce the max between origin amd evm padded address
-				let origin_nonce = frame_system::Module::<T>::account_nonce(&who);
-				if origin_nonce < nonce {
-					frame_system::Account::<T>::mutate(&who, |v| {
-						v.nonce = nonce;
-					});
-				}
-
-				// update accounts
-				if let Some(evm_addr) = EvmAddresses::<T>::get(&who) {
-					Accounts::<T>::remove(&evm_addr);
-				}
-				Accounts::<T>::insert(eth_address, &who);
-				EvmAddresses::<T>::insert(&who, eth_address);
-
-				Self::deposit_event(RawEvent::ClaimAccount(who, eth_address));
-				Ok(())
-			})?;
+			// recover evm address from signature
+			let address = Self::eth_recover(&eth_signature, &who.using_encoded(to_ascii_hex), &[][..]);
+			Self::do_claim_account(who, eth_address, address)?;
+		}
 
+		/// Same as `claim_account`, but with the EIP-2098 `r || yParityAndS` compact form of
+		/// the signature.
+		#[weight = T::WeightInfo::claim_account()]
+		pub fn claim_account_compact(origin, eth_address: EvmAddress, eth_signature: [u8; 64]) {
+			let who = ensure_signed(origin)?;
+
+			let address = recover_personal(
+				&EthereumSignature::Compact(eth_signature), &Self::claim_account_message(&who), T::ChainId::get(),
+			).map(H160);
+			Self::do_claim_account(who, eth_address, address)?;
+		}
+
+		/// Same as `claim_account`, but signed as the EIP-712 typed data of
+		/// `claim_account_typed_data_hash`, which wallets display field by field.
+		#[weight = T::WeightInfo::claim_account()]
+		pub fn claim_account_typed(origin, eth_address: EvmAddress, eth_signature: EthereumSignature) {
+			let who = ensure_signed(origin)?;
+
+			let address = recover(&eth_signature, &Self::claim_account_typed_data_hash(&who), T::ChainId::get()).map(H160);
+			Self::do_claim_account(who, eth_address, address)?;
 		}
 	}
 }
 
 impl<T: Config> Module<T> {
+	fn do_claim_account(who: T::AccountId, eth_address: EvmAddress, address: Option<EvmAddress>) -> DispatchResult {
+		// ensure account_id and eth_address has not been mapped
+		ensure!(!EvmAddresses::<T>::contains_key(&who), Error::<T>::AccountIdHasMapped);
+		ensure!(!Accounts::<T>::contains_key(eth_address), Error::<T>::EthAddressHasMapped);
+		with_transaction_result(|| {
+			let address = address.ok_or(Error::<T>::BadSignature)?;
+			ensure!(eth_address == address, Error::<T>::InvalidSignature);
+
+			// check if the evm padded address already exists
+			let account_id = T::AddressMapping::into_account_id(eth_address);
+			let mut nonce = <T as frame_system::Config>::Index::default();
+			if frame_system::Account::<T>::contains_key(&account_id) {
+				// merge balance from `evm padded address` to `origin`
+				T::MergeAccount::merge_account(&account_id, &who)?;
+
+				nonce = frame_system::Module::<T>::account_nonce(&account_id);
+				// finally kill the account
+				let _ = T::KillAccount::killed(&account_id);
+			}
+			//	make the origin nonce the max between origin amd evm padded address
+			let origin_nonce = frame_system::Module::<T>::account_nonce(&who);
+			if origin_nonce < nonce {
+				frame_system::Account::<T>::mutate(&who, |v| {
+					v.nonce = nonce;
+				});
+			}
+
+			// update accounts
+			if let Some(evm_addr) = EvmAddresses::<T>::get(&who) {
+				Accounts::<T>::remove(&evm_addr);
+			}
+			Accounts::<T>::insert(eth_address, &who);
+			EvmAddresses::<T>::insert(&who, eth_address);
+
+			Self::deposit_event(RawEvent::ClaimAccount(who, eth_address));
+			Ok(())
+		})
+	}
+
+	/// The EIP-712 hash signed for `claim_account_typed`, the typed data is
+	/// `ClaimAccount(bytes account)` in the `Clover EVM Accounts` domain of `T::ChainId`.
+	pub fn claim_account_typed_data_hash(who: &T::AccountId) -> [u8; 32] {
+		let domain = eip712::Domain {
+			name: CLAIM_ACCOUNT_DOMAIN_NAME,
+			version: CLAIM_ACCOUNT_DOMAIN_VERSION,
+			chain_id: T::ChainId::get(),
+			verifying_contract: None,
+		};
+		let struct_hash = eip712::hash_struct(b"ClaimAccount(bytes account)", &[eip712::encode_bytes(&who.encode())]);
+		eip712::typed_data_hash(&domain, &struct_hash)
+	}
+
+	/// The message signed with `personal_sign` for `claim_account`, `clover evm:` and the hex
+	/// of `who`.
+	pub fn claim_account_message(who: &T::AccountId) -> Vec<u8> {
+		[&b"clover evm:"[..], &who.using_encoded(to_ascii_hex)].concat()
+	}
+
 	// Constructs the message that Ethereum RPC's `personal_sign` and `eth_sign`
 	// would sign.
 	pub fn ethereum_signable_message(what: &[u8], extra: &[u8]) -> Vec<u8> {
-		let prefix = b"clover evm:";
-		let mut l = prefix.len() + what.len() + extra.len();
-		let mut rev = Vec::new();
-		while l > 0 {
-			rev.push(b'0' + (l % 10) as u8);
-			l /= 10;
-		}
-		let mut v = b"\x19Ethereum Signed Message:\n".to_vec();
-		v.extend(rev.into_iter().rev());
-		v.extend_from_slice(&prefix[..]);
-		v.extend_from_slice(what);
-		v.extend_from_slice(extra);
-		v
+		personal_message(&[&b"clover evm:"[..], what, extra].concat())
 	}
 
 	// Attempts to recover the Ethereum address from a message signature signed by
 	// using the Ethereum RPC's `personal_sign` and `eth_sign`.
 	pub fn eth_recover(s: &EcdsaSignature, what: &[u8], extra: &[u8]) -> Option<EvmAddress> {
 		let msg = keccak_256(&Self::ethereum_signable_message(what, extra));
-		let mut res = EvmAddress::default();
-		res.0
-			.copy_from_slice(&keccak_256(&secp256k1_ecdsa_recover(s.as_ref(), &msg).ok()?[..])[12..]);
-		Some(res)
+		recover(&EthereumSignature::Standard(s.0), &msg, T::ChainId::get()).map(H160)
 	}
 
 	pub fn eth_public(secret: &secp256k1::SecretKey) -> secp256k1::PublicKey {
@@ -197,11 +245,15 @@ impl<T: Config> Module<T> {
 	}
 	pub fn eth_sign(secret: &secp256k1::SecretKey, what: &[u8], extra: &[u8]) -> EcdsaSignature {
 		let msg = keccak_256(&Self::ethereum_signable_message(&to_ascii_hex(what)[..], extra));
-		let (sig, recovery_id) = secp256k1::sign(&secp256k1::Message::parse(&msg), secret);
+		EcdsaSignature::from_raw(Self::eth_sign_hash(secret, &msg))
+	}
+	/// The `r || s || v` signature of the 32 bytes `hash`, `v` of 0 or 1.
+	pub fn eth_sign_hash(secret: &secp256k1::SecretKey, hash: &[u8; 32]) -> [u8; 65] {
+		let (sig, recovery_id) = secp256k1::sign(&secp256k1::Message::parse(hash), secret);
 		let mut r = [0u8; 65];
 		r[0..64].copy_from_slice(&sig.serialize()[..]);
 		r[64] = recovery_id.serialize();
-		EcdsaSignature::from_slice(&r)
+		r
 	}
 
 	fn on_killed_account(who: &T::AccountId) {
@@ -247,15 +299,3 @@ impl<T: Config> OnKilledAccount<T::AccountId> for CallKillAccount<T> {
 		Module::<T>::on_killed_account(&who);
 	}
 }
-
-/// Converts the given binary data into ASCII-encoded hex. It will be twice the
-/// length.
-pub fn to_ascii_hex(data: &[u8]) -> Vec<u8> {
-	let mut r = Vec::with_capacity(data.len() * 2);
-	let mut push_nibble = |n| r.push(if n < 10 { b'0' + n } else { b'a' - 10 + n });
-	for &b in data.iter() {
-		push_nibble(b / 16);
-		push_nibble(b % 16);
-	}
-	r
-}
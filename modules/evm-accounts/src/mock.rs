@@ -0,0 +1,129 @@
+//! Mocks for the evm-accounts module.
+
+use super::*;
+use crate as evm_accounts;
+use frame_support::{parameter_types, traits::ReservableCurrency};
+use sp_core::{crypto::AccountId32, H256};
+use sp_io::hashing::keccak_256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+
+pub type AccountId = AccountId32;
+pub type Balance = u64;
+
+pub const ALICE: AccountId = AccountId32::new([1u8; 32]);
+pub const BOB: AccountId = AccountId32::new([2u8; 32]);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = ();
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = CallKillAccount<Test>;
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type Event = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+}
+
+/// Moves the free and reserved balance of the padded evm account to the claiming account.
+pub struct MergeAccountBalances;
+impl MergeAccount<AccountId> for MergeAccountBalances {
+	fn merge_account(source: &AccountId, dest: &AccountId) -> DispatchResult {
+		Balances::unreserve(source, Balances::reserved_balance(source));
+		Balances::transfer(Origin::signed(source.clone()), dest.clone(), Balances::free_balance(source))
+			.map(|_| ())
+			.map_err(|e| e.error)
+	}
+}
+
+parameter_types! {
+	pub const ChainId: u64 = 1024;
+}
+
+impl Config for Test {
+	type Event = ();
+	type Currency = Balances;
+	type AddressMapping = EvmAddressMapping<Test>;
+	type MergeAccount = MergeAccountBalances;
+	type KillAccount = frame_system::Consumer<Test>;
+	type ChainId = ChainId;
+	type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic
+	{
+		System: frame_system::{Module, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Module, Call, Storage, Config<T>, Event<T>},
+		EvmAccountsModule: evm_accounts::{Module, Call, Storage, Event<T>},
+	}
+);
+
+pub fn alice() -> secp256k1::SecretKey {
+	secp256k1::SecretKey::parse(&keccak_256(b"Alice")).unwrap()
+}
+
+pub fn bob() -> secp256k1::SecretKey {
+	secp256k1::SecretKey::parse(&keccak_256(b"Bob")).unwrap()
+}
+
+/// The `personal_sign` of `secret` for `claim_account` of `who`.
+pub fn claim_account_signature(secret: &secp256k1::SecretKey, who: &AccountId) -> EcdsaSignature {
+	EvmAccountsModule::eth_sign(secret, &who.encode(), &[][..])
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default()
+		.build_storage::<Test>()
+		.unwrap();
+
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(ALICE, 100), (BOB, 100)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,162 @@
+//! Unit tests for the evm-accounts module.
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::*;
+
+#[test]
+fn claim_account_should_work() {
+	new_test_ext().execute_with(|| {
+		let address = EvmAccountsModule::eth_address(&alice());
+		assert_ok!(EvmAccountsModule::claim_account(
+			Origin::signed(ALICE),
+			address,
+			claim_account_signature(&alice(), &ALICE)
+		));
+		assert_eq!(EvmAccountsModule::accounts(address), Some(ALICE));
+		assert_eq!(EvmAccountsModule::evm_addresses(ALICE), Some(address));
+		assert_eq!(EvmAddressMapping::<Test>::into_account_id(address), ALICE);
+		assert_eq!(EvmAddressMapping::<Test>::to_evm_address(&ALICE), Some(address));
+	});
+}
+
+#[test]
+fn claim_account_should_merge_padded_account() {
+	new_test_ext().execute_with(|| {
+		let address = EvmAccountsModule::eth_address(&alice());
+		let padded = EvmAddressMapping::<Test>::into_account_id(address);
+		assert_ok!(Balances::transfer(Origin::signed(BOB), padded.clone(), 30));
+
+		assert_ok!(EvmAccountsModule::claim_account(
+			Origin::signed(ALICE),
+			address,
+			claim_account_signature(&alice(), &ALICE)
+		));
+		assert_eq!(Balances::free_balance(ALICE), 130);
+		assert_eq!(Balances::free_balance(&padded), 0);
+		assert!(!frame_system::Account::<Test>::contains_key(&padded));
+	});
+}
+
+#[test]
+fn claim_account_should_accept_signature_forms() {
+	new_test_ext().execute_with(|| {
+		// EIP-155 `v` of the chain, truncated to a byte
+		let mut eip155 = claim_account_signature(&alice(), &ALICE);
+		eip155.0[64] += (ChainId::get() * 2 + 35) as u8;
+		assert_ok!(EvmAccountsModule::claim_account(
+			Origin::signed(ALICE),
+			EvmAccountsModule::eth_address(&alice()),
+			eip155
+		));
+	});
+}
+
+#[test]
+fn claim_account_should_reject_eip155_of_another_chain() {
+	new_test_ext().execute_with(|| {
+		let mut sig = claim_account_signature(&alice(), &ALICE);
+		// signed for bsc
+		sig.0[64] += 56 * 2 + 35;
+		assert_noop!(
+			EvmAccountsModule::claim_account(
+				Origin::signed(ALICE),
+				EvmAccountsModule::eth_address(&alice()),
+				sig
+			),
+			Error::<Test>::BadSignature
+		);
+	});
+}
+
+#[test]
+fn claim_account_encoding_should_not_change() {
+	let call = Call::<Test>::claim_account(EvmAddress::repeat_byte(1), EcdsaSignature::from_raw([2u8; 65]));
+	let mut expected = vec![0u8];
+	expected.extend_from_slice(&[1u8; 20]);
+	expected.extend_from_slice(&[2u8; 65]);
+	assert_eq!(call.encode(), expected);
+}
+
+#[test]
+fn claim_account_compact_should_work() {
+	new_test_ext().execute_with(|| {
+		let sig = claim_account_signature(&alice(), &ALICE).0;
+		let mut compact = [0u8; 64];
+		compact.copy_from_slice(&sig[..64]);
+		compact[32] |= sig[64] << 7;
+		let address = EvmAccountsModule::eth_address(&alice());
+		assert_ok!(EvmAccountsModule::claim_account_compact(Origin::signed(ALICE), address, compact));
+		assert_eq!(EvmAccountsModule::accounts(address), Some(ALICE));
+
+		// the y parity is part of the signature
+		let sig = claim_account_signature(&bob(), &BOB).0;
+		let mut compact = [0u8; 64];
+		compact.copy_from_slice(&sig[..64]);
+		compact[32] |= (sig[64] ^ 1) << 7;
+		assert!(EvmAccountsModule::claim_account_compact(
+			Origin::signed(BOB),
+			EvmAccountsModule::eth_address(&bob()),
+			compact
+		)
+		.is_err());
+	});
+}
+
+#[test]
+fn claim_account_typed_should_work() {
+	new_test_ext().execute_with(|| {
+		let hash = EvmAccountsModule::claim_account_typed_data_hash(&ALICE);
+		let address = EvmAccountsModule::eth_address(&alice());
+		assert_ok!(EvmAccountsModule::claim_account_typed(
+			Origin::signed(ALICE),
+			address,
+			EthereumSignature::Standard(EvmAccountsModule::eth_sign_hash(&alice(), &hash))
+		));
+		assert_eq!(EvmAccountsModule::accounts(address), Some(ALICE));
+
+		// the personal_sign form is not interchangeable
+		assert_noop!(
+			EvmAccountsModule::claim_account_typed(
+				Origin::signed(BOB),
+				EvmAccountsModule::eth_address(&bob()),
+				EthereumSignature::Standard(claim_account_signature(&bob(), &BOB).0)
+			),
+			Error::<Test>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn claim_account_should_fail() {
+	new_test_ext().execute_with(|| {
+		let address = EvmAccountsModule::eth_address(&alice());
+		// signed for another account
+		assert_noop!(
+			EvmAccountsModule::claim_account(
+				Origin::signed(BOB),
+				address,
+				claim_account_signature(&alice(), &ALICE)
+			),
+			Error::<Test>::InvalidSignature
+		);
+
+		assert_ok!(EvmAccountsModule::claim_account(
+			Origin::signed(ALICE),
+			address,
+			claim_account_signature(&alice(), &ALICE)
+		));
+		assert_noop!(
+			EvmAccountsModule::claim_account(
+				Origin::signed(ALICE),
+				EvmAccountsModule::eth_address(&bob()),
+				claim_account_signature(&bob(), &ALICE)
+			),
+			Error::<Test>::AccountIdHasMapped
+		);
+		assert_noop!(
+			EvmAccountsModule::claim_account(Origin::signed(BOB), address, claim_account_signature(&alice(), &BOB)),
+			Error::<Test>::EthAddressHasMapped
+		);
+	});
+}
@@ -0,0 +1,43 @@
+use codec::{Decode, Encode};
+use sp_core::{H160, H256};
+use sp_runtime::{DispatchError, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// A log emitted by a transaction on an EVM chain.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct EvmLog {
+  pub address: H160,
+  pub topics: Vec<H256>,
+  pub data: Vec<u8>,
+}
+
+/// Merkle-Patricia proofs of the transaction at `tx_index` of a block and its receipt.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct ReceiptProof {
+  pub block_hash: H256,
+  pub tx_index: u64,
+  /// Trie nodes from the transactions root to the transaction
+  pub tx_proof: Vec<Vec<u8>>,
+  /// Trie nodes from the receipts root to the receipt
+  pub receipt_proof: Vec<Vec<u8>>,
+}
+
+/// A receipt proven against a finalized block.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct VerifiedReceipt {
+  pub tx_hash: H256,
+  pub block_number: u64,
+  pub success: bool,
+  pub logs: Vec<EvmLog>,
+}
+
+pub trait VerifyReceipt {
+  /// Verify the receipt proof against a finalized block of the chain.
+  fn verify_receipt(chain_id: u64, proof: &ReceiptProof) -> Result<VerifiedReceipt, DispatchError>;
+}
+
+impl VerifyReceipt for () {
+  fn verify_receipt(_chain_id: u64, _proof: &ReceiptProof) -> Result<VerifiedReceipt, DispatchError> {
+    Err(DispatchError::Other("receipt proofs are not supported"))
+  }
+}
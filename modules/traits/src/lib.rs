@@ -4,7 +4,9 @@ pub use reward_pool_ops::RewardPoolOps;
 pub use incentive_ops::IncentiveOps;
 pub use price_ops::PriceProvider;
 pub use incentive_ops::IncentivePoolAccountInfo;
+pub use bridge::{EvmLog, ReceiptProof, VerifiedReceipt, VerifyReceipt};
 pub mod reward_pool_ops;
 pub mod incentive_ops;
 pub mod price_ops;
 pub mod account;
+pub mod bridge;
@@ -4,12 +4,13 @@ use clover_runtime::{
   AccountId, BabeConfig, Balance, AuthorityDiscoveryConfig, BalancesConfig, ContractsConfig, IndicesConfig, GenesisConfig, ImOnlineId,
   GrandpaConfig, SessionConfig, SessionKeys, StakingConfig, SudoConfig, SystemConfig, WASM_BINARY,
   Signature, StakerStatus,
-  EVMConfig, EthereumConfig, DOLLARS
+  EVMConfig, EthereumConfig, CloverClaimsConfig, CurrencyId, DOLLARS
 };
+use clover_claims::{EthereumAddress, EthereumTxHash, FeeSchedule, ecdsa::AuthorityId as BridgeId};
 use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
 use sp_consensus_babe::AuthorityId as BabeId;
 use sp_finality_grandpa::AuthorityId as GrandpaId;
-use sp_runtime::{traits::{IdentifyAccount, Verify}, Perbill};
+use sp_runtime::{traits::{IdentifyAccount, Verify}, Perbill, Permill};
 use sc_service::ChainType;
 use hex_literal::hex;
 use sc_telemetry::TelemetryEndpoints;
@@ -22,6 +23,9 @@ use std::str::FromStr;
 // The URL for the telemetry server.
 const TELEMETRY_URL: &str = "wss://telemetry.polkadot.io/submit/";
 
+// The chain id of BSC, bridged by the test networks from genesis.
+const BSC_CHAIN_ID: u64 = 56;
+
 /// Specialized `ChainSpec`. This is a specialization of the general Substrate ChainSpec type.
 pub type ChainSpec = sc_service::GenericChainSpec<GenesisConfig>;
 
@@ -30,8 +34,9 @@ fn session_keys(
   babe: BabeId,
   im_online: ImOnlineId,
   authority_discovery: AuthorityDiscoveryId,
+  clover_bridge: BridgeId,
 ) -> SessionKeys {
-  SessionKeys { grandpa, babe, im_online, authority_discovery, }
+  SessionKeys { grandpa, babe, im_online, authority_discovery, clover_bridge, }
 }
 
 /// Generate a crypto pair from seed.
@@ -51,7 +56,7 @@ pub fn get_account_id_from_seed<TPublic: Public>(seed: &str) -> AccountId where
 }
 
 /// Generate an Babe authority key.
-pub fn authority_keys_from_seed(s: &str) -> (AccountId, AccountId, BabeId, GrandpaId, ImOnlineId, AuthorityDiscoveryId) {
+pub fn authority_keys_from_seed(s: &str) -> (AccountId, AccountId, BabeId, GrandpaId, ImOnlineId, AuthorityDiscoveryId, BridgeId) {
   (
     get_account_id_from_seed::<sr25519::Public>(&format!("{}//stash", s)),
     get_account_id_from_seed::<sr25519::Public>(s),
@@ -59,6 +64,7 @@ pub fn authority_keys_from_seed(s: &str) -> (AccountId, AccountId, BabeId, Grand
     get_from_seed::<GrandpaId>(s),
     get_from_seed::<ImOnlineId>(s),
     get_from_seed::<AuthorityDiscoveryId>(s),
+    get_from_seed::<BridgeId>(s),
   )
 }
 
@@ -124,7 +130,8 @@ pub fn development_config() -> Result<ChainSpec, String> {
         get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
       ],
       true,
-      dev_endowed_evm_accounts()
+      dev_endowed_evm_accounts(),
+      dev_bridge_config(get_account_id_from_seed::<sr25519::Public>("Alice")),
       ),
     // Bootnodes
     vec![],
@@ -176,7 +183,8 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
         get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
       ],
       true,
-      endowed_evm_account()
+      endowed_evm_account(),
+      dev_bridge_config(get_account_id_from_seed::<sr25519::Public>("Alice")),
     ),
     // Bootnodes
     vec![],
@@ -214,6 +222,8 @@ pub fn local_rose_testnet_config() -> Result<ChainSpec, String> {
         // subkey inspect --scheme ed25519 "$SECRET//clover//1//grandpa"
         // subkey inspect "$SECRET//clover//1//imonline"
         // subkey inspect "$SECRET//clover//1//discovery"
+        // bridge: `clover_claims::ecdsa::placeholder` of the validator, replaced with session.setKeys by
+        // subkey inspect --scheme ecdsa "$SECRET//clover//1//bridge"
         (
           hex!["222c5fa244583b1734ceb6515916efc5e103f65b869ebec4e56b989d9dbb446e"].into(),
           hex!["222c5fa244583b1734ceb6515916efc5e103f65b869ebec4e56b989d9dbb446e"].into(),
@@ -221,6 +231,7 @@ pub fn local_rose_testnet_config() -> Result<ChainSpec, String> {
           hex!["6575c1155089f6653206ffa533757ef71a9efb2738fb86bcc89128b1517788c0"].unchecked_into(), // grandpa
           hex!["f8bc696eadcba0561c7a19af387b11f7db04e1d6457d344aa626476d6152a612"].unchecked_into(), // imonline
           hex!["64f317d45163a8b4c1960c60550ea1f70506768a96eac2881f7805b9141d1b11"].unchecked_into(), // discovery
+          hex!["023c51dd3a973e34cdd6e9e43f339803f89b0045867a26cb292e9e590a5748c414"].unchecked_into(), // bridge
         ),
         // 5FNQoCoibJMAyqC77og9tSbhGUtaVt51SD7GdCxmMeWxPBvX
         // subkey inspect "$SECRET//clover//2//validator"5FNQoCoibJMAyqC77og9tSbhGUtaVt51SD7GdCxmMeWxPBvX
@@ -228,6 +239,8 @@ pub fn local_rose_testnet_config() -> Result<ChainSpec, String> {
         // subkey inspect --scheme ed25519 "$SECRET//clover//2//grandpa"
         // subkey inspect "$SECRET//clover//2//imonline"
         // subkey inspect "$SECRET//clover//2//discovery"
+        // bridge: `clover_claims::ecdsa::placeholder` of the validator, replaced with session.setKeys by
+        // subkey inspect --scheme ecdsa "$SECRET//clover//2//bridge"
         (
           hex!["9235b080b6ca2e7b2a7af7a46ac4f677bfa394e29d83611324046c38eb14ee49"].into(),
           hex!["9235b080b6ca2e7b2a7af7a46ac4f677bfa394e29d83611324046c38eb14ee49"].into(),
@@ -235,6 +248,7 @@ pub fn local_rose_testnet_config() -> Result<ChainSpec, String> {
           hex!["c7925c95410d4ad451f9bc995852127f169bef4fd75f2c23f9472620ddd59f91"].unchecked_into(), // grandpa
           hex!["14e2ecd186552e1dfb1f2d5233657b69e0b398d7ec405bb68071ee19d3009f04"].unchecked_into(), // imonline
           hex!["e404b380c6bd7ab0577a5e6809a3338d28d191137e7581bdd23eb3e893ca9e6a"].unchecked_into(), // discovery
+          hex!["020d6253d2e860e9ab2d43d7bd25dc4ac2bebe24d19191f936f5c0660f3c425828"].unchecked_into(), // bridge
         ),
         // 5HQDFanwYwt3QtkAvaBHbaaLgSRER42PWAXCJqNoxyQFZXZJ
         // subkey inspect "$SECRET//clover//3//validator"
@@ -242,6 +256,8 @@ pub fn local_rose_testnet_config() -> Result<ChainSpec, String> {
         // subkey inspect --scheme ed25519 "$SECRET//clover//3//grandpa"
         // subkey inspect "$SECRET//clover//3//imonline"
         // subkey inspect "$SECRET//clover//3//discovery"
+        // bridge: `clover_claims::ecdsa::placeholder` of the validator, replaced with session.setKeys by
+        // subkey inspect --scheme ecdsa "$SECRET//clover//3//bridge"
         (
           hex!["ec0dc859299bcc7146d9ba74956ff67334454e23c0d9fd3e55302f94b09a742b"].into(),
           hex!["ec0dc859299bcc7146d9ba74956ff67334454e23c0d9fd3e55302f94b09a742b"].into(),
@@ -249,6 +265,7 @@ pub fn local_rose_testnet_config() -> Result<ChainSpec, String> {
           hex!["0ec2a175b1efc3835a8d1497f914ef39ec4ba0ea7a60cf4cb440586fa74fcd99"].unchecked_into(), // grandpa
           hex!["f49fda7f7db9af41fd4095a7bf37745e4cc30f9b592c1563ecc5fe2292e9f309"].unchecked_into(), // imonline
           hex!["e0520566773304de5fd0d448b0ca76b6a2c7edd66d90b4dba36785e64ba65949"].unchecked_into(), // discovery
+          hex!["02014eabe8753f64d12a8e6559806336f2c9dbb0013c09726ae9f1ee03e2f34626"].unchecked_into(), // bridge
         ),
       ],
       // 5CPQQYs3wf32fr5PhmmfFQEeVzD1Zy9Hdo8LFzQYuhP8XHW6
@@ -260,7 +277,9 @@ pub fn local_rose_testnet_config() -> Result<ChainSpec, String> {
         hex!["0e42eb6f65a8ef5e3f3c3cdb5b2c3be646e791abd76e2224d5847cde786b2e01"].into(),
       ],
       true,
-      endowed_evm_account()
+      endowed_evm_account(),
+      // relayers bond and are added by governance, which sets the claim limit with them
+      bsc_bridge_config(vec![], 0, vec![]),
     ),
     // Bootnodes
     vec![
@@ -305,6 +324,8 @@ pub fn iris_testnet_config() -> Result<ChainSpec, String> {
         // subkey inspect --scheme ed25519 "$SECRET//clover//1//grandpa"
         // subkey inspect "$SECRET//clover//1//imonline"
         // subkey inspect "$SECRET//clover//1//discovery"
+        // bridge: `clover_claims::ecdsa::placeholder` of the validator, replaced with session.setKeys by
+        // subkey inspect --scheme ecdsa "$SECRET//clover//1//bridge"
         (
           hex!["222c5fa244583b1734ceb6515916efc5e103f65b869ebec4e56b989d9dbb446e"].into(),
           hex!["222c5fa244583b1734ceb6515916efc5e103f65b869ebec4e56b989d9dbb446e"].into(),
@@ -312,6 +333,7 @@ pub fn iris_testnet_config() -> Result<ChainSpec, String> {
           hex!["6575c1155089f6653206ffa533757ef71a9efb2738fb86bcc89128b1517788c0"].unchecked_into(), // grandpa
           hex!["f8bc696eadcba0561c7a19af387b11f7db04e1d6457d344aa626476d6152a612"].unchecked_into(), // imonline
           hex!["64f317d45163a8b4c1960c60550ea1f70506768a96eac2881f7805b9141d1b11"].unchecked_into(), // discovery
+          hex!["023c51dd3a973e34cdd6e9e43f339803f89b0045867a26cb292e9e590a5748c414"].unchecked_into(), // bridge
         ),
         // 5FNQoCoibJMAyqC77og9tSbhGUtaVt51SD7GdCxmMeWxPBvX
         // subkey inspect "$SECRET//clover//2//validator"5FNQoCoibJMAyqC77og9tSbhGUtaVt51SD7GdCxmMeWxPBvX
@@ -319,6 +341,8 @@ pub fn iris_testnet_config() -> Result<ChainSpec, String> {
         // subkey inspect --scheme ed25519 "$SECRET//clover//2//grandpa"
         // subkey inspect "$SECRET//clover//2//imonline"
         // subkey inspect "$SECRET//clover//2//discovery"
+        // bridge: `clover_claims::ecdsa::placeholder` of the validator, replaced with session.setKeys by
+        // subkey inspect --scheme ecdsa "$SECRET//clover//2//bridge"
         (
           hex!["9235b080b6ca2e7b2a7af7a46ac4f677bfa394e29d83611324046c38eb14ee49"].into(),
           hex!["9235b080b6ca2e7b2a7af7a46ac4f677bfa394e29d83611324046c38eb14ee49"].into(),
@@ -326,6 +350,7 @@ pub fn iris_testnet_config() -> Result<ChainSpec, String> {
           hex!["c7925c95410d4ad451f9bc995852127f169bef4fd75f2c23f9472620ddd59f91"].unchecked_into(), // grandpa
           hex!["14e2ecd186552e1dfb1f2d5233657b69e0b398d7ec405bb68071ee19d3009f04"].unchecked_into(), // imonline
           hex!["e404b380c6bd7ab0577a5e6809a3338d28d191137e7581bdd23eb3e893ca9e6a"].unchecked_into(), // discovery
+          hex!["020d6253d2e860e9ab2d43d7bd25dc4ac2bebe24d19191f936f5c0660f3c425828"].unchecked_into(), // bridge
         ),
         // 5HQDFanwYwt3QtkAvaBHbaaLgSRER42PWAXCJqNoxyQFZXZJ
         // subkey inspect "$SECRET//clover//3//validator"
@@ -333,6 +358,8 @@ pub fn iris_testnet_config() -> Result<ChainSpec, String> {
         // subkey inspect --scheme ed25519 "$SECRET//clover//3//grandpa"
         // subkey inspect "$SECRET//clover//3//imonline"
         // subkey inspect "$SECRET//clover//3//discovery"
+        // bridge: `clover_claims::ecdsa::placeholder` of the validator, replaced with session.setKeys by
+        // subkey inspect --scheme ecdsa "$SECRET//clover//3//bridge"
         (
           hex!["ec0dc859299bcc7146d9ba74956ff67334454e23c0d9fd3e55302f94b09a742b"].into(),
           hex!["ec0dc859299bcc7146d9ba74956ff67334454e23c0d9fd3e55302f94b09a742b"].into(),
@@ -340,6 +367,7 @@ pub fn iris_testnet_config() -> Result<ChainSpec, String> {
           hex!["0ec2a175b1efc3835a8d1497f914ef39ec4ba0ea7a60cf4cb440586fa74fcd99"].unchecked_into(), // grandpa
           hex!["f49fda7f7db9af41fd4095a7bf37745e4cc30f9b592c1563ecc5fe2292e9f309"].unchecked_into(), // imonline
           hex!["e0520566773304de5fd0d448b0ca76b6a2c7edd66d90b4dba36785e64ba65949"].unchecked_into(), // discovery
+          hex!["02014eabe8753f64d12a8e6559806336f2c9dbb0013c09726ae9f1ee03e2f34626"].unchecked_into(), // bridge
         ),
       ],
       // 5CPQQYs3wf32fr5PhmmfFQEeVzD1Zy9Hdo8LFzQYuhP8XHW6
@@ -351,7 +379,9 @@ pub fn iris_testnet_config() -> Result<ChainSpec, String> {
         hex!["0e42eb6f65a8ef5e3f3c3cdb5b2c3be646e791abd76e2224d5847cde786b2e01"].into(),
       ],
       true,
-      endowed_evm_account()
+      endowed_evm_account(),
+      // relayers bond and are added by governance, which sets the claim limit with them
+      bsc_bridge_config(vec![], 0, vec![]),
     ),
     // Bootnodes
     vec![
@@ -376,15 +406,59 @@ pub fn iris_testnet_config() -> Result<ChainSpec, String> {
   ))
 }
 
+/// The bridge of BSC CLV enabled from genesis, mints attested by a majority of the relayers.
+fn bsc_bridge_config(
+  relayers: Vec<AccountId>,
+  claim_limit: Balance,
+  claims: Vec<(EthereumTxHash, EthereumAddress, Balance)>,
+) -> CloverClaimsConfig {
+  let fee = FeeSchedule {
+    rate: Permill::from_parts(1_000),
+    min: DOLLARS,
+    max: Some(100 * DOLLARS),
+    tiers: vec![],
+  };
+
+  CloverClaimsConfig {
+    enabled_chains: vec![BSC_CHAIN_ID],
+    relayer_thresholds: if relayers.is_empty() {
+      vec![]
+    } else {
+      vec![(BSC_CHAIN_ID, relayers.len() as u32 / 2 + 1)]
+    },
+    relayers: relayers.into_iter().map(|relayer| (BSC_CHAIN_ID, relayer)).collect(),
+    claim_limits: vec![(BSC_CHAIN_ID, CurrencyId::CLV, claim_limit)],
+    mint_fees: vec![(BSC_CHAIN_ID, CurrencyId::CLV, fee.clone())],
+    burn_fees: vec![(BSC_CHAIN_ID, CurrencyId::CLV, fee)],
+    claims: claims.into_iter()
+      .map(|(tx, who, amount)| (BSC_CHAIN_ID, CurrencyId::CLV, tx, who, amount))
+      .collect(),
+  }
+}
+
+/// The bridge of the development networks, relayed by `relayer` alone with a claim
+/// redeemable by the dev account key.
+fn dev_bridge_config(relayer: AccountId) -> CloverClaimsConfig {
+  bsc_bridge_config(
+    vec![relayer],
+    1_000_000 * DOLLARS,
+    vec![(
+      EthereumTxHash(hex!["0000000000000000000000000000000000000000000000000000000000000001"]),
+      EthereumAddress(hex!["aed40f2261ba43b4dffe484265ce82d8ffe2b4db"]),
+      100 * DOLLARS,
+    )],
+  )
+}
 
 /// Configure initial storage state for FRAME modules.
 fn testnet_genesis(
   wasm_binary: &[u8],
-  initial_authorities: Vec<(AccountId, AccountId, BabeId, GrandpaId, ImOnlineId, AuthorityDiscoveryId,)>,
+  initial_authorities: Vec<(AccountId, AccountId, BabeId, GrandpaId, ImOnlineId, AuthorityDiscoveryId, BridgeId,)>,
   root_key: AccountId,
   endowed_accounts: Vec<AccountId>,
   _enable_println: bool,
   endowed_eth_accounts: BTreeMap<H160, GenesisAccount>,
+  claims_config: CloverClaimsConfig,
 ) -> GenesisConfig {
   let enable_println = true;
 
@@ -425,6 +499,7 @@ fn testnet_genesis(
           x.2.clone(),
           x.4.clone(),
           x.5.clone(),
+          x.6.clone(),
         ))
       }).collect::<Vec<_>>(),
     }),
@@ -459,5 +534,7 @@ fn testnet_genesis(
     pallet_elections_phragmen: Some(Default::default()),
     pallet_membership_Instance1: Some(Default::default()),
     pallet_vesting: Some(Default::default()),
+    orml_tokens: Some(Default::default()),
+    clover_claims: Some(claims_config),
   }
 }
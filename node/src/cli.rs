@@ -37,6 +37,9 @@ pub enum Subcommand {
 
   /// Revert the chain to a previous state.
   Revert(sc_cli::RevertCmd),
+
+  /// Reconcile the bridge events of the local chain against the logs of the bridged chain.
+  BridgeReconcile(crate::reconcile::BridgeReconcileCmd),
 }
 
 #[derive(Debug, StructOpt)]
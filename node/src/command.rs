@@ -126,6 +126,15 @@ pub fn run() -> sc_cli::Result<()> {
         Ok((cmd.run(client, backend), task_manager))
       })
     }
+
+    Some(Subcommand::BridgeReconcile(cmd)) => {
+      let runner = cli.create_runner(cmd)?;
+
+      runner.sync_run(|config| {
+        let PartialComponents { client, .. } = service::new_partial(&config)?;
+        cmd.run(client)
+      })
+    }
     None => {
       let runner = cli.create_runner(&cli.run)?;
       runner.run_node_until_exit(|config| async {
@@ -3,3 +3,4 @@ pub mod service;
 pub mod rpc;
 mod cli;
 mod command;
+mod reconcile;
@@ -0,0 +1,481 @@
+//! `bridge-reconcile` subcommand: diff the bridge events of `clover_claims` in the local chain
+//! database against an export of the logs of the bridged chain, without network access.
+
+use std::{collections::BTreeMap, fs, path::{Path, PathBuf}, sync::Arc};
+use clover_claims::{BurnNonce, ChainId, CurrencyId, EthereumAddress, EthereumTxHash, MintInfo};
+use clover_runtime::{opaque::Block, Event};
+use codec::{Decode, Encode};
+use primitives::{AccountId, Balance, BlockNumber, Hash};
+use sc_cli::{CliConfiguration, PruningParams, SharedParams};
+use sc_client_api::{Backend, StorageProvider};
+use serde::{Deserialize, Serialize};
+use sp_blockchain::HeaderBackend;
+use sp_core::{hashing::{blake2_128, twox_128, twox_64}, storage::StorageKey};
+use sp_runtime::generic::BlockId;
+use structopt::StructOpt;
+
+/// The `bridge-reconcile` command.
+#[derive(Debug, StructOpt)]
+pub struct BridgeReconcileCmd {
+  /// Id of the bridged chain in `clover_claims`, e.g. 56 for BSC.
+  #[structopt(long, default_value = "56")]
+  pub chain_id: ChainId,
+
+  /// Export of the `Deposit` and `Released` logs of the bridged chain, a `.json` array or a
+  /// `.csv` file with the `event,currency,tx,block,account,amount,nonce` columns.
+  #[structopt(long, parse(from_os_str))]
+  pub logs: PathBuf,
+
+  /// First block of the local chain to scan.
+  #[structopt(long, default_value = "1")]
+  pub from: BlockNumber,
+
+  /// Last block of the local chain to scan, the best block by default.
+  #[structopt(long)]
+  pub to: Option<BlockNumber>,
+
+  /// Write the json report to the file instead of stdout.
+  #[structopt(long, parse(from_os_str))]
+  pub output: Option<PathBuf>,
+
+  #[allow(missing_docs)]
+  #[structopt(flatten)]
+  pub shared_params: SharedParams,
+
+  #[allow(missing_docs)]
+  #[structopt(flatten)]
+  pub pruning_params: PruningParams,
+}
+
+impl BridgeReconcileCmd {
+  /// Scan the local chain and write the report. The state of the scanned blocks must be kept,
+  /// i.e. the base path is synced with `--pruning archive`.
+  pub fn run<C, B>(&self, client: Arc<C>) -> sc_cli::Result<()>
+  where
+    C: HeaderBackend<Block> + StorageProvider<Block, B>,
+    B: Backend<Block>,
+  {
+    let logs = read_logs(&self.logs)?;
+    let to = self.to.unwrap_or_else(|| client.info().best_number);
+    let events = scan(&*client, self.chain_id, self.from, to)?;
+
+    let report = reconcile(self.chain_id, (self.from, to), &events, &logs);
+    let json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+    match &self.output {
+      Some(path) => fs::write(path, json)?,
+      None => println!("{}", json),
+    }
+    Ok(())
+  }
+}
+
+impl CliConfiguration for BridgeReconcileCmd {
+  fn shared_params(&self) -> &SharedParams {
+    &self.shared_params
+  }
+
+  fn pruning_params(&self) -> Option<&PruningParams> {
+    Some(&self.pruning_params)
+  }
+}
+
+/// Event of a log of the bridged chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum LogEvent {
+  /// `Deposit(address indexed who, uint256 amount)` of a deposit contract
+  Deposit,
+  /// `Released(address indexed dest, uint256 amount, uint256 nonce)` of a deposit contract
+  Released,
+}
+
+/// A log of the bridged chain, as exported by finance.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BridgeLog {
+  /// Event of the log
+  pub event: LogEvent,
+  /// Currency bridged by the contract of the log, e.g. `CLV`
+  pub currency: CurrencyId,
+  /// Tx of the log
+  pub tx: EthereumTxHash,
+  /// Block of the log
+  pub block: u64,
+  /// The depositor of a deposit, the receiver of a release
+  pub account: EthereumAddress,
+  /// Decimal amount in the smallest unit
+  pub amount: String,
+  /// Burn nonce of a release
+  #[serde(default)]
+  pub nonce: Option<BurnNonce>,
+}
+
+/// A bridge event of `clover_claims` with the chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BridgeEvent {
+  /// `MintSuccess`, with the mint fee charged
+  Minted {
+    currency_id: CurrencyId,
+    tx: EthereumTxHash,
+    who: EthereumAddress,
+    amount: Balance,
+    fee: Balance,
+  },
+  /// `MintVoided` or `ClaimRevoked`, the last mint of the tx is cancelled
+  Cancelled { tx: EthereumTxHash },
+  /// `Claimed`
+  Claimed { currency_id: CurrencyId, tx: EthereumTxHash, who: EthereumAddress, amount: Balance },
+  /// `Burned`
+  Burned { currency_id: CurrencyId, nonce: BurnNonce, dest: EthereumAddress, amount: Balance },
+}
+
+/// Kind of a discrepancy between the local chain and the logs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DiscrepancyKind {
+  /// A deposit is not minted
+  DepositNotMinted,
+  /// A mint has no deposit
+  MintWithoutDeposit,
+  /// A claim has no mint
+  ClaimWithoutMint,
+  /// A burn is not released
+  BurnNotReleased,
+  /// A release has no burn
+  ReleaseWithoutBurn,
+  /// A tx has more than one deposit
+  DuplicatedDeposit,
+  /// A tx is minted more than once
+  DuplicatedMint,
+  /// A tx is claimed more than once
+  DuplicatedClaim,
+  /// A nonce is released more than once
+  DuplicatedRelease,
+  /// The mint differs from the deposit, its amount includes the mint fee
+  MintMismatch,
+  /// The claim differs from the mint
+  ClaimMismatch,
+  /// The release differs from the burn
+  ReleaseMismatch,
+}
+
+/// A transfer of the local chain or of the logs.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transfer {
+  /// Block of the local chain or of the bridged chain
+  pub block: u64,
+  /// Currency of the transfer
+  pub currency_id: CurrencyId,
+  /// Tx of the deposit, `None` for burns
+  pub tx: Option<EthereumTxHash>,
+  /// Nonce of the burn
+  pub nonce: Option<BurnNonce>,
+  /// Ethereum address of the depositor or of the receiver
+  pub account: EthereumAddress,
+  /// Decimal amount
+  pub amount: String,
+}
+
+/// Transfers which do not reconcile.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Discrepancy {
+  /// Kind of the discrepancy
+  pub kind: DiscrepancyKind,
+  /// Transfers of the local chain
+  pub clover: Vec<Transfer>,
+  /// Transfers of the logs
+  pub source: Vec<Transfer>,
+}
+
+/// Reconciliation report, serialized as json.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Report {
+  /// Id of the bridged chain
+  pub chain_id: ChainId,
+  /// Scanned blocks of the local chain
+  pub blocks: (BlockNumber, BlockNumber),
+  /// Number of `MintSuccess` events
+  pub mints: usize,
+  /// Number of `Claimed` events
+  pub claims: usize,
+  /// Number of `Burned` events
+  pub burns: usize,
+  /// Number of `Deposit` logs
+  pub deposits: usize,
+  /// Number of `Released` logs
+  pub releases: usize,
+  /// Transfers missing on one side
+  pub missing: Vec<Discrepancy>,
+  /// Transfers seen more than once
+  pub duplicated: Vec<Discrepancy>,
+  /// Transfers whose account or amount differ
+  pub mismatched: Vec<Discrepancy>,
+}
+
+impl Report {
+  fn push(&mut self, kind: DiscrepancyKind, clover: Vec<Transfer>, source: Vec<Transfer>) {
+    use DiscrepancyKind::*;
+    let list = match kind {
+      DepositNotMinted | MintWithoutDeposit | ClaimWithoutMint | BurnNotReleased
+      | ReleaseWithoutBurn => &mut self.missing,
+      DuplicatedDeposit | DuplicatedMint | DuplicatedClaim | DuplicatedRelease => {
+        &mut self.duplicated
+      }
+      MintMismatch | ClaimMismatch | ReleaseMismatch => &mut self.mismatched,
+    };
+    list.push(Discrepancy { kind, clover, source });
+  }
+}
+
+fn read_logs(path: &Path) -> sc_cli::Result<Vec<BridgeLog>> {
+  let data = fs::read_to_string(path)?;
+  let logs = if path.extension().map_or(false, |ext| ext == "csv") {
+    parse_csv(&data)
+  } else {
+    serde_json::from_str(&data).map_err(|e| e.to_string())
+  };
+  logs.map_err(|e| format!("{}: {}", path.display(), e).into())
+}
+
+const CSV_HEADER: &str = "event,currency,tx,block,account,amount,nonce";
+
+/// Logs of a csv export with the `CSV_HEADER` header
+fn parse_csv(data: &str) -> Result<Vec<BridgeLog>, String> {
+  let mut lines = data.lines().enumerate().filter(|(_, line)| !line.trim().is_empty());
+  match lines.next() {
+    Some((_, header)) if header.trim() == CSV_HEADER => {}
+    _ => return Err(format!("expected the {} header", CSV_HEADER)),
+  }
+  lines.map(|(i, line)| {
+    let fields = line.split(',').map(str::trim).collect::<Vec<_>>();
+    if fields.len() != 7 {
+      return Err(format!("line {}: expected 7 fields", i + 1));
+    }
+    let json = serde_json::json!({
+      "event": fields[0],
+      "currency": fields[1],
+      "tx": fields[2],
+      "block": fields[3].parse::<u64>().map_err(|e| format!("line {}: {}", i + 1, e))?,
+      "account": fields[4],
+      "amount": fields[5],
+      "nonce": match fields[6] {
+        "" => None,
+        nonce => Some(nonce.parse::<BurnNonce>().map_err(|e| format!("line {}: {}", i + 1, e))?),
+      },
+    });
+    serde_json::from_value(json).map_err(|e| format!("line {}: {}", i + 1, e))
+  }).collect()
+}
+
+fn storage_key(pallet: &[u8], item: &[u8], key: &[u8]) -> StorageKey {
+  StorageKey([&twox_128(pallet)[..], &twox_128(item)[..], key].concat())
+}
+
+/// Bridge events with the chain in the blocks `from..=to` of the local chain
+fn scan<C, B>(
+  client: &C,
+  chain_id: ChainId,
+  from: BlockNumber,
+  to: BlockNumber,
+) -> sc_cli::Result<Vec<(BlockNumber, BridgeEvent)>>
+where
+  C: HeaderBackend<Block> + StorageProvider<Block, B>,
+  B: Backend<Block>,
+{
+  let pruned = |number: BlockNumber| {
+    format!("state of block {} is not available, sync the base path with `--pruning archive`", number)
+  };
+  let events_key = storage_key(b"System", b"Events", &[]);
+  let mut events = Vec::new();
+  for number in from..=to {
+    let hash = client.hash(number)
+      .map_err(|e| e.to_string())?
+      .ok_or_else(|| format!("block {} is not imported", number))?;
+    let at = BlockId::<Block>::Hash(hash);
+    let records = match client.storage(&at, &events_key).map_err(|_| pruned(number))? {
+      Some(data) => Vec::<frame_system::EventRecord<Event, Hash>>::decode(&mut &data.0[..])
+        .map_err(|e| format!("events of block {}: {}", number, e))?,
+      None => continue,
+    };
+
+    for record in records {
+      let event = match record.event {
+        Event::clover_claims(event) => event,
+        _ => continue,
+      };
+      let event = match event {
+        clover_claims::Event::MintSuccess(chain, currency_id, tx, who, amount)
+          if chain == chain_id =>
+        {
+          // the fee is not part of the event
+          let key = [
+            twox_64(&chain.encode()).to_vec(), chain.encode(),
+            blake2_128(&tx.encode()).to_vec(), tx.encode(),
+          ].concat();
+          let fee = match client.storage(&at, &storage_key(b"CloverClaims", b"Mints", &key))
+            .map_err(|_| pruned(number))? {
+            Some(data) => MintInfo::<AccountId, Balance, BlockNumber>::decode(&mut &data.0[..])
+              .map_err(|e| format!("mint {:?} of block {}: {}", tx, number, e))?
+              .fee,
+            None => 0,
+          };
+          BridgeEvent::Minted { currency_id, tx, who, amount, fee }
+        }
+        clover_claims::Event::MintVoided(chain, tx) if chain == chain_id => {
+          BridgeEvent::Cancelled { tx }
+        }
+        clover_claims::Event::ClaimRevoked(chain, _, tx, _, _, _) if chain == chain_id => {
+          BridgeEvent::Cancelled { tx }
+        }
+        clover_claims::Event::Claimed(_, chain, currency_id, tx, who, amount)
+          if chain == chain_id =>
+        {
+          BridgeEvent::Claimed { currency_id, tx, who, amount }
+        }
+        clover_claims::Event::Burned(_, chain, currency_id, dest, amount, nonce)
+          if chain == chain_id =>
+        {
+          BridgeEvent::Burned { currency_id, nonce, dest, amount }
+        }
+        _ => continue,
+      };
+      events.push((number, event));
+    }
+  }
+  Ok(events)
+}
+
+/// Diff the bridge events of the local chain against the logs of the chain
+pub fn reconcile(
+  chain_id: ChainId,
+  blocks: (BlockNumber, BlockNumber),
+  events: &[(BlockNumber, BridgeEvent)],
+  logs: &[BridgeLog],
+) -> Report {
+  use DiscrepancyKind::*;
+  let mut report = Report { chain_id, blocks, ..Default::default() };
+
+  // mints of a tx which are not cancelled, claims and burns, by currency
+  let mut mints = BTreeMap::<(CurrencyId, [u8; 32]), Vec<(Transfer, Balance)>>::new();
+  let mut claims = BTreeMap::<(CurrencyId, [u8; 32]), Vec<Transfer>>::new();
+  let mut burns = BTreeMap::<(CurrencyId, BurnNonce), Transfer>::new();
+  // `MintVoided` has no currency, the one of the last mint of the tx is cancelled
+  let mut minted_currency = BTreeMap::<[u8; 32], CurrencyId>::new();
+  for (number, event) in events {
+    let block = *number as u64;
+    match event {
+      BridgeEvent::Minted { currency_id, tx, who, amount, fee } => {
+        report.mints += 1;
+        let transfer = Transfer {
+          block, currency_id: *currency_id, tx: Some(*tx), nonce: None, account: *who,
+          amount: amount.to_string(),
+        };
+        minted_currency.insert(tx.0, *currency_id);
+        mints.entry((*currency_id, tx.0)).or_default()
+          .push((transfer, amount.saturating_add(*fee)));
+      }
+      BridgeEvent::Cancelled { tx } => {
+        if let Some(minted) = minted_currency.get(&tx.0)
+          .and_then(|currency_id| mints.get_mut(&(*currency_id, tx.0)))
+        {
+          minted.pop();
+        }
+      }
+      BridgeEvent::Claimed { currency_id, tx, who, amount } => {
+        report.claims += 1;
+        claims.entry((*currency_id, tx.0)).or_default().push(Transfer {
+          block, currency_id: *currency_id, tx: Some(*tx), nonce: None, account: *who,
+          amount: amount.to_string(),
+        });
+      }
+      BridgeEvent::Burned { currency_id, nonce, dest, amount } => {
+        report.burns += 1;
+        burns.insert((*currency_id, *nonce), Transfer {
+          block, currency_id: *currency_id, tx: None, nonce: Some(*nonce), account: *dest,
+          amount: amount.to_string(),
+        });
+      }
+    }
+  }
+
+  let mut deposits = BTreeMap::<(CurrencyId, [u8; 32]), Vec<Transfer>>::new();
+  let mut releases = BTreeMap::<(CurrencyId, Option<BurnNonce>), Vec<Transfer>>::new();
+  for log in logs {
+    let transfer = Transfer {
+      block: log.block, currency_id: log.currency, tx: Some(log.tx), nonce: log.nonce,
+      account: log.account,
+      // compared as the decimal of the balance
+      amount: log.amount.parse::<Balance>().map_or_else(|_| log.amount.clone(), |a| a.to_string()),
+    };
+    match log.event {
+      LogEvent::Deposit => {
+        report.deposits += 1;
+        deposits.entry((log.currency, log.tx.0)).or_default().push(transfer);
+      }
+      LogEvent::Released => {
+        report.releases += 1;
+        releases.entry((log.currency, log.nonce)).or_default().push(transfer);
+      }
+    }
+  }
+
+  for (key, source) in &deposits {
+    if source.len() > 1 {
+      report.push(DuplicatedDeposit, Vec::new(), source.clone());
+    }
+    let minted = mints.get(key).map(Vec::as_slice).unwrap_or_default();
+    match minted {
+      [] => report.push(DepositNotMinted, Vec::new(), source.clone()),
+      [(mint, value), ..] => {
+        if mint.account != source[0].account || value.to_string() != source[0].amount {
+          report.push(MintMismatch, vec![mint.clone()], vec![source[0].clone()]);
+        }
+      }
+    }
+  }
+  for (key, minted) in &mints {
+    let transfers = minted.iter().map(|(mint, _)| mint.clone()).collect::<Vec<_>>();
+    if minted.len() > 1 {
+      report.push(DuplicatedMint, transfers.clone(), Vec::new());
+    }
+    if !minted.is_empty() && !deposits.contains_key(key) {
+      report.push(MintWithoutDeposit, transfers, Vec::new());
+    }
+  }
+  for (key, claimed) in &claims {
+    if claimed.len() > 1 {
+      report.push(DuplicatedClaim, claimed.clone(), Vec::new());
+    }
+    match mints.get(key).and_then(|minted| minted.last()) {
+      Some((mint, _)) => {
+        if mint.account != claimed[0].account || mint.amount != claimed[0].amount {
+          report.push(ClaimMismatch, vec![mint.clone(), claimed[0].clone()], Vec::new());
+        }
+      }
+      // minted before the scanned blocks
+      None => report.push(ClaimWithoutMint, claimed.clone(), Vec::new()),
+    }
+  }
+
+  for ((currency_id, nonce), burn) in &burns {
+    match releases.get(&(*currency_id, Some(*nonce))) {
+      None => report.push(BurnNotReleased, vec![burn.clone()], Vec::new()),
+      Some(released) => {
+        if released[0].account != burn.account || released[0].amount != burn.amount {
+          report.push(ReleaseMismatch, vec![burn.clone()], vec![released[0].clone()]);
+        }
+      }
+    }
+  }
+  for ((currency_id, nonce), released) in &releases {
+    if released.len() > 1 {
+      report.push(DuplicatedRelease, Vec::new(), released.clone());
+    }
+    if !nonce.map_or(false, |nonce| burns.contains_key(&(*currency_id, nonce))) {
+      report.push(ReleaseWithoutBurn, Vec::new(), released.clone());
+    }
+  }
+  report
+}
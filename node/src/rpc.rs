@@ -109,6 +109,7 @@ pub fn create_full<C, P, SC, B>(
   C::Api: pallet_contracts_rpc::ContractsRuntimeApi<Block, AccountId, Balance, BlockNumber>,
   C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
   C::Api: fp_rpc::EthereumRuntimeRPCApi<Block>,
+  C::Api: clover_rpc::claims::ClaimsRuntimeApi<Block, AccountId, Balance, BlockNumber>,
   C::Api: BabeApi<Block>,
   C::Api: BlockBuilder<Block>,
   P: TransactionPool<Block=Block> + 'static,
@@ -123,6 +124,7 @@ pub fn create_full<C, P, SC, B>(
   use substrate_frame_rpc_system::{FullSystem, SystemApi};
   use pallet_contracts_rpc::{Contracts, ContractsApi};
   use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
+  use clover_rpc::claims::{Claims, ClaimsRpc};
 
   let mut io = jsonrpc_core::IoHandler::default();
   let FullDeps {
@@ -160,6 +162,7 @@ pub fn create_full<C, P, SC, B>(
     TransactionPaymentApi::to_delegate(TransactionPayment::new(client.clone()))
   );
   io.extend_with(ContractsApi::to_delegate(Contracts::new(client.clone())));
+  io.extend_with(ClaimsRpc::to_delegate(Claims::new(client.clone())));
   io.extend_with(
     sc_consensus_babe_rpc::BabeApi::to_delegate(
       BabeRpcHandler::new(
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: GPL-3.0
+pragma solidity ^0.7.0;
+
+// Deposit contract of the native currency of the chain, for running clover-relayer against a
+// local EVM stand-in. Deposits are minted on Clover, burns on Clover are released to `dest`.
+contract Bridge {
+  address public relayer;
+  mapping(uint256 => bool) public released;
+
+  event Deposit(address indexed who, uint256 amount);
+  event Released(address indexed dest, uint256 amount, uint256 nonce);
+
+  constructor(address _relayer) {
+    relayer = _relayer;
+  }
+
+  function deposit() external payable {
+    require(msg.value > 0, "empty deposit");
+    emit Deposit(msg.sender, msg.value);
+  }
+
+  function release(address payable dest, uint256 amount, uint256 nonce) external {
+    require(msg.sender == relayer, "not the relayer");
+    require(!released[nonce], "already released");
+    released[nonce] = true;
+    dest.transfer(amount);
+    emit Released(dest, amount, nonce);
+  }
+}
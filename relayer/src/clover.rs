@@ -0,0 +1,315 @@
+// Copyright (C) 2021 Clover Network
+// This file is part of Clover.
+
+//! The Clover node: mints are submitted as signed `mint_claims_batch` extrinsics, and burns are
+//! read from the `Burned` events of finalized blocks.
+
+use crate::{
+  rpc::{from_hex, from_quantity, to_hex, RpcClient},
+  Error, Result,
+};
+use clover_claims::{
+  BurnInfo, BurnNonce, BurnStatus, ChainId, CurrencyId, EthereumAddress, EthereumTxHash,
+};
+use clover_runtime::{Call, Event, SignedExtra, SignedPayload, UncheckedExtrinsic};
+use codec::{Decode, Encode};
+use primitives::{AccountId, Balance, BlockNumber, Hash, Index};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use sp_core::{
+  hashing::{blake2_128, twox_128, twox_64},
+  sr25519, Pair,
+};
+use sp_runtime::{generic::Era, MultiAddress, MultiSignature};
+use std::{
+  thread,
+  time::{Duration, Instant},
+};
+
+/// Interval of polling the node for the inclusion of a submitted extrinsic.
+const INCLUSION_POLL: Duration = Duration::from_secs(2);
+
+/// State of the claim of a tx on the source chain.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ClaimState {
+  /// Not attested by any relayer
+  Unknown,
+  /// Attested by some relayers
+  Attesting,
+  /// Minted, claimed or revoked
+  Done,
+}
+
+/// A `Burned` event to the chain.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Burn {
+  pub nonce: BurnNonce,
+  pub currency_id: CurrencyId,
+  pub dest: EthereumAddress,
+  pub amount: Balance,
+}
+
+pub trait BridgeClient {
+  fn claim_state(&self, chain_id: ChainId, tx: &EthereumTxHash) -> Result<ClaimState>;
+
+  /// Attest the mints, returns once the extrinsic is included
+  fn mint_claims(
+    &self,
+    chain_id: ChainId,
+    currency_id: CurrencyId,
+    items: Vec<(EthereumTxHash, EthereumAddress, Balance)>,
+  ) -> Result<()>;
+
+  /// Number of the last finalized block
+  fn finalized_number(&self) -> Result<BlockNumber>;
+
+  /// Burns to the chain in the block
+  fn burns(&self, chain_id: ChainId, number: BlockNumber) -> Result<Vec<Burn>>;
+
+  /// Whether the burn is waiting to be released
+  fn burn_pending(&self, chain_id: ChainId, nonce: BurnNonce) -> Result<bool>;
+
+  /// Confirm the burns are released by the txs, returns once the extrinsic is included
+  fn confirm_burns(&self, chain_id: ChainId, items: Vec<(BurnNonce, EthereumTxHash)>)
+    -> Result<()>;
+
+  /// Report the value of the currency locked on the chain, returns once the extrinsic is
+  /// included
+  fn report_locked(
+    &self,
+    chain_id: ChainId,
+    currency_id: CurrencyId,
+    locked: Balance,
+  ) -> Result<()>;
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RuntimeVersion {
+  spec_version: u32,
+  transaction_version: u32,
+}
+
+#[derive(Deserialize)]
+struct Header {
+  number: String,
+}
+
+/// `BridgeClient` of the RPC endpoint of a Clover node, extrinsics are signed by the relayer key.
+pub struct CloverRpc {
+  rpc: RpcClient,
+  pair: sr25519::Pair,
+  account: AccountId,
+  genesis_hash: Hash,
+  inclusion_timeout: Duration,
+}
+
+impl CloverRpc {
+  pub fn new(url: &str, pair: sr25519::Pair, inclusion_timeout: Duration) -> Result<Self> {
+    let rpc = RpcClient::new(url);
+    let genesis_hash = rpc.call("chain_getBlockHash", json!([0]))?;
+    Ok(CloverRpc {
+      rpc,
+      account: pair.public().into(),
+      pair,
+      genesis_hash,
+      inclusion_timeout,
+    })
+  }
+
+  pub fn account(&self) -> &AccountId {
+    &self.account
+  }
+
+  fn storage(&self, key: Vec<u8>, at: Option<Hash>) -> Result<Option<Vec<u8>>> {
+    match self
+      .rpc
+      .call::<Option<String>>("state_getStorage", json!([to_hex(&key), at]))?
+    {
+      Some(data) => Ok(Some(from_hex(&data)?)),
+      None => Ok(None),
+    }
+  }
+
+  /// Nonce of the relayer account in the best block, the pool excluded
+  fn account_nonce(&self) -> Result<Index> {
+    let key = storage_key(
+      b"System",
+      b"Account",
+      &blake2_128_concat(&self.account.encode()),
+    );
+    match self.storage(key, None)? {
+      // the nonce is the first field of `AccountInfo`
+      Some(data) => Index::decode(&mut &data[..]).map_err(|e| Error::Decode(e.to_string())),
+      None => Ok(0),
+    }
+  }
+
+  /// Sign and submit the call, and wait until it is included in the best block
+  fn submit(&self, call: Call) -> Result<()> {
+    let version: RuntimeVersion = self.rpc.call("state_getRuntimeVersion", json!([]))?;
+    let nonce: Index = self
+      .rpc
+      .call("system_accountNextIndex", json!([self.account.to_string()]))?;
+    let extra: SignedExtra = (
+      frame_system::CheckSpecVersion::new(),
+      frame_system::CheckTxVersion::new(),
+      frame_system::CheckGenesis::new(),
+      frame_system::CheckEra::from(Era::Immortal),
+      frame_system::CheckNonce::from(nonce),
+      frame_system::CheckWeight::new(),
+      pallet_transaction_payment::ChargeTransactionPayment::from(0),
+    );
+    let payload = SignedPayload::from_raw(
+      call,
+      extra,
+      (
+        version.spec_version,
+        version.transaction_version,
+        self.genesis_hash,
+        self.genesis_hash,
+        (),
+        (),
+        (),
+      ),
+    );
+    let signature = payload.using_encoded(|payload| self.pair.sign(payload));
+    let (call, extra, _) = payload.deconstruct();
+    let extrinsic = UncheckedExtrinsic::new_signed(
+      call,
+      MultiAddress::Id(self.account.clone()),
+      MultiSignature::Sr25519(signature),
+      extra,
+    );
+    let hash: Hash = self.rpc.call(
+      "author_submitExtrinsic",
+      json!([to_hex(&extrinsic.encode())]),
+    )?;
+    log::debug!("submitted extrinsic {:?} with nonce {}", hash, nonce);
+
+    let started = Instant::now();
+    while self.account_nonce()? <= nonce {
+      if started.elapsed() > self.inclusion_timeout {
+        return Err(Error::Timeout(format!("extrinsic {:?}", hash)));
+      }
+      thread::sleep(INCLUSION_POLL);
+    }
+    Ok(())
+  }
+}
+
+impl BridgeClient for CloverRpc {
+  fn claim_state(&self, chain_id: ChainId, tx: &EthereumTxHash) -> Result<ClaimState> {
+    let status: Value = self.rpc.call("claims_claimStatus", json!([chain_id, tx]))?;
+    match status["status"].as_str() {
+      Some("unknown") => Ok(ClaimState::Unknown),
+      Some("attesting") => Ok(ClaimState::Attesting),
+      Some(_) => Ok(ClaimState::Done),
+      None => Err(Error::Decode(format!("claims_claimStatus: {}", status))),
+    }
+  }
+
+  fn mint_claims(
+    &self,
+    chain_id: ChainId,
+    currency_id: CurrencyId,
+    items: Vec<(EthereumTxHash, EthereumAddress, Balance)>,
+  ) -> Result<()> {
+    self.submit(Call::CloverClaims(clover_claims::Call::mint_claims_batch(
+      chain_id,
+      currency_id,
+      items,
+    )))
+  }
+
+  fn finalized_number(&self) -> Result<BlockNumber> {
+    let hash: Hash = self.rpc.call("chain_getFinalizedHead", json!([]))?;
+    let header: Header = self.rpc.call("chain_getHeader", json!([hash]))?;
+    Ok(from_quantity(&header.number)? as BlockNumber)
+  }
+
+  fn burns(&self, chain_id: ChainId, number: BlockNumber) -> Result<Vec<Burn>> {
+    let hash: Hash = self.rpc.call("chain_getBlockHash", json!([number]))?;
+    let events = match self.storage(storage_key(b"System", b"Events", &[]), Some(hash))? {
+      Some(data) => Vec::<frame_system::EventRecord<Event, Hash>>::decode(&mut &data[..])
+        .map_err(|e| Error::Decode(format!("events of block {}: {}", number, e)))?,
+      None => return Ok(Vec::new()),
+    };
+    Ok(
+      events
+        .into_iter()
+        .filter_map(|record| match record.event {
+          Event::clover_claims(clover_claims::Event::Burned(
+            _,
+            chain,
+            currency_id,
+            dest,
+            amount,
+            nonce,
+          )) if chain == chain_id => Some(Burn {
+            nonce,
+            currency_id,
+            dest,
+            amount,
+          }),
+          _ => None,
+        })
+        .collect(),
+    )
+  }
+
+  fn burn_pending(&self, chain_id: ChainId, nonce: BurnNonce) -> Result<bool> {
+    let key = storage_key(
+      b"CloverClaims",
+      b"Burns",
+      &[
+        twox_64_concat(&chain_id.encode()),
+        twox_64_concat(&nonce.encode()),
+      ]
+      .concat(),
+    );
+    match self.storage(key, None)? {
+      Some(data) => {
+        let burn = BurnInfo::<AccountId, Balance, BlockNumber>::decode(&mut &data[..])
+          .map_err(|e| Error::Decode(format!("burn {}: {}", nonce, e)))?;
+        Ok(burn.status == BurnStatus::Pending)
+      }
+      None => Ok(false),
+    }
+  }
+
+  fn confirm_burns(
+    &self,
+    chain_id: ChainId,
+    items: Vec<(BurnNonce, EthereumTxHash)>,
+  ) -> Result<()> {
+    self.submit(Call::CloverClaims(clover_claims::Call::confirm_burns(
+      chain_id, items,
+    )))
+  }
+
+  fn report_locked(
+    &self,
+    chain_id: ChainId,
+    currency_id: CurrencyId,
+    locked: Balance,
+  ) -> Result<()> {
+    self.submit(Call::CloverClaims(clover_claims::Call::report_locked(
+      chain_id,
+      currency_id,
+      locked,
+    )))
+  }
+}
+
+fn storage_key(pallet: &[u8], item: &[u8], key: &[u8]) -> Vec<u8> {
+  [&twox_128(pallet)[..], &twox_128(item)[..], key].concat()
+}
+
+fn blake2_128_concat(data: &[u8]) -> Vec<u8> {
+  [&blake2_128(data)[..], data].concat()
+}
+
+fn twox_64_concat(data: &[u8]) -> Vec<u8> {
+  [&twox_64(data)[..], data].concat()
+}
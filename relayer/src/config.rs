@@ -0,0 +1,121 @@
+// Copyright (C) 2021 Clover Network
+// This file is part of Clover.
+
+use crate::{Error, Result};
+use clover_claims::{ChainId, CurrencyId, H160};
+use serde::Deserialize;
+use std::{fs, path::Path, path::PathBuf};
+
+/// Relayer configuration, read from a json file.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Config {
+  /// HTTP RPC endpoint of the Clover node
+  pub clover_url: String,
+  /// HTTP JSON-RPC endpoint of the EVM chain
+  pub evm_url: String,
+  /// Id of the EVM chain in `clover_claims`
+  pub chain_id: ChainId,
+  /// Secret URI of the relayer key, e.g. `//Alice` on a `--dev` node
+  pub suri: String,
+  /// Account unlocked on the EVM node sending the releases
+  pub evm_account: H160,
+  /// Deposit contracts of the EVM chain and the currency they bridge, each contract releases
+  /// the burns of its currency
+  pub contracts: Vec<(H160, CurrencyId)>,
+  /// Blocks built on top of a deposit or a release before it is relayed
+  #[serde(default = "default_confirmations")]
+  pub confirmations: u64,
+  /// Maximum number of blocks of a chain scanned by one poll
+  #[serde(default = "default_max_blocks")]
+  pub max_blocks: u64,
+  /// Seconds between polls
+  #[serde(default = "default_poll_interval")]
+  pub poll_interval: u64,
+  /// Seconds to wait for the inclusion of a submitted extrinsic
+  #[serde(default = "default_inclusion_timeout")]
+  pub inclusion_timeout: u64,
+  /// Release txs sent for a burn before it is left to the bridge admin
+  #[serde(default = "default_max_release_attempts")]
+  pub max_release_attempts: u32,
+  /// EVM blocks between the reports of the values locked in the deposit contracts, 0 disables
+  /// the reports
+  #[serde(default = "default_lock_report_interval")]
+  pub lock_report_interval: u64,
+  /// File of the persisted cursor
+  pub cursor_path: PathBuf,
+  /// EVM block to scan from without a persisted cursor
+  #[serde(default)]
+  pub evm_start_block: u64,
+  /// Clover block to scan from without a persisted cursor
+  #[serde(default)]
+  pub clover_start_block: u32,
+}
+
+fn default_confirmations() -> u64 {
+  15
+}
+
+fn default_max_blocks() -> u64 {
+  1000
+}
+
+fn default_poll_interval() -> u64 {
+  6
+}
+
+fn default_inclusion_timeout() -> u64 {
+  60
+}
+
+fn default_max_release_attempts() -> u32 {
+  3
+}
+
+fn default_lock_report_interval() -> u64 {
+  1200
+}
+
+impl Config {
+  pub fn load(path: &Path) -> Result<Self> {
+    let data = fs::read(path)?;
+    let config: Config = serde_json::from_slice(&data)
+      .map_err(|e| Error::Config(format!("{}: {}", path.display(), e)))?;
+    if config.contracts.is_empty() {
+      return Err(Error::Config("no deposit contracts".into()));
+    }
+    // the placeholders of `dev.json`
+    if config.evm_account.is_zero() {
+      return Err(Error::Config("evmAccount is not configured".into()));
+    }
+    if config
+      .contracts
+      .iter()
+      .any(|(contract, _)| contract.is_zero())
+    {
+      return Err(Error::Config("a deposit contract is not configured".into()));
+    }
+    if config.max_blocks == 0 {
+      return Err(Error::Config("max_blocks must be positive".into()));
+    }
+    Ok(config)
+  }
+
+  /// The currency bridged by the deposit contract
+  pub fn currency_of(&self, contract: &H160) -> Option<CurrencyId> {
+    self
+      .contracts
+      .iter()
+      .find(|(c, _)| c == contract)
+      .map(|(_, currency_id)| *currency_id)
+  }
+
+  /// The deposit contract releasing the currency
+  pub fn contract_of(&self, currency_id: CurrencyId) -> Option<H160> {
+    self
+      .contracts
+      .iter()
+      .find(|(_, c)| *c == currency_id)
+      .map(|(contract, _)| *contract)
+  }
+}
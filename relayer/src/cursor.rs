@@ -0,0 +1,73 @@
+// Copyright (C) 2021 Clover Network
+// This file is part of Clover.
+
+use crate::Result;
+use clover_claims::{BurnNonce, EthereumAddress, EthereumTxHash, H160};
+use primitives::{Balance, BlockNumber};
+use serde::{Deserialize, Serialize};
+use std::{
+  collections::BTreeMap,
+  fs, io,
+  path::{Path, PathBuf},
+};
+
+/// Progress of the relayer, saved after every relayed step so a restart resumes from it.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Cursor {
+  /// Next block of the EVM chain to scan for deposits
+  pub evm_block: u64,
+  /// Next block of Clover to scan for burns
+  pub clover_block: BlockNumber,
+  /// Releases queued for the EVM chain and not confirmed on Clover yet, by burn nonce
+  pub releases: BTreeMap<BurnNonce, Release>,
+  #[serde(skip)]
+  path: PathBuf,
+}
+
+/// A release of a burn, saved before its tx is sent.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Release {
+  /// Deposit contract releasing the currency of the burn
+  pub contract: H160,
+  pub dest: EthereumAddress,
+  pub amount: Balance,
+  /// EVM block when the release was queued, its `RELEASED_EVENT` log is searched from there
+  pub evm_block: u64,
+  /// Number of release txs sent
+  pub attempts: u32,
+  /// The last sent tx, `None` until it is sent and after it failed
+  pub tx: Option<EthereumTxHash>,
+}
+
+impl Cursor {
+  /// Load the cursor saved at the path, or start from the blocks
+  pub fn load(path: &Path, evm_block: u64, clover_block: BlockNumber) -> Result<Self> {
+    match fs::read(path) {
+      Ok(data) => {
+        let mut cursor: Cursor = serde_json::from_slice(&data)
+          .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        cursor.path = path.into();
+        Ok(cursor)
+      }
+      Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Cursor {
+        evm_block,
+        clover_block,
+        releases: BTreeMap::new(),
+        path: path.into(),
+      }),
+      Err(e) => Err(e.into()),
+    }
+  }
+
+  /// Replace the saved cursor, the file is renamed over so it is never left half written
+  pub fn save(&self) -> Result<()> {
+    let data =
+      serde_json::to_vec_pretty(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let tmp = self.path.with_extension("tmp");
+    fs::write(&tmp, data)?;
+    fs::rename(&tmp, &self.path)?;
+    Ok(())
+  }
+}
@@ -0,0 +1,234 @@
+// Copyright (C) 2021 Clover Network
+// This file is part of Clover.
+
+//! The EVM chain bridged with Clover: `DEPOSIT_EVENT` logs of the deposit contracts are minted on
+//! Clover, and burns on Clover are released by the `release(address,uint256,uint256)` method of
+//! the same contracts, which emit a `Released(address,uint256,uint256)` log.
+
+use crate::{
+  rpc::{from_amount, from_hex, from_quantity, to_hex, RpcClient},
+  Result,
+};
+use clover_claims::{BurnNonce, EthereumAddress, EthereumTxHash, DEPOSIT_EVENT, H160};
+use serde::Deserialize;
+use serde_json::json;
+use sp_core::{hashing::keccak_256, H256};
+
+/// Signature of the method releasing a burn, it must revert for a released nonce.
+pub const RELEASE_METHOD: &[u8] = b"release(address,uint256,uint256)";
+
+/// Signature of the event of a release, with the indexed `dest`, the amount and the nonce.
+pub const RELEASED_EVENT: &[u8] = b"Released(address,uint256,uint256)";
+
+/// A `DEPOSIT_EVENT` log of a deposit contract.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Deposit {
+  pub contract: H160,
+  pub block: u64,
+  pub tx: EthereumTxHash,
+  pub who: EthereumAddress,
+  pub amount: u128,
+}
+
+pub trait EvmClient {
+  /// Number of the best block
+  fn block_number(&self) -> Result<u64>;
+
+  /// Deposits to the contracts in the blocks `from..=to`
+  fn deposits(&self, contracts: &[H160], from: u64, to: u64) -> Result<Vec<Deposit>>;
+
+  /// Send the tx releasing the burn from the contract
+  fn release(
+    &self,
+    contract: H160,
+    dest: EthereumAddress,
+    amount: u128,
+    nonce: BurnNonce,
+  ) -> Result<EthereumTxHash>;
+
+  /// The tx of the contract which released the nonce, searched from the block
+  fn released(
+    &self,
+    contract: &H160,
+    nonce: BurnNonce,
+    from: u64,
+  ) -> Result<Option<EthereumTxHash>>;
+
+  /// Block number and success of the tx, `None` if it is not included yet
+  fn receipt(&self, tx: &EthereumTxHash) -> Result<Option<(u64, bool)>>;
+
+  /// Native balance locked in the deposit contract at the block
+  fn locked(&self, contract: &H160, block: u64) -> Result<u128>;
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Log {
+  address: H160,
+  topics: Vec<H256>,
+  data: String,
+  block_number: String,
+  transaction_hash: EthereumTxHash,
+  #[serde(default)]
+  removed: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Receipt {
+  block_number: String,
+  status: String,
+}
+
+/// `EvmClient` of an Ethereum JSON-RPC endpoint, releases are sent from an account unlocked on
+/// the node with `eth_sendTransaction`.
+pub struct EthRpc {
+  rpc: RpcClient,
+  from: H160,
+}
+
+impl EthRpc {
+  pub fn new(url: &str, from: H160) -> Self {
+    EthRpc {
+      rpc: RpcClient::new(url),
+      from,
+    }
+  }
+}
+
+impl EvmClient for EthRpc {
+  fn block_number(&self) -> Result<u64> {
+    from_quantity(&self.rpc.call::<String>("eth_blockNumber", json!([]))?)
+  }
+
+  fn deposits(&self, contracts: &[H160], from: u64, to: u64) -> Result<Vec<Deposit>> {
+    let logs: Vec<Log> = self.rpc.call(
+      "eth_getLogs",
+      json!([{
+        "fromBlock": format!("{:#x}", from),
+        "toBlock": format!("{:#x}", to),
+        "address": contracts,
+        "topics": [H256(keccak_256(DEPOSIT_EVENT))],
+      }]),
+    )?;
+
+    let mut deposits = Vec::new();
+    for log in logs.into_iter().filter(|log| !log.removed) {
+      match decode_deposit(&log.topics, &from_hex(&log.data)?) {
+        Some((who, amount)) => deposits.push(Deposit {
+          contract: log.address,
+          block: from_quantity(&log.block_number)?,
+          tx: log.transaction_hash,
+          who,
+          amount,
+        }),
+        None => log::warn!(
+          "invalid deposit in tx {:?} of {:?}",
+          log.transaction_hash,
+          log.address
+        ),
+      }
+    }
+    Ok(deposits)
+  }
+
+  fn release(
+    &self,
+    contract: H160,
+    dest: EthereumAddress,
+    amount: u128,
+    nonce: BurnNonce,
+  ) -> Result<EthereumTxHash> {
+    self.rpc.call(
+      "eth_sendTransaction",
+      json!([{
+        "from": self.from,
+        "to": contract,
+        "data": to_hex(&release_call(dest, amount, nonce)),
+      }]),
+    )
+  }
+
+  fn released(
+    &self,
+    contract: &H160,
+    nonce: BurnNonce,
+    from: u64,
+  ) -> Result<Option<EthereumTxHash>> {
+    let logs: Vec<Log> = self.rpc.call(
+      "eth_getLogs",
+      json!([{
+        "fromBlock": format!("{:#x}", from),
+        "toBlock": "latest",
+        "address": contract,
+        "topics": [H256(keccak_256(RELEASED_EVENT))],
+      }]),
+    )?;
+    for log in logs.into_iter().filter(|log| !log.removed) {
+      if decode_released_nonce(&from_hex(&log.data)?) == Some(nonce) {
+        return Ok(Some(log.transaction_hash));
+      }
+    }
+    Ok(None)
+  }
+
+  fn receipt(&self, tx: &EthereumTxHash) -> Result<Option<(u64, bool)>> {
+    match self
+      .rpc
+      .call::<Option<Receipt>>("eth_getTransactionReceipt", json!([tx]))?
+    {
+      Some(receipt) => Ok(Some((
+        from_quantity(&receipt.block_number)?,
+        from_quantity(&receipt.status)? == 1,
+      ))),
+      None => Ok(None),
+    }
+  }
+
+  fn locked(&self, contract: &H160, block: u64) -> Result<u128> {
+    from_amount(
+      &self
+        .rpc
+        .call::<String>("eth_getBalance", json!([contract, format!("{:#x}", block)]))?,
+    )
+  }
+}
+
+/// The depositor and amount of a `DEPOSIT_EVENT` log, the same rules as `mint_with_proof`
+pub fn decode_deposit(topics: &[H256], data: &[u8]) -> Option<(EthereumAddress, u128)> {
+  if topics.len() != 2 || topics[0] != H256(keccak_256(DEPOSIT_EVENT)) || data.len() != 32 {
+    return None;
+  }
+  // abi encoded as 32 bytes words
+  let topic = topics[1].as_bytes();
+  if topic[..12].iter().any(|b| *b != 0) || data[..16].iter().any(|b| *b != 0) {
+    return None;
+  }
+  let mut who = [0u8; 20];
+  who.copy_from_slice(&topic[12..]);
+  let mut amount = [0u8; 16];
+  amount.copy_from_slice(&data[16..]);
+  Some((EthereumAddress(who), u128::from_be_bytes(amount)))
+}
+
+/// The nonce of the data of a `RELEASED_EVENT` log
+pub fn decode_released_nonce(data: &[u8]) -> Option<BurnNonce> {
+  if data.len() != 64 || data[32..56].iter().any(|b| *b != 0) {
+    return None;
+  }
+  let mut nonce = [0u8; 8];
+  nonce.copy_from_slice(&data[56..]);
+  Some(BurnNonce::from_be_bytes(nonce))
+}
+
+/// Abi encoded call of `RELEASE_METHOD`
+pub fn release_call(dest: EthereumAddress, amount: u128, nonce: BurnNonce) -> Vec<u8> {
+  let mut data = keccak_256(RELEASE_METHOD)[..4].to_vec();
+  data.extend_from_slice(&[0u8; 12]);
+  data.extend_from_slice(&dest.0);
+  data.extend_from_slice(&[0u8; 16]);
+  data.extend_from_slice(&amount.to_be_bytes());
+  data.extend_from_slice(&[0u8; 24]);
+  data.extend_from_slice(&nonce.to_be_bytes());
+  data
+}
@@ -0,0 +1,121 @@
+// Copyright (C) 2021 Clover Network
+// This file is part of Clover.
+
+//! Reference relayer of `clover_claims`.
+//!
+//! Deposits to the deposit contracts of an EVM chain are attested on Clover with
+//! `mint_claims_batch` once they have `confirmations` blocks on top, and the burns to the chain
+//! in finalized Clover blocks are released by calling `release(address,uint256,uint256)` on the
+//! deposit contract of the currency, then confirmed with `confirm_burns`. Progress is saved to
+//! the cursor file after every step, so a restarted relayer does not skip a relay. A release is
+//! saved before its tx is sent, and its `Released(address,uint256,uint256)` log is looked up
+//! before it is sent again after a restart or a failed tx.
+//!
+//! For a local setup run `clover --dev`, whose genesis makes `//Alice` the relayer of chain 56,
+//! and an EVM stand-in with unlocked accounts, e.g. `ganache-cli --chainId 56`. Deploy
+//! `contracts/Bridge.sol` there with the first account as its relayer, fill both addresses into
+//! `dev.json` in place of the zero placeholders, which fail the startup, and run
+//! `clover-relayer --config relayer/dev.json`.
+
+use sp_core::{sr25519, Pair};
+use std::{fmt, io, path::PathBuf, thread, time::Duration};
+use structopt::StructOpt;
+
+mod clover;
+mod config;
+mod cursor;
+mod evm;
+mod relayer;
+mod rpc;
+
+#[cfg(test)]
+mod tests;
+
+use crate::{clover::CloverRpc, config::Config, cursor::Cursor, evm::EthRpc, relayer::Relayer};
+
+#[derive(Debug)]
+pub enum Error {
+  Io(io::Error),
+  Config(String),
+  Http(String),
+  /// The RPC method returned an error
+  Rpc(String, String),
+  Decode(String),
+  Timeout(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Error::Io(e) => write!(f, "io error: {}", e),
+      Error::Config(e) => write!(f, "invalid config: {}", e),
+      Error::Http(e) => write!(f, "http error: {}", e),
+      Error::Rpc(method, e) => write!(f, "{} failed: {}", method, e),
+      Error::Decode(e) => write!(f, "decode error: {}", e),
+      Error::Timeout(e) => write!(f, "timed out waiting for {}", e),
+    }
+  }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+  fn from(e: io::Error) -> Self {
+    Error::Io(e)
+  }
+}
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "clover-relayer", about = "Reference relayer of clover_claims")]
+struct Cli {
+  /// Path of the json config file
+  #[structopt(long, parse(from_os_str))]
+  config: PathBuf,
+
+  /// Poll once and exit
+  #[structopt(long)]
+  once: bool,
+}
+
+fn run(cli: Cli) -> Result<()> {
+  let config = Config::load(&cli.config)?;
+  let pair = sr25519::Pair::from_string(&config.suri, None)
+    .map_err(|e| Error::Config(format!("invalid suri: {:?}", e)))?;
+  let cursor = Cursor::load(
+    &config.cursor_path,
+    config.evm_start_block,
+    config.clover_start_block,
+  )?;
+  let clover = CloverRpc::new(
+    &config.clover_url,
+    pair,
+    Duration::from_secs(config.inclusion_timeout),
+  )?;
+  log::info!("relaying chain {} as {}", config.chain_id, clover.account());
+  let evm = EthRpc::new(&config.evm_url, config.evm_account);
+  let poll_interval = Duration::from_secs(config.poll_interval);
+
+  let mut relayer = Relayer::new(config, cursor, evm, clover);
+  loop {
+    match relayer.poll() {
+      Ok(()) => log::debug!("cursor {:?}", relayer.cursor()),
+      Err(e) if cli.once => return Err(e),
+      // progress is saved, retry from the cursor
+      Err(e) => log::error!("{}", e),
+    }
+    if cli.once {
+      return Ok(());
+    }
+    thread::sleep(poll_interval);
+  }
+}
+
+fn main() {
+  env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+  if let Err(e) = run(Cli::from_args()) {
+    log::error!("{}", e);
+    std::process::exit(1);
+  }
+}
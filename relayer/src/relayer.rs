@@ -0,0 +1,268 @@
+// Copyright (C) 2021 Clover Network
+// This file is part of Clover.
+
+use crate::{
+  clover::{BridgeClient, ClaimState},
+  config::Config,
+  cursor::{Cursor, Release},
+  evm::EvmClient,
+  Result,
+};
+use clover_claims::{CurrencyId, MAX_BATCH_SIZE};
+use primitives::BlockNumber;
+use std::collections::BTreeMap;
+
+/// Relays the deposits of the EVM chain to Clover and the burns of Clover back to it.
+pub struct Relayer<E, C> {
+  config: Config,
+  cursor: Cursor,
+  evm: E,
+  clover: C,
+  /// EVM block of the last lock report
+  last_lock_report: Option<u64>,
+}
+
+impl<E: EvmClient, C: BridgeClient> Relayer<E, C> {
+  pub fn new(config: Config, cursor: Cursor, evm: E, clover: C) -> Self {
+    Relayer {
+      config,
+      cursor,
+      evm,
+      clover,
+      last_lock_report: None,
+    }
+  }
+
+  pub fn cursor(&self) -> &Cursor {
+    &self.cursor
+  }
+
+  /// Relay the confirmed deposits and the finalized burns
+  pub fn poll(&mut self) -> Result<()> {
+    self.relay_deposits()?;
+    self.relay_burns()
+  }
+
+  /// Attest the deposits of the next confirmed blocks of the EVM chain
+  fn relay_deposits(&mut self) -> Result<()> {
+    let confirmed = match self
+      .evm
+      .block_number()?
+      .checked_sub(self.config.confirmations)
+    {
+      Some(number) => number,
+      None => return Ok(()),
+    };
+    let from = self.cursor.evm_block;
+    if from > confirmed {
+      return Ok(());
+    }
+    let to = confirmed.min(from.saturating_add(self.config.max_blocks - 1));
+    let contracts = self
+      .config
+      .contracts
+      .iter()
+      .map(|(contract, _)| *contract)
+      .collect::<Vec<_>>();
+
+    let mut mints = BTreeMap::<CurrencyId, Vec<_>>::new();
+    for deposit in self.evm.deposits(&contracts, from, to)? {
+      let currency_id = match self.config.currency_of(&deposit.contract) {
+        Some(currency_id) => currency_id,
+        None => continue,
+      };
+      // attesting txs may not be attested by this relayer, `mint_claims_batch` skips them if so
+      if self.clover.claim_state(self.config.chain_id, &deposit.tx)? == ClaimState::Done {
+        log::debug!("deposit {:?} is already minted", deposit.tx);
+        continue;
+      }
+      mints
+        .entry(currency_id)
+        .or_default()
+        .push((deposit.tx, deposit.who, deposit.amount));
+    }
+    for (currency_id, items) in mints {
+      for batch in items.chunks(MAX_BATCH_SIZE) {
+        log::info!(
+          "attesting {} deposits of {:?} in blocks {}..={}",
+          batch.len(),
+          currency_id,
+          from,
+          to
+        );
+        self
+          .clover
+          .mint_claims(self.config.chain_id, currency_id, batch.to_vec())?;
+        for (tx, _, _) in batch {
+          if self.clover.claim_state(self.config.chain_id, tx)? == ClaimState::Unknown {
+            log::warn!("deposit {:?} is not minted, see the MintSkipped event", tx);
+          }
+        }
+      }
+    }
+
+    self.cursor.evm_block = to + 1;
+    self.cursor.save()?;
+    self.report_locked(to)
+  }
+
+  /// Report the values locked in the deposit contracts at the scanned block, at most once per
+  /// `lock_report_interval` blocks
+  fn report_locked(&mut self, block: u64) -> Result<()> {
+    let interval = self.config.lock_report_interval;
+    if interval == 0
+      || self
+        .last_lock_report
+        .map_or(false, |last| block < last.saturating_add(interval))
+    {
+      return Ok(());
+    }
+    for (contract, currency_id) in &self.config.contracts {
+      let locked = self.evm.locked(contract, block)?;
+      log::info!(
+        "reporting {} of {:?} locked at block {}",
+        locked,
+        currency_id,
+        block
+      );
+      self
+        .clover
+        .report_locked(self.config.chain_id, *currency_id, locked)?;
+    }
+    self.last_lock_report = Some(block);
+    Ok(())
+  }
+
+  /// Queue the burns of the next finalized blocks of Clover, send the queued releases and
+  /// confirm the included ones
+  fn relay_burns(&mut self) -> Result<()> {
+    let finalized = self.clover.finalized_number()?;
+    let from = self.cursor.clover_block;
+    if from <= finalized {
+      let to = (finalized as u64).min(from as u64 + self.config.max_blocks - 1) as BlockNumber;
+      let evm_block = self.evm.block_number()?;
+      for number in from..=to {
+        for burn in self.clover.burns(self.config.chain_id, number)? {
+          if self.cursor.releases.contains_key(&burn.nonce)
+            || !self.clover.burn_pending(self.config.chain_id, burn.nonce)?
+          {
+            continue;
+          }
+          let contract = match self.config.contract_of(burn.currency_id) {
+            Some(contract) => contract,
+            None => {
+              log::warn!(
+                "no contract releases {:?} of burn {}",
+                burn.currency_id,
+                burn.nonce
+              );
+              continue;
+            }
+          };
+          self.cursor.releases.insert(
+            burn.nonce,
+            Release {
+              contract,
+              dest: burn.dest,
+              amount: burn.amount,
+              evm_block,
+              attempts: 0,
+              tx: None,
+            },
+          );
+        }
+        self.cursor.clover_block = number + 1;
+        self.cursor.save()?;
+      }
+    }
+
+    self.send_releases()?;
+    self.confirm_releases()
+  }
+
+  /// Send the queued releases without a tx. The attempt is saved before the tx is sent, so after
+  /// a restart or a failed tx the `RELEASED_EVENT` log of the nonce is looked up first.
+  fn send_releases(&mut self) -> Result<()> {
+    let queued = self
+      .cursor
+      .releases
+      .iter()
+      .filter(|(_, release)| release.tx.is_none())
+      .map(|(nonce, release)| (*nonce, release.clone()))
+      .collect::<Vec<_>>();
+    for (nonce, mut release) in queued {
+      if let Some(tx) = self
+        .evm
+        .released(&release.contract, nonce, release.evm_block)?
+      {
+        log::info!("burn {} is released in {:?}", nonce, tx);
+        release.tx = Some(tx);
+      } else if !self.clover.burn_pending(self.config.chain_id, nonce)? {
+        log::info!("burn {} is not pending anymore", nonce);
+        self.cursor.releases.remove(&nonce);
+        self.cursor.save()?;
+        continue;
+      } else if release.attempts >= self.config.max_release_attempts {
+        // the burn stays pending on Clover, to be refunded by the bridge admin
+        log::error!(
+          "burn {} is not released after {} attempts",
+          nonce,
+          release.attempts
+        );
+        continue;
+      } else {
+        release.attempts += 1;
+        self.cursor.releases.insert(nonce, release.clone());
+        self.cursor.save()?;
+        let tx = self
+          .evm
+          .release(release.contract, release.dest, release.amount, nonce)?;
+        log::info!("releasing burn {} in {:?}", nonce, tx);
+        release.tx = Some(tx);
+      }
+      self.cursor.releases.insert(nonce, release);
+      self.cursor.save()?;
+    }
+    Ok(())
+  }
+
+  /// Confirm the releases with enough confirmations on Clover, failed releases are queued again
+  fn confirm_releases(&mut self) -> Result<()> {
+    if self.cursor.releases.is_empty() {
+      return Ok(());
+    }
+    let head = self.evm.block_number()?;
+    let mut confirmed = Vec::new();
+    let mut failed = Vec::new();
+    for (nonce, release) in &self.cursor.releases {
+      let tx = match release.tx {
+        Some(tx) => tx,
+        None => continue,
+      };
+      match self.evm.receipt(&tx)? {
+        Some((block, true)) if block.saturating_add(self.config.confirmations) <= head => {
+          confirmed.push((*nonce, tx))
+        }
+        Some((_, false)) => failed.push((*nonce, tx)),
+        _ => {}
+      }
+    }
+
+    for (nonce, tx) in failed {
+      log::error!("release of burn {} failed in {:?}", nonce, tx);
+      if let Some(release) = self.cursor.releases.get_mut(&nonce) {
+        release.tx = None;
+      }
+    }
+    for batch in confirmed.chunks(MAX_BATCH_SIZE) {
+      log::info!("confirming {} releases", batch.len());
+      self
+        .clover
+        .confirm_burns(self.config.chain_id, batch.to_vec())?;
+      for (nonce, _) in batch {
+        self.cursor.releases.remove(nonce);
+      }
+    }
+    self.cursor.save()
+  }
+}
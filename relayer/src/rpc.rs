@@ -0,0 +1,70 @@
+// Copyright (C) 2021 Clover Network
+// This file is part of Clover.
+
+//! Blocking JSON-RPC 2.0 client over HTTP, shared by the EVM and the Clover clients.
+
+use crate::{Error, Result};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+pub struct RpcClient {
+  url: String,
+  agent: ureq::Agent,
+  next_id: AtomicU64,
+}
+
+impl RpcClient {
+  pub fn new(url: &str) -> Self {
+    RpcClient {
+      url: url.into(),
+      agent: ureq::Agent::new(),
+      next_id: AtomicU64::new(1),
+    }
+  }
+
+  /// Call the method, `params` is the array of positional params
+  pub fn call<T: DeserializeOwned>(&self, method: &str, params: Value) -> Result<T> {
+    let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+    let response: Value = self
+      .agent
+      .post(&self.url)
+      .send_json(json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "method": method,
+        "params": params,
+      }))
+      .map_err(|e| Error::Http(format!("{}: {}", self.url, e)))?
+      .into_json()
+      .map_err(|e| Error::Http(format!("{}: {}", self.url, e)))?;
+
+    if let Some(error) = response.get("error") {
+      return Err(Error::Rpc(method.into(), error.to_string()));
+    }
+    let result = response.get("result").cloned().unwrap_or(Value::Null);
+    serde_json::from_value(result).map_err(|e| Error::Decode(format!("{}: {}", method, e)))
+  }
+}
+
+/// `0x` prefixed hex of the bytes
+pub fn to_hex(data: &[u8]) -> String {
+  format!("0x{}", hex::encode(data))
+}
+
+/// Bytes of `0x` prefixed hex
+pub fn from_hex(data: &str) -> Result<Vec<u8>> {
+  hex::decode(data.trim_start_matches("0x")).map_err(|e| Error::Decode(format!("{}: {}", data, e)))
+}
+
+/// Number of `0x` prefixed hex quantity
+pub fn from_quantity(data: &str) -> Result<u64> {
+  u64::from_str_radix(data.trim_start_matches("0x"), 16)
+    .map_err(|e| Error::Decode(format!("{}: {}", data, e)))
+}
+
+/// Amount of `0x` prefixed hex quantity
+pub fn from_amount(data: &str) -> Result<u128> {
+  u128::from_str_radix(data.trim_start_matches("0x"), 16)
+    .map_err(|e| Error::Decode(format!("{}: {}", data, e)))
+}
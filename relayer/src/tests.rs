@@ -0,0 +1,530 @@
+// Copyright (C) 2021 Clover Network
+// This file is part of Clover.
+
+use crate::{
+  clover::{BridgeClient, Burn, ClaimState},
+  config::Config,
+  cursor::Cursor,
+  evm::{decode_deposit, decode_released_nonce, release_call, Deposit, EvmClient},
+  relayer::Relayer,
+  Error, Result,
+};
+use clover_claims::{
+  BurnNonce, ChainId, CurrencyId, EthereumAddress, EthereumTxHash, DEPOSIT_EVENT, H160,
+};
+use primitives::{Balance, BlockNumber};
+use sp_core::{hashing::keccak_256, H256};
+use std::{
+  cell::RefCell,
+  collections::BTreeMap,
+  fs,
+  path::{Path, PathBuf},
+  rc::Rc,
+};
+
+const BSC: ChainId = 56;
+
+fn token(n: u8) -> H160 {
+  H160::repeat_byte(n)
+}
+
+fn tx(n: u8) -> EthereumTxHash {
+  EthereumTxHash([n; 32])
+}
+
+fn addr(n: u8) -> EthereumAddress {
+  EthereumAddress([n; 20])
+}
+
+/// EVM chain stand-in
+#[derive(Default)]
+struct EvmState {
+  head: u64,
+  deposits: Vec<Deposit>,
+  /// `(contract, dest, amount, nonce)` of the sent releases
+  releases: Vec<(H160, EthereumAddress, u128, BurnNonce)>,
+  /// The relayer stops right after sending a release
+  fail_release: bool,
+  /// `(contract, nonce, tx)` of the `RELEASED_EVENT` logs
+  released: Vec<(H160, BurnNonce, EthereumTxHash)>,
+  receipts: BTreeMap<[u8; 32], (u64, bool)>,
+  /// `(contract, from_block, balance)` of the deposit contracts
+  balances: Vec<(H160, u64, u128)>,
+}
+
+#[derive(Clone, Default)]
+struct MockEvm(Rc<RefCell<EvmState>>);
+
+impl EvmClient for MockEvm {
+  fn block_number(&self) -> Result<u64> {
+    Ok(self.0.borrow().head)
+  }
+
+  fn deposits(&self, contracts: &[H160], from: u64, to: u64) -> Result<Vec<Deposit>> {
+    Ok(
+      self
+        .0
+        .borrow()
+        .deposits
+        .iter()
+        .filter(|d| contracts.contains(&d.contract) && d.block >= from && d.block <= to)
+        .cloned()
+        .collect(),
+    )
+  }
+
+  fn release(
+    &self,
+    contract: H160,
+    dest: EthereumAddress,
+    amount: u128,
+    nonce: BurnNonce,
+  ) -> Result<EthereumTxHash> {
+    let mut state = self.0.borrow_mut();
+    let sent = state.releases.iter().filter(|r| r.3 == nonce).count() as u8;
+    state.releases.push((contract, dest, amount, nonce));
+    if state.fail_release {
+      return Err(Error::Http("connection reset".into()));
+    }
+    Ok(tx(100 + nonce as u8 + 10 * sent))
+  }
+
+  fn released(&self, contract: &H160, nonce: BurnNonce, _: u64) -> Result<Option<EthereumTxHash>> {
+    Ok(
+      self
+        .0
+        .borrow()
+        .released
+        .iter()
+        .find(|(c, n, _)| c == contract && *n == nonce)
+        .map(|(_, _, tx)| *tx),
+    )
+  }
+
+  fn receipt(&self, tx: &EthereumTxHash) -> Result<Option<(u64, bool)>> {
+    Ok(self.0.borrow().receipts.get(&tx.0).cloned())
+  }
+
+  fn locked(&self, contract: &H160, block: u64) -> Result<u128> {
+    Ok(
+      self
+        .0
+        .borrow()
+        .balances
+        .iter()
+        .filter(|(c, from, _)| c == contract && *from <= block)
+        .last()
+        .map_or(0, |(_, _, balance)| *balance),
+    )
+  }
+}
+
+type MintItems = Vec<(EthereumTxHash, EthereumAddress, Balance)>;
+
+/// Clover node stand-in
+#[derive(Default)]
+struct CloverState {
+  finalized: BlockNumber,
+  claims: BTreeMap<[u8; 32], ClaimState>,
+  /// `(currency_id, items)` of the submitted `mint_claims_batch`
+  mints: Vec<(CurrencyId, MintItems)>,
+  burns: BTreeMap<BlockNumber, Vec<Burn>>,
+  pending_burns: Vec<BurnNonce>,
+  /// Items of the submitted `confirm_burns`
+  confirmed: Vec<Vec<(BurnNonce, EthereumTxHash)>>,
+  /// `(currency_id, locked)` of the submitted `report_locked`
+  reports: Vec<(CurrencyId, Balance)>,
+}
+
+#[derive(Clone, Default)]
+struct MockClover(Rc<RefCell<CloverState>>);
+
+impl BridgeClient for MockClover {
+  fn claim_state(&self, _: ChainId, tx: &EthereumTxHash) -> Result<ClaimState> {
+    Ok(
+      self
+        .0
+        .borrow()
+        .claims
+        .get(&tx.0)
+        .cloned()
+        .unwrap_or(ClaimState::Unknown),
+    )
+  }
+
+  fn mint_claims(&self, _: ChainId, currency_id: CurrencyId, items: MintItems) -> Result<()> {
+    let mut state = self.0.borrow_mut();
+    for (tx, _, _) in &items {
+      state.claims.insert(tx.0, ClaimState::Done);
+    }
+    state.mints.push((currency_id, items));
+    Ok(())
+  }
+
+  fn finalized_number(&self) -> Result<BlockNumber> {
+    Ok(self.0.borrow().finalized)
+  }
+
+  fn burns(&self, _: ChainId, number: BlockNumber) -> Result<Vec<Burn>> {
+    Ok(
+      self
+        .0
+        .borrow()
+        .burns
+        .get(&number)
+        .cloned()
+        .unwrap_or_default(),
+    )
+  }
+
+  fn burn_pending(&self, _: ChainId, nonce: BurnNonce) -> Result<bool> {
+    Ok(self.0.borrow().pending_burns.contains(&nonce))
+  }
+
+  fn confirm_burns(&self, _: ChainId, items: Vec<(BurnNonce, EthereumTxHash)>) -> Result<()> {
+    let mut state = self.0.borrow_mut();
+    state
+      .pending_burns
+      .retain(|nonce| !items.iter().any(|(n, _)| n == nonce));
+    state.confirmed.push(items);
+    Ok(())
+  }
+
+  fn report_locked(&self, _: ChainId, currency_id: CurrencyId, locked: Balance) -> Result<()> {
+    self.0.borrow_mut().reports.push((currency_id, locked));
+    Ok(())
+  }
+}
+
+fn cursor_path(name: &str) -> PathBuf {
+  let path = std::env::temp_dir().join(format!(
+    "clover-relayer-{}-{}.json",
+    name,
+    std::process::id()
+  ));
+  let _ = fs::remove_file(&path);
+  path
+}
+
+fn config(cursor_path: PathBuf) -> Config {
+  Config {
+    clover_url: "http://127.0.0.1:9933".into(),
+    evm_url: "http://127.0.0.1:8545".into(),
+    chain_id: BSC,
+    suri: "//Alice".into(),
+    evm_account: token(9),
+    contracts: vec![(token(1), CurrencyId::CLV), (token(2), CurrencyId::CETH)],
+    confirmations: 3,
+    max_blocks: 100,
+    poll_interval: 6,
+    inclusion_timeout: 60,
+    max_release_attempts: 3,
+    lock_report_interval: 0,
+    cursor_path,
+    evm_start_block: 0,
+    clover_start_block: 0,
+  }
+}
+
+fn relayer(config: &Config, evm: &MockEvm, clover: &MockClover) -> Relayer<MockEvm, MockClover> {
+  let cursor = Cursor::load(&config.cursor_path, config.evm_start_block, 0).unwrap();
+  Relayer::new(config.clone(), cursor, evm.clone(), clover.clone())
+}
+
+fn deposit(contract: H160, block: u64, n: u8, amount: u128) -> Deposit {
+  Deposit {
+    contract,
+    block,
+    tx: tx(n),
+    who: addr(n),
+    amount,
+  }
+}
+
+#[test]
+fn deposits_should_be_minted_once_confirmed() {
+  let config = config(cursor_path("deposits"));
+  let (evm, clover) = (MockEvm::default(), MockClover::default());
+  evm.0.borrow_mut().deposits = vec![
+    deposit(token(1), 2, 1, 100),
+    deposit(token(2), 3, 2, 200),
+    // not a deposit contract of the config
+    deposit(token(3), 3, 3, 300),
+    deposit(token(1), 5, 4, 400),
+    deposit(token(1), 6, 5, 500),
+  ];
+  // minted by other relayers
+  clover
+    .0
+    .borrow_mut()
+    .claims
+    .insert(tx(4).0, ClaimState::Done);
+  evm.0.borrow_mut().head = 2;
+
+  let mut relayer = relayer(&config, &evm, &clover);
+  relayer.poll().unwrap();
+  assert!(clover.0.borrow().mints.is_empty());
+  assert_eq!(relayer.cursor().evm_block, 0);
+
+  evm.0.borrow_mut().head = 8;
+  relayer.poll().unwrap();
+  assert_eq!(
+    clover.0.borrow().mints,
+    vec![
+      (CurrencyId::CLV, vec![(tx(1), addr(1), 100)]),
+      (CurrencyId::CETH, vec![(tx(2), addr(2), 200)]),
+    ]
+  );
+  assert_eq!(relayer.cursor().evm_block, 6);
+
+  // a restarted relayer resumes from the saved cursor
+  let mut relayer = self::relayer(&config, &evm, &clover);
+  assert_eq!(relayer.cursor().evm_block, 6);
+  evm.0.borrow_mut().head = 9;
+  relayer.poll().unwrap();
+  assert_eq!(clover.0.borrow().mints.len(), 3);
+  assert_eq!(
+    clover.0.borrow().mints[2],
+    (CurrencyId::CLV, vec![(tx(5), addr(5), 500)])
+  );
+  assert_eq!(relayer.cursor().evm_block, 7);
+
+  fs::remove_file(&config.cursor_path).unwrap();
+}
+
+#[test]
+fn burns_should_be_released_and_confirmed() {
+  let config = config(cursor_path("burns"));
+  let (evm, clover) = (MockEvm::default(), MockClover::default());
+  {
+    let mut state = clover.0.borrow_mut();
+    state.burns.insert(
+      2,
+      vec![
+        Burn {
+          nonce: 0,
+          currency_id: CurrencyId::CLV,
+          dest: addr(1),
+          amount: 100,
+        },
+        // refunded already
+        Burn {
+          nonce: 1,
+          currency_id: CurrencyId::CLV,
+          dest: addr(2),
+          amount: 200,
+        },
+      ],
+    );
+    state.burns.insert(
+      4,
+      vec![Burn {
+        nonce: 2,
+        currency_id: CurrencyId::CETH,
+        dest: addr(3),
+        amount: 300,
+      }],
+    );
+    state.pending_burns = vec![0, 2];
+    state.finalized = 3;
+  }
+
+  let mut relayer = relayer(&config, &evm, &clover);
+  relayer.poll().unwrap();
+  assert_eq!(evm.0.borrow().releases, vec![(token(1), addr(1), 100, 0)]);
+  assert_eq!(relayer.cursor().clover_block, 4);
+  assert_eq!(relayer.cursor().releases[&0].tx, Some(tx(100)));
+
+  // the release is included but not confirmed yet
+  evm.0.borrow_mut().receipts.insert(tx(100).0, (10, true));
+  evm.0.borrow_mut().head = 12;
+  relayer.poll().unwrap();
+  assert!(clover.0.borrow().confirmed.is_empty());
+
+  // a restarted relayer does not release the burn again
+  let mut relayer = self::relayer(&config, &evm, &clover);
+  evm.0.borrow_mut().head = 13;
+  clover.0.borrow_mut().finalized = 4;
+  relayer.poll().unwrap();
+  assert_eq!(evm.0.borrow().releases.len(), 2);
+  assert_eq!(evm.0.borrow().releases[1], (token(2), addr(3), 300, 2));
+  assert_eq!(clover.0.borrow().confirmed, vec![vec![(0, tx(100))]]);
+  assert_eq!(
+    relayer
+      .cursor()
+      .releases
+      .keys()
+      .cloned()
+      .collect::<Vec<_>>(),
+    vec![2]
+  );
+
+  // failed releases are queued again
+  evm.0.borrow_mut().receipts.insert(tx(102).0, (13, false));
+  relayer.poll().unwrap();
+  assert_eq!(relayer.cursor().releases[&2].tx, None);
+  assert_eq!(clover.0.borrow().confirmed.len(), 1);
+  relayer.poll().unwrap();
+  assert_eq!(evm.0.borrow().releases[2], (token(2), addr(3), 300, 2));
+  assert_eq!(relayer.cursor().releases[&2].tx, Some(tx(112)));
+  assert_eq!(relayer.cursor().releases[&2].attempts, 2);
+
+  evm.0.borrow_mut().receipts.insert(tx(112).0, (14, true));
+  evm.0.borrow_mut().head = 17;
+  relayer.poll().unwrap();
+  assert_eq!(clover.0.borrow().confirmed[1], vec![(2, tx(112))]);
+  assert!(relayer.cursor().releases.is_empty());
+
+  fs::remove_file(&config.cursor_path).unwrap();
+}
+
+#[test]
+fn releases_should_be_saved_before_sending() {
+  let config = config(cursor_path("release-intents"));
+  let (evm, clover) = (MockEvm::default(), MockClover::default());
+  {
+    let mut state = clover.0.borrow_mut();
+    state.burns.insert(
+      1,
+      vec![
+        Burn {
+          nonce: 0,
+          currency_id: CurrencyId::CLV,
+          dest: addr(1),
+          amount: 100,
+        },
+        Burn {
+          nonce: 1,
+          currency_id: CurrencyId::CLV,
+          dest: addr(2),
+          amount: 200,
+        },
+      ],
+    );
+    state.pending_burns = vec![0, 1];
+    state.finalized = 1;
+  }
+  evm.0.borrow_mut().fail_release = true;
+
+  let mut relayer = relayer(&config, &evm, &clover);
+  assert!(relayer.poll().is_err());
+  assert_eq!(evm.0.borrow().releases.len(), 1);
+
+  // a restarted relayer finds the release sent before the crash by its log
+  evm.0.borrow_mut().fail_release = false;
+  evm.0.borrow_mut().released.push((token(1), 0, tx(100)));
+  let mut relayer = self::relayer(&config, &evm, &clover);
+  assert_eq!(relayer.cursor().clover_block, 2);
+  assert_eq!(relayer.cursor().releases[&0].attempts, 1);
+  relayer.poll().unwrap();
+  assert_eq!(
+    evm.0.borrow().releases,
+    vec![(token(1), addr(1), 100, 0), (token(1), addr(2), 200, 1)]
+  );
+  assert_eq!(relayer.cursor().releases[&0].tx, Some(tx(100)));
+  assert_eq!(relayer.cursor().releases[&1].tx, Some(tx(101)));
+
+  // failing releases are sent at most `max_release_attempts` times
+  for n in [101, 111, 121].iter() {
+    evm.0.borrow_mut().receipts.insert(tx(*n).0, (1, false));
+  }
+  for _ in 0..4 {
+    relayer.poll().unwrap();
+  }
+  assert_eq!(evm.0.borrow().releases.len(), 4);
+  assert_eq!(relayer.cursor().releases[&1].attempts, 3);
+  assert_eq!(relayer.cursor().releases[&1].tx, None);
+
+  // until the bridge admin refunds the burn
+  clover.0.borrow_mut().pending_burns = vec![0];
+  relayer.poll().unwrap();
+  assert_eq!(
+    relayer
+      .cursor()
+      .releases
+      .keys()
+      .cloned()
+      .collect::<Vec<_>>(),
+    vec![0]
+  );
+
+  fs::remove_file(&config.cursor_path).unwrap();
+}
+
+#[test]
+fn placeholder_addresses_should_fail() {
+  let dev = Path::new(env!("CARGO_MANIFEST_DIR")).join("dev.json");
+  assert!(matches!(Config::load(&dev), Err(Error::Config(_))));
+}
+
+#[test]
+fn decode_deposit_should_work() {
+  let topic = H256(keccak_256(DEPOSIT_EVENT));
+  let mut who = H256::zero();
+  who.as_bytes_mut()[12..].copy_from_slice(&[7; 20]);
+  let mut data = [0u8; 32];
+  data[31] = 100;
+
+  assert_eq!(decode_deposit(&[topic, who], &data), Some((addr(7), 100)));
+  assert_eq!(decode_deposit(&[who, who], &data), None);
+  assert_eq!(decode_deposit(&[topic], &data), None);
+  assert_eq!(decode_deposit(&[topic, who], &data[1..]), None);
+  // above u128
+  data[15] = 1;
+  assert_eq!(decode_deposit(&[topic, who], &data), None);
+}
+
+#[test]
+fn release_call_should_work() {
+  let data = release_call(addr(7), 100, 3);
+  assert_eq!(data.len(), 4 + 3 * 32);
+  assert_eq!(
+    data[..4],
+    keccak_256(b"release(address,uint256,uint256)")[..4]
+  );
+  assert_eq!(data[16..36], [7; 20]);
+  assert_eq!(data[67], 100);
+  assert_eq!(data[99], 3);
+}
+
+#[test]
+fn decode_released_nonce_should_work() {
+  let mut data = [0u8; 64];
+  data[31] = 100;
+  data[63] = 7;
+  assert_eq!(decode_released_nonce(&data), Some(7));
+  data[40] = 1;
+  assert_eq!(decode_released_nonce(&data), None);
+  assert_eq!(decode_released_nonce(&data[..32]), None);
+}
+
+#[test]
+fn locked_values_should_be_reported() {
+  let mut config = config(cursor_path("locked"));
+  config.lock_report_interval = 10;
+  let (evm, clover) = (MockEvm::default(), MockClover::default());
+  evm.0.borrow_mut().balances = vec![(token(1), 2, 100), (token(2), 3, 200), (token(1), 12, 150)];
+  evm.0.borrow_mut().head = 8;
+
+  // reported at the last scanned block
+  let mut relayer = relayer(&config, &evm, &clover);
+  relayer.poll().unwrap();
+  assert_eq!(
+    clover.0.borrow().reports,
+    vec![(CurrencyId::CLV, 100), (CurrencyId::CETH, 200)]
+  );
+
+  // at most once per interval
+  evm.0.borrow_mut().head = 17;
+  relayer.poll().unwrap();
+  assert_eq!(clover.0.borrow().reports.len(), 2);
+
+  evm.0.borrow_mut().head = 18;
+  relayer.poll().unwrap();
+  assert_eq!(
+    clover.0.borrow().reports[2..],
+    [(CurrencyId::CLV, 150), (CurrencyId::CETH, 200)]
+  );
+
+  fs::remove_file(&config.cursor_path).unwrap();
+}
@@ -35,4 +35,44 @@ sp_api::decl_runtime_apis! {
     Share: codec::Codec, {
     fn get_all_incentive_pools() -> sp_std::vec::Vec<(CurrencyId, CurrencyId, Share, Balance)>;
   }
+
+  pub trait ClaimsApi<AccountId, Balance, BlockNumber> where
+    AccountId: codec::Codec,
+    Balance: codec::Codec,
+    BlockNumber: codec::Codec, {
+    // Get pending burns to the chain from the `start` nonce, at most `limit` items
+    fn pending_burns(chain_id: clover_claims::ChainId, start: clover_claims::BurnNonce, limit: u32) -> sp_std::vec::Vec<(clover_claims::BurnNonce, clover_claims::BurnInfo<AccountId, Balance, BlockNumber>)>;
+    // Get the remaining value of the currency can be minted on the chain, to the ethereum address if given
+    fn remaining_claim_limit(chain_id: clover_claims::ChainId, currency_id: clover_claims::CurrencyId, who: Option<clover_claims::EthereumAddress>) -> Balance;
+    // Get the total mint and burn fees of the currency collected from the chain
+    fn fees_collected(chain_id: clover_claims::ChainId, currency_id: clover_claims::CurrencyId) -> Balance;
+    // Get the mint fee schedule of the currency from the chain
+    fn mint_fee_schedule(chain_id: clover_claims::ChainId, currency_id: clover_claims::CurrencyId) -> Option<clover_claims::FeeSchedule<Balance>>;
+    // Get the burn fee schedule of the currency to the chain
+    fn burn_fee_schedule(chain_id: clover_claims::ChainId, currency_id: clover_claims::CurrencyId) -> Option<clover_claims::FeeSchedule<Balance>>;
+    // Get the fee charged for minting the amount of the currency from the chain
+    fn mint_fee(chain_id: clover_claims::ChainId, currency_id: clover_claims::CurrencyId, amount: Balance) -> Balance;
+    // Get the fee charged for burning the amount of the currency to the chain
+    fn burn_fee(chain_id: clover_claims::ChainId, currency_id: clover_claims::CurrencyId, amount: Balance) -> Balance;
+    // Get the status of the claim of the tx from the chain
+    fn claim_status(chain_id: clover_claims::ChainId, tx: clover_claims::EthereumTxHash) -> clover_claims::ClaimStatus<Balance, BlockNumber>;
+    // Get the minted claims from the chain to the ethereum address which are not claimed yet
+    fn pending_claims(chain_id: clover_claims::ChainId, who: clover_claims::EthereumAddress) -> sp_std::vec::Vec<(clover_claims::EthereumTxHash, clover_claims::ClaimDetails<Balance, BlockNumber>)>;
+    // Get the message to sign with `personal_sign` for `claim`
+    fn claim_message(dest: AccountId, tx: clover_claims::EthereumTxHash) -> sp_std::vec::Vec<u8>;
+    // Get the message to sign with `personal_sign` for `claim_to_evm`
+    fn evm_claim_message(dest: clover_claims::H160, tx: clover_claims::EthereumTxHash) -> sp_std::vec::Vec<u8>;
+    // Get the message to sign with `personal_sign` for the next `burn_from_evm` of the source
+    fn evm_burn_message(source: clover_claims::H160, chain_id: clover_claims::ChainId, currency_id: clover_claims::CurrencyId, dest: clover_claims::EthereumAddress, amount: Balance) -> sp_std::vec::Vec<u8>;
+    // Get the EIP-712 hash to sign for `claim_typed`
+    fn claim_typed_data_hash(dest: AccountId, chain_id: clover_claims::ChainId, tx: clover_claims::EthereumTxHash) -> [u8; 32];
+    // Get the current limits of minting the currency from the chain
+    fn claim_limits(chain_id: clover_claims::ChainId, currency_id: clover_claims::CurrencyId) -> clover_claims::ClaimLimits<Balance, BlockNumber>;
+    // Get the reward ledgers of the relayer by currency
+    fn relayer_rewards(who: AccountId) -> sp_std::vec::Vec<(clover_claims::CurrencyId, clover_claims::RewardLedger<Balance>)>;
+    // Get the bridged supply of the currency from the chain and its latest lock report
+    fn supply_audit(chain_id: clover_claims::ChainId, currency_id: clover_claims::CurrencyId) -> clover_claims::SupplyAudit<AccountId, Balance, BlockNumber>;
+    // Get the certificate of the burn to the chain signed by the bridge authorities
+    fn burn_certificate(chain_id: clover_claims::ChainId, nonce: clover_claims::BurnNonce) -> Option<clover_claims::CertifiedBurn<Balance>>;
+  }
 }
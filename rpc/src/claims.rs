@@ -0,0 +1,434 @@
+use super::*;
+
+use std::fmt::Display;
+use codec::Codec;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use clover_claims::{
+  BurnNonce, CertifiedBurn, ChainId, ClaimDetails, ClaimLimits, ClaimStatus, CurrencyId,
+  EthereumAddress, EthereumTxHash, FeeSchedule, RewardLedger, SupplyAudit, H160,
+};
+use sp_runtime::Permill;
+
+pub use clover_rpc_runtime_api::ClaimsApi as ClaimsRuntimeApi;
+
+/// A minted claim, balances are formatted as strings
+#[derive(Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ClaimInfo<BlockNumber> {
+  currency_id: CurrencyId,
+  who: EthereumAddress,
+  amount: String,
+  expiry: Option<BlockNumber>,
+  vesting: Option<ClaimVestingInfo<BlockNumber>>,
+}
+
+/// Vesting of a claim, balances are formatted as strings
+#[derive(Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ClaimVestingInfo<BlockNumber> {
+  start: BlockNumber,
+  per_block: String,
+}
+
+/// Status of the claim of a tx
+#[derive(Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(tag = "status", rename_all = "camelCase"))]
+pub enum ClaimStatusInfo<BlockNumber> {
+  Unknown,
+  Attesting { attestations: u32 },
+  Minted(ClaimInfo<BlockNumber>),
+  Claimed(ClaimInfo<BlockNumber>),
+  Revoked,
+  #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+  Delayed { claim: ClaimInfo<BlockNumber>, finalize_at: BlockNumber },
+}
+
+#[derive(Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ClaimWindowInfo<BlockNumber> {
+  limit: String,
+  period: BlockNumber,
+  start: BlockNumber,
+}
+
+/// Current limits of minting a currency from a chain
+#[derive(Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ClaimLimitsInfo<BlockNumber> {
+  claim_limit: String,
+  remaining_claim_limit: String,
+  claim_window: Option<ClaimWindowInfo<BlockNumber>>,
+  max_claim_amount: Option<String>,
+  address_daily_cap: Option<String>,
+}
+
+#[derive(Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FeeScheduleInfo {
+  rate: Permill,
+  min: String,
+  max: Option<String>,
+  tiers: Vec<(String, Permill)>,
+}
+
+/// Reward ledger of a relayer in a currency, balances are formatted as strings
+#[derive(Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RelayerRewardsInfo {
+  currency_id: CurrencyId,
+  earned: String,
+  claimed: String,
+}
+
+/// Value locked on the source chain reported by a relayer, balances are formatted as strings
+#[derive(Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct LockReportInfo<AccountId, BlockNumber> {
+  relayer: AccountId,
+  locked: String,
+  block: BlockNumber,
+}
+
+/// Bridged supply of a currency from a chain, balances are formatted as strings
+#[derive(Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct SupplyAuditInfo<AccountId, BlockNumber> {
+  minted: String,
+  claimed: String,
+  burned: String,
+  fees: String,
+  lock_report: Option<LockReportInfo<AccountId, BlockNumber>>,
+}
+
+/// A burn certified by the bridge authorities in the form verified by the contracts of the
+/// dest chain, balances are formatted as strings and bytes hex encoded
+#[derive(Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct BurnCertificateInfo {
+  chain_id: ChainId,
+  nonce: BurnNonce,
+  currency_id: CurrencyId,
+  /// The contract of the currency on the chain, which verifies the certificate
+  token: H160,
+  dest: EthereumAddress,
+  amount: String,
+  message: String,
+  set_id: u64,
+  signers: Vec<EthereumAddress>,
+  /// `r || s || v` signatures of `message` in the order of `signers`, `v` of 27 or 28
+  signatures: Vec<String>,
+}
+
+/// Fee schedules of bridging a currency from and to a chain
+#[derive(Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct BridgeFeesInfo {
+  mint_fee: Option<FeeScheduleInfo>,
+  burn_fee: Option<FeeScheduleInfo>,
+  fees_collected: String,
+}
+
+#[rpc]
+pub trait ClaimsRpc<BlockHash, AccountId, Balance, BlockNumber> {
+  #[rpc(name = "claims_claimStatus")]
+  fn claim_status(&self, chain_id: ChainId, tx: EthereumTxHash, at: Option<BlockHash>) -> Result<ClaimStatusInfo<BlockNumber>>;
+
+  #[rpc(name = "claims_pendingClaims")]
+  fn pending_claims(&self, chain_id: ChainId, who: EthereumAddress, at: Option<BlockHash>) -> Result<Vec<(EthereumTxHash, ClaimInfo<BlockNumber>)>>;
+
+  /// The bytes to sign with `personal_sign` for `claim`, hex encoded
+  #[rpc(name = "claims_claimMessage")]
+  fn claim_message(&self, dest: AccountId, tx: EthereumTxHash, at: Option<BlockHash>) -> Result<String>;
+
+  /// The bytes to sign with `personal_sign` for `claim_to_evm`, hex encoded
+  #[rpc(name = "claims_evmClaimMessage")]
+  fn evm_claim_message(&self, dest: H160, tx: EthereumTxHash, at: Option<BlockHash>) -> Result<String>;
+
+  /// The bytes to sign with `personal_sign` for the next `burn_from_evm` of the source, hex encoded
+  #[rpc(name = "claims_evmBurnMessage")]
+  fn evm_burn_message(&self, source: H160, chain_id: ChainId, currency_id: CurrencyId, dest: EthereumAddress, amount: Balance, at: Option<BlockHash>) -> Result<String>;
+
+  /// The EIP-712 hash to sign for `claim_typed`, hex encoded
+  #[rpc(name = "claims_claimTypedDataHash")]
+  fn claim_typed_data_hash(&self, dest: AccountId, chain_id: ChainId, tx: EthereumTxHash, at: Option<BlockHash>) -> Result<String>;
+
+  #[rpc(name = "claims_claimLimits")]
+  fn claim_limits(&self, chain_id: ChainId, currency_id: CurrencyId, who: Option<EthereumAddress>, at: Option<BlockHash>) -> Result<ClaimLimitsInfo<BlockNumber>>;
+
+  #[rpc(name = "claims_fees")]
+  fn fees(&self, chain_id: ChainId, currency_id: CurrencyId, at: Option<BlockHash>) -> Result<BridgeFeesInfo>;
+
+  /// Reward ledgers of the relayer by currency
+  #[rpc(name = "claims_relayerRewards")]
+  fn relayer_rewards(&self, who: AccountId, at: Option<BlockHash>) -> Result<Vec<RelayerRewardsInfo>>;
+
+  /// Bridged supply of the currency from the chain and its latest lock report
+  #[rpc(name = "claims_supplyAudit")]
+  fn supply_audit(&self, chain_id: ChainId, currency_id: CurrencyId, at: Option<BlockHash>) -> Result<SupplyAuditInfo<AccountId, BlockNumber>>;
+
+  /// Certificate of the burn to the chain signed by more than two thirds of the bridge authorities
+  #[rpc(name = "claims_burnCertificate")]
+  fn burn_certificate(&self, chain_id: ChainId, nonce: BurnNonce, at: Option<BlockHash>) -> Result<Option<BurnCertificateInfo>>;
+}
+
+pub struct Claims<C, B> {
+  client: Arc<C>,
+  _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Claims<C, B> {
+  pub fn new(client: Arc<C>) -> Self {
+    Claims {
+      client,
+      _marker: Default::default(),
+    }
+  }
+}
+
+fn runtime_error<E: std::fmt::Debug>(e: E) -> RpcError {
+  RpcError {
+    code: ErrorCode::ServerError(Error::RuntimeError.into()),
+    message: "Unable to get value.".into(),
+    data: Some(format!("{:?}", e).into()),
+  }
+}
+
+fn to_hex(data: &[u8]) -> String {
+  format!("0x{}", data.iter().map(|b| format!("{:02x}", b)).collect::<String>())
+}
+
+fn claim_info<Balance: Display, BlockNumber>(details: ClaimDetails<Balance, BlockNumber>) -> ClaimInfo<BlockNumber> {
+  ClaimInfo {
+    currency_id: details.currency_id,
+    who: details.who,
+    amount: format!("{}", details.amount),
+    expiry: details.expiry,
+    vesting: details.vesting.map(|vesting| ClaimVestingInfo {
+      start: vesting.start,
+      per_block: format!("{}", vesting.per_block),
+    }),
+  }
+}
+
+fn fee_schedule_info<Balance: Display>(schedule: FeeSchedule<Balance>) -> FeeScheduleInfo {
+  FeeScheduleInfo {
+    rate: schedule.rate,
+    min: format!("{}", schedule.min),
+    max: schedule.max.map(|max| format!("{}", max)),
+    tiers: schedule.tiers.into_iter().map(|(amount, rate)| (format!("{}", amount), rate)).collect(),
+  }
+}
+
+impl<C, Block, AccountId, Balance, BlockNumber> ClaimsRpc<<Block as BlockT>::Hash, AccountId, Balance, BlockNumber> for Claims<C, Block>
+where
+  Block: BlockT,
+  C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+  C::Api: ClaimsRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+  AccountId: Codec,
+  Balance: Codec + Display,
+  BlockNumber: Codec,
+{
+  fn claim_status(&self,
+    chain_id: ChainId,
+    tx: EthereumTxHash,
+    at: Option<<Block as BlockT>::Hash>
+  ) -> Result<ClaimStatusInfo<BlockNumber>> {
+    let api = self.client.runtime_api();
+    let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+    api.claim_status(&at, chain_id, tx).map_err(runtime_error).map(|status| match status {
+      ClaimStatus::Unknown => ClaimStatusInfo::Unknown,
+      ClaimStatus::Attesting(attestations) => ClaimStatusInfo::Attesting { attestations },
+      ClaimStatus::Minted(details) => ClaimStatusInfo::Minted(claim_info(details)),
+      ClaimStatus::Claimed(details) => ClaimStatusInfo::Claimed(claim_info(details)),
+      ClaimStatus::Revoked => ClaimStatusInfo::Revoked,
+      ClaimStatus::Delayed(details, finalize_at) => ClaimStatusInfo::Delayed {
+        claim: claim_info(details),
+        finalize_at,
+      },
+    })
+  }
+
+  fn pending_claims(&self,
+    chain_id: ChainId,
+    who: EthereumAddress,
+    at: Option<<Block as BlockT>::Hash>
+  ) -> Result<Vec<(EthereumTxHash, ClaimInfo<BlockNumber>)>> {
+    let api = self.client.runtime_api();
+    let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+    api.pending_claims(&at, chain_id, who).map_err(runtime_error).map(|claims|
+      claims.into_iter().map(|(tx, details)| (tx, claim_info(details))).collect())
+  }
+
+  fn claim_message(&self,
+    dest: AccountId,
+    tx: EthereumTxHash,
+    at: Option<<Block as BlockT>::Hash>
+  ) -> Result<String> {
+    let api = self.client.runtime_api();
+    let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+    api.claim_message(&at, dest, tx).map_err(runtime_error).map(|msg| to_hex(&msg))
+  }
+
+  fn evm_claim_message(&self,
+    dest: H160,
+    tx: EthereumTxHash,
+    at: Option<<Block as BlockT>::Hash>
+  ) -> Result<String> {
+    let api = self.client.runtime_api();
+    let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+    api.evm_claim_message(&at, dest, tx).map_err(runtime_error).map(|msg| to_hex(&msg))
+  }
+
+  fn evm_burn_message(&self,
+    source: H160,
+    chain_id: ChainId,
+    currency_id: CurrencyId,
+    dest: EthereumAddress,
+    amount: Balance,
+    at: Option<<Block as BlockT>::Hash>
+  ) -> Result<String> {
+    let api = self.client.runtime_api();
+    let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+    api.evm_burn_message(&at, source, chain_id, currency_id, dest, amount).map_err(runtime_error).map(|msg| to_hex(&msg))
+  }
+
+  fn claim_typed_data_hash(&self,
+    dest: AccountId,
+    chain_id: ChainId,
+    tx: EthereumTxHash,
+    at: Option<<Block as BlockT>::Hash>
+  ) -> Result<String> {
+    let api = self.client.runtime_api();
+    let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+    api.claim_typed_data_hash(&at, dest, chain_id, tx).map_err(runtime_error).map(|hash| to_hex(&hash))
+  }
+
+  fn claim_limits(&self,
+    chain_id: ChainId,
+    currency_id: CurrencyId,
+    who: Option<EthereumAddress>,
+    at: Option<<Block as BlockT>::Hash>
+  ) -> Result<ClaimLimitsInfo<BlockNumber>> {
+    let api = self.client.runtime_api();
+    let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+    let limits: ClaimLimits<Balance, BlockNumber> = api.claim_limits(&at, chain_id, currency_id).map_err(runtime_error)?;
+    let remaining = api.remaining_claim_limit(&at, chain_id, currency_id, who).map_err(runtime_error)?;
+    Ok(ClaimLimitsInfo {
+      claim_limit: format!("{}", limits.claim_limit),
+      remaining_claim_limit: format!("{}", remaining),
+      claim_window: limits.claim_window.map(|window| ClaimWindowInfo {
+        limit: format!("{}", window.limit),
+        period: window.period,
+        start: window.start,
+      }),
+      max_claim_amount: limits.max_claim_amount.map(|amount| format!("{}", amount)),
+      address_daily_cap: limits.address_daily_cap.map(|cap| format!("{}", cap)),
+    })
+  }
+
+  fn fees(&self,
+    chain_id: ChainId,
+    currency_id: CurrencyId,
+    at: Option<<Block as BlockT>::Hash>
+  ) -> Result<BridgeFeesInfo> {
+    let api = self.client.runtime_api();
+    let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+    let mint_fee = api.mint_fee_schedule(&at, chain_id, currency_id).map_err(runtime_error)?;
+    let burn_fee = api.burn_fee_schedule(&at, chain_id, currency_id).map_err(runtime_error)?;
+    let fees_collected = api.fees_collected(&at, chain_id, currency_id).map_err(runtime_error)?;
+    Ok(BridgeFeesInfo {
+      mint_fee: mint_fee.map(fee_schedule_info),
+      burn_fee: burn_fee.map(fee_schedule_info),
+      fees_collected: format!("{}", fees_collected),
+    })
+  }
+
+  fn relayer_rewards(&self,
+    who: AccountId,
+    at: Option<<Block as BlockT>::Hash>
+  ) -> Result<Vec<RelayerRewardsInfo>> {
+    let api = self.client.runtime_api();
+    let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+    api.relayer_rewards(&at, who).map_err(runtime_error).map(|ledgers| {
+      ledgers.into_iter().map(|(currency_id, ledger): (CurrencyId, RewardLedger<Balance>)| RelayerRewardsInfo {
+        currency_id,
+        earned: format!("{}", ledger.earned),
+        claimed: format!("{}", ledger.claimed),
+      }).collect()
+    })
+  }
+
+  fn supply_audit(&self,
+    chain_id: ChainId,
+    currency_id: CurrencyId,
+    at: Option<<Block as BlockT>::Hash>
+  ) -> Result<SupplyAuditInfo<AccountId, BlockNumber>> {
+    let api = self.client.runtime_api();
+    let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+    api.supply_audit(&at, chain_id, currency_id).map_err(runtime_error).map(|audit: SupplyAudit<AccountId, Balance, BlockNumber>| SupplyAuditInfo {
+      minted: format!("{}", audit.minted),
+      claimed: format!("{}", audit.claimed),
+      burned: format!("{}", audit.burned),
+      fees: format!("{}", audit.fees),
+      lock_report: audit.lock_report.map(|report| LockReportInfo {
+        relayer: report.relayer,
+        locked: format!("{}", report.locked),
+        block: report.block,
+      }),
+    })
+  }
+
+  fn burn_certificate(&self,
+    chain_id: ChainId,
+    nonce: BurnNonce,
+    at: Option<<Block as BlockT>::Hash>
+  ) -> Result<Option<BurnCertificateInfo>> {
+    let api = self.client.runtime_api();
+    let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+    api.burn_certificate(&at, chain_id, nonce).map_err(runtime_error).map(|certificate: Option<CertifiedBurn<Balance>>| {
+      certificate.map(|certificate| {
+        let (signers, signatures) = certificate.signatures.into_iter().filter_map(|(signer, signature)| {
+          let mut signature = signature.to_standard(chain_id)?;
+          signature[64] += 27;
+          Some((signer, to_hex(&signature)))
+        }).unzip();
+        BurnCertificateInfo {
+          chain_id,
+          nonce,
+          currency_id: certificate.currency_id,
+          token: certificate.token,
+          dest: certificate.dest,
+          amount: format!("{}", certificate.amount),
+          message: to_hex(&certificate.message),
+          set_id: certificate.set_id,
+          signers,
+          signatures,
+        }
+      })
+    })
+  }
+}
@@ -11,6 +11,7 @@ pub mod pair;
 pub mod balance;
 pub mod exchange;
 pub mod incentive_pool;
+pub mod claims;
 
 pub enum Error {
   RuntimeError,
@@ -10,8 +10,8 @@ use codec::Decode;
 use sp_core::{crypto::KeyTypeId, crypto::Public, OpaqueMetadata, H160, H256, U256};
 use sp_runtime::curve::PiecewiseLinear;
 use sp_runtime::traits::{
-  BlakeTwo256, Block as BlockT, Convert, ConvertInto, NumberFor, OpaqueKeys, SaturatedConversion,
-  StaticLookup,
+  AccountIdConversion, BlakeTwo256, Block as BlockT, Convert, ConvertInto, NumberFor, OpaqueKeys,
+  SaturatedConversion, StaticLookup, Zero,
 };
 use sp_runtime::{
   create_runtime_str, generic, impl_opaque_keys,
@@ -59,6 +59,7 @@ pub use frame_support::{
 use frame_system::{limits, EnsureOneOf, EnsureRoot};
 pub use pallet_balances::Call as BalancesCall;
 use pallet_evm::{Account as EVMAccount, EnsureAddressTruncated, FeeCalculator, Runner};
+use orml_traits::parameter_type_with_key;
 pub use pallet_timestamp::Call as TimestampCall;
 pub use sp_runtime::{Perbill, Permill};
 
@@ -100,6 +101,38 @@ impl_opaque_keys! {
     pub babe: Babe,
     pub im_online: ImOnline,
     pub authority_discovery: AuthorityDiscovery,
+    pub clover_bridge: CloverClaims,
+  }
+}
+
+impl_opaque_keys! {
+  /// Session keys before the bridge key was added.
+  pub struct OldSessionKeys {
+    pub grandpa: Grandpa,
+    pub babe: Babe,
+    pub im_online: ImOnline,
+    pub authority_discovery: AuthorityDiscovery,
+  }
+}
+
+/// Adds a placeholder bridge key, unique to each validator, to the session keys of the
+/// validators, which they replace with `session.setKeys` to sign burn certificates. Only runs
+/// before `CloverClaims` records `Releases::V8_0_0` in the same upgrade, later upgrades must
+/// keep the keys set by the validators.
+pub struct UpgradeSessionKeys;
+impl frame_support::traits::OnRuntimeUpgrade for UpgradeSessionKeys {
+  fn on_runtime_upgrade() -> Weight {
+    if CloverClaims::storage_version() == clover_claims::Releases::V8_0_0 {
+      return 0;
+    }
+    Session::upgrade_keys::<OldSessionKeys, _>(|validator, old| SessionKeys {
+      grandpa: old.grandpa,
+      babe: old.babe,
+      im_online: old.im_online,
+      authority_discovery: old.authority_discovery,
+      clover_bridge: clover_claims::ecdsa::placeholder(&validator),
+    });
+    BlockWeights::get().max_block
   }
 }
 
@@ -107,10 +140,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
   spec_name: create_runtime_str!("clover"),
   impl_name: create_runtime_str!("clover"),
   authoring_version: 1,
-  spec_version: 14,
+  spec_version: 15,
   impl_version: 1,
   apis: RUNTIME_API_VERSIONS,
-  transaction_version: 1,
+  transaction_version: 2,
 };
 
 pub const MILLISECS_PER_BLOCK: u64 = 6000;
@@ -354,6 +387,7 @@ impl evm_accounts::Config for Runtime {
   type KillAccount = frame_system::Consumer<Runtime>;
   type AddressMapping = EvmAddressMapping<Runtime>;
   type MergeAccount = MergeAccountEvm;
+  type ChainId = ChainId;
   type WeightInfo = weights::evm_accounts::WeightInfo<Runtime>;
 }
 
@@ -1009,9 +1043,50 @@ impl pallet_contracts::Config for Runtime {
   type DeletionWeightLimit = DeletionWeightLimit;
 }
 
+parameter_type_with_key! {
+  pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+    Zero::zero()
+  };
+}
+
+impl orml_tokens::Config for Runtime {
+  type Event = Event;
+  type Balance = Balance;
+  type Amount = Amount;
+  type CurrencyId = CurrencyId;
+  type WeightInfo = ();
+  type ExistentialDeposits = ExistentialDeposits;
+  type OnDust = ();
+}
+
+parameter_types! {
+  pub const BscChainId: u64 = 56;
+  pub const BscEpoch: u64 = 200;
+  // about 12 hours of bsc blocks
+  pub const BscHeadersToKeep: u64 = 14_400;
+}
+
+impl bsc_light_client::Config for Runtime {
+  type Event = Event;
+  type ChainId = BscChainId;
+  type Epoch = BscEpoch;
+  type HeadersToKeep = BscHeadersToKeep;
+}
+
 parameter_types! {
   pub Prefix: &'static [u8] = b"Pay CLVs to the Clover account:";
   pub const ClaimsModuleId: ModuleId = ModuleId(*b"clvclaim");
+  // claims minted before multi-chain support are all from bsc
+  pub const ClaimsLegacyChainId: clover_claims::ChainId = 56;
+  pub const ClaimsBurnRefundDelay: BlockNumber = 3 * DAYS;
+  pub const ClaimsBlocksPerDay: BlockNumber = DAYS;
+  pub const ClaimsExpiry: BlockNumber = 30 * DAYS;
+  pub const ClaimsRelayerBond: Balance = 10_000 * DOLLARS;
+  pub const ClaimsUnbondingDelay: BlockNumber = 7 * DAYS;
+  pub const ClaimsLargeMintDelay: BlockNumber = DAYS;
+  pub const ClaimsCertificateUnsignedPriority: TransactionPriority = TransactionPriority::max_value();
+  pub const GetNativeCurrencyId: CurrencyId = CurrencyId::CLV;
+  pub TreasuryAccount: AccountId = TreasuryModuleId::get().into_account();
 }
 
 impl clover_claims::Config for Runtime {
@@ -1019,6 +1094,43 @@ impl clover_claims::Config for Runtime {
   type Event = Event;
   type Currency = Balances;
   type Prefix = Prefix;
+  type LegacyChainId = ClaimsLegacyChainId;
+  type BurnRefundDelay = ClaimsBurnRefundDelay;
+  type BlocksPerDay = ClaimsBlocksPerDay;
+  type ClaimExpiry = ClaimsExpiry;
+  type FeeDestination = Treasury;
+  type MultiCurrency = Tokens;
+  type NativeCurrencyId = GetNativeCurrencyId;
+  type TokenFeeDestination = TreasuryAccount;
+  type AdminOrigin = EnsureRootOrHalfCouncil;
+  type PauseOrigin = EnsureOneOf<
+    AccountId,
+    EnsureRoot<AccountId>,
+    pallet_collective::EnsureProportionMoreThan<_1, _2, AccountId, TechnicalCollective>,
+  >;
+  type AddressMapping = EvmAddressMapping<Runtime>;
+  type ReceiptVerifier = BscLightClient;
+  type RelayerBond = ClaimsRelayerBond;
+  type UnbondingDelay = ClaimsUnbondingDelay;
+  type SlashOrigin = EnsureRootOrHalfCouncil;
+  type Slashed = Treasury;
+  type VestingSchedule = Vesting;
+  type LargeMintDelay = ClaimsLargeMintDelay;
+  type VetoOrigin = EnsureOneOf<
+    AccountId,
+    EnsureRootOrHalfCouncil,
+    pallet_collective::EnsureProportionMoreThan<_1, _2, AccountId, TechnicalCollective>,
+  >;
+  type MintCall = Call;
+  type Scheduler = Scheduler;
+  type PalletsOrigin = OriginCaller;
+  type BridgeAuthorityId = clover_claims::ecdsa::AuthorityId;
+  type CertificateUnsignedPriority = ClaimsCertificateUnsignedPriority;
+  type FreezeOrigin = EnsureOneOf<
+    AccountId,
+    EnsureRootOrHalfCouncil,
+    pallet_collective::EnsureProportionMoreThan<_1, _2, AccountId, TechnicalCollective>,
+  >;
 }
 
 parameter_types! {
@@ -1086,7 +1198,9 @@ construct_runtime!(
     // account module
     EvmAccounts: evm_accounts::{Module, Call, Storage, Event<T>},
 
-    CloverClaims: clover_claims::{Module, Call, Storage, Event<T>, ValidateUnsigned},
+    Tokens: orml_tokens::{Module, Call, Storage, Event<T>, Config<T>},
+    CloverClaims: clover_claims::{Module, Call, Storage, Config<T>, Event<T>, ValidateUnsigned},
+    BscLightClient: bsc_light_client::{Module, Call, Storage, Event<T>},
   }
 );
 
@@ -1121,6 +1235,7 @@ pub type Executive = frame_executive::Executive<
   frame_system::ChainContext<Runtime>,
   Runtime,
   AllModules,
+  UpgradeSessionKeys,
 >;
 
 pub type SignedPayload = generic::SignedPayload<Call, SignedExtra>;
@@ -1323,6 +1438,116 @@ impl_runtime_apis! {
     }
   }
 
+  impl clover_rpc_runtime_api::ClaimsApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+    fn pending_burns(
+      chain_id: clover_claims::ChainId,
+      start: clover_claims::BurnNonce,
+      limit: u32,
+    ) -> Vec<(clover_claims::BurnNonce, clover_claims::BurnInfo<AccountId, Balance, BlockNumber>)> {
+      CloverClaims::pending_burns(chain_id, start, limit)
+    }
+
+    fn remaining_claim_limit(
+      chain_id: clover_claims::ChainId,
+      currency_id: CurrencyId,
+      who: Option<clover_claims::EthereumAddress>,
+    ) -> Balance {
+      CloverClaims::remaining_claim_limit(chain_id, currency_id, who)
+    }
+
+    fn fees_collected(chain_id: clover_claims::ChainId, currency_id: CurrencyId) -> Balance {
+      CloverClaims::fees_collected(chain_id, currency_id)
+    }
+
+    fn mint_fee_schedule(
+      chain_id: clover_claims::ChainId,
+      currency_id: CurrencyId,
+    ) -> Option<clover_claims::FeeSchedule<Balance>> {
+      CloverClaims::mint_fee(chain_id, currency_id)
+    }
+
+    fn burn_fee_schedule(
+      chain_id: clover_claims::ChainId,
+      currency_id: CurrencyId,
+    ) -> Option<clover_claims::FeeSchedule<Balance>> {
+      CloverClaims::burn_fee(chain_id, currency_id)
+    }
+
+    fn mint_fee(chain_id: clover_claims::ChainId, currency_id: CurrencyId, amount: Balance) -> Balance {
+      CloverClaims::mint_fee_of(chain_id, currency_id, amount)
+    }
+
+    fn burn_fee(chain_id: clover_claims::ChainId, currency_id: CurrencyId, amount: Balance) -> Balance {
+      CloverClaims::burn_fee_of(chain_id, currency_id, amount)
+    }
+
+    fn claim_status(
+      chain_id: clover_claims::ChainId,
+      tx: clover_claims::EthereumTxHash,
+    ) -> clover_claims::ClaimStatus<Balance, BlockNumber> {
+      CloverClaims::claim_status(chain_id, &tx)
+    }
+
+    fn pending_claims(
+      chain_id: clover_claims::ChainId,
+      who: clover_claims::EthereumAddress,
+    ) -> Vec<(clover_claims::EthereumTxHash, clover_claims::ClaimDetails<Balance, BlockNumber>)> {
+      CloverClaims::pending_claims(chain_id, &who)
+    }
+
+    fn claim_message(dest: AccountId, tx: clover_claims::EthereumTxHash) -> Vec<u8> {
+      CloverClaims::claim_message(&dest, &tx)
+    }
+
+    fn evm_claim_message(dest: clover_claims::H160, tx: clover_claims::EthereumTxHash) -> Vec<u8> {
+      CloverClaims::evm_claim_message(&dest, &tx)
+    }
+
+    fn evm_burn_message(
+      source: clover_claims::H160,
+      chain_id: clover_claims::ChainId,
+      currency_id: CurrencyId,
+      dest: clover_claims::EthereumAddress,
+      amount: Balance,
+    ) -> Vec<u8> {
+      let nonce = CloverClaims::evm_burn_nonce(&source);
+      CloverClaims::evm_burn_message(chain_id, currency_id, &dest, amount, nonce)
+    }
+
+    fn claim_typed_data_hash(
+      dest: AccountId,
+      chain_id: clover_claims::ChainId,
+      tx: clover_claims::EthereumTxHash,
+    ) -> [u8; 32] {
+      CloverClaims::claim_typed_data_hash(&dest, chain_id, &tx)
+    }
+
+    fn claim_limits(
+      chain_id: clover_claims::ChainId,
+      currency_id: CurrencyId,
+    ) -> clover_claims::ClaimLimits<Balance, BlockNumber> {
+      CloverClaims::claim_limits(chain_id, currency_id)
+    }
+
+    fn relayer_rewards(who: AccountId) -> Vec<(CurrencyId, clover_claims::RewardLedger<Balance>)> {
+      CloverClaims::relayer_ledger(&who)
+    }
+
+    fn supply_audit(
+      chain_id: clover_claims::ChainId,
+      currency_id: CurrencyId,
+    ) -> clover_claims::SupplyAudit<AccountId, Balance, BlockNumber> {
+      CloverClaims::supply_audit(chain_id, currency_id)
+    }
+
+    fn burn_certificate(
+      chain_id: clover_claims::ChainId,
+      nonce: clover_claims::BurnNonce,
+    ) -> Option<clover_claims::CertifiedBurn<Balance>> {
+      CloverClaims::burn_certificate(chain_id, nonce)
+    }
+  }
+
   impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
     fn chain_id() -> u64 {
         <Runtime as pallet_evm::Config>::ChainId::get()